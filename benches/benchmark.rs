@@ -17,7 +17,8 @@
 #[cfg(feature = "oracle")]
 use crate::oracle_bench::*;
 use sqldatetime::{
-    Date, DateTime, Formatter, IntervalDT, IntervalYM, Round, Time, Timestamp, Trunc,
+    Date, DateTime, Formatter, FormatterCache, IntervalDT, IntervalYM, Round, Time, Timestamp,
+    Trunc,
 };
 use stack_buf::StackVec;
 
@@ -92,6 +93,28 @@ fn timestamp_format(bench: &mut Bencher) {
     })
 }
 
+fn timestamp_format_with_cache(bench: &mut Bencher) {
+    let ts = Timestamp::from_str("2021-8-16 12:12:34.234566");
+    let cache = FormatterCache::new(16);
+    bench.iter(|| {
+        let s = cache
+            .format(black_box("yyyy-mm-dd hh24:mi:ss.ff"), black_box(ts))
+            .unwrap();
+        black_box(s);
+    })
+}
+
+fn timestamp_format_without_cache(bench: &mut Bencher) {
+    let ts = Timestamp::from_str("2021-8-16 12:12:34.234566");
+    bench.iter(|| {
+        let s = ts
+            .format(black_box("yyyy-mm-dd hh24:mi:ss.ff"))
+            .unwrap()
+            .to_string();
+        black_box(s);
+    })
+}
+
 fn timestamp_format_dow(bench: &mut Bencher) {
     let ts = Timestamp::from_str("2021-8-16 12:12:34.234566");
     let fmt = Formatter::try_new("yyyy-mm-dd hh24:mi:ss.ff day").unwrap();
@@ -654,6 +677,38 @@ fn interval_dt_div_f64(bench: &mut Bencher) {
     })
 }
 
+fn interval_dt_apply_to_slice_1000(bench: &mut Bencher) {
+    let ds = IntervalDT::from_str("0 01:00:00");
+    let mut timestamps: Vec<Timestamp> = (0..1000)
+        .map(|i| Timestamp::try_from_usecs(i * 1_000_000_000).unwrap())
+        .collect();
+    bench.iter(|| {
+        let _ = black_box(black_box(ds).apply_to_slice(black_box(&mut timestamps)));
+    })
+}
+
+fn interval_dt_shift_all_1000(bench: &mut Bencher) {
+    let ds = IntervalDT::from_str("0 01:00:00");
+    let timestamps: Vec<Timestamp> = (0..1000)
+        .map(|i| Timestamp::try_from_usecs(i * 1_000_000_000).unwrap())
+        .collect();
+    bench.iter(|| {
+        let _ = black_box(black_box(ds).shift_all(black_box(&timestamps)));
+    })
+}
+
+fn interval_dt_add_each_1000(bench: &mut Bencher) {
+    let ds = IntervalDT::from_str("0 01:00:00");
+    let mut timestamps: Vec<Timestamp> = (0..1000)
+        .map(|i| Timestamp::try_from_usecs(i * 1_000_000_000).unwrap())
+        .collect();
+    bench.iter(|| {
+        for ts in black_box(&mut timestamps).iter_mut() {
+            *ts = ts.add_interval_dt(black_box(ds)).unwrap();
+        }
+    })
+}
+
 fn interval_ym_parse_format(bench: &mut Bencher) {
     bench.iter(|| {
         Formatter::try_new(black_box("YY-MM")).unwrap();
@@ -1086,6 +1141,8 @@ benchmark_group!(
     timestamp_parse_format_without_date,
     timestamp_parse_without_date,
     timestamp_format,
+    timestamp_format_with_cache,
+    timestamp_format_without_cache,
     timestamp_format_dow,
     timestamp_sub_interval_dt_100_times,
     timestamp_sub_interval_ym,
@@ -1150,6 +1207,9 @@ benchmark_group!(
     interval_dt_div_f64,
     interval_dt_sub_time,
     interval_dt_sub_interval_dt,
+    interval_dt_apply_to_slice_1000,
+    interval_dt_shift_all_1000,
+    interval_dt_add_each_1000,
     interval_ym_parse_format,
     interval_ym_parse,
     interval_ym_format,
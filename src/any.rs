@@ -0,0 +1,189 @@
+//! AnyDateTime implementation.
+
+use crate::common::USECONDS_PER_DAY;
+use crate::{Date, Time, Timestamp};
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+/// AnyDateTime represents a value of any of the date time types, so that
+/// heterogeneous collections can be compared and sorted coherently.
+///
+/// All variants are compared on the common scale of microseconds from Unix Epoch:
+/// - `Date` is treated as the midnight of that day.
+/// - `Time` is treated as the time of day on Unix Epoch, i.e. `1970-01-01`.
+/// - `Timestamp` is used as is.
+///
+/// Values of different variants that represent the same instant are equal,
+/// e.g. `AnyDateTime::Date(d) == AnyDateTime::Timestamp(d.and_zero_time())`.
+#[derive(Copy, Clone, Debug)]
+pub enum AnyDateTime {
+    /// A `Date` value.
+    Date(Date),
+    /// A `Time` value.
+    Time(Time),
+    /// A `Timestamp` value.
+    Timestamp(Timestamp),
+}
+
+impl AnyDateTime {
+    /// Gets the microseconds from Unix Epoch, which is used for comparison.
+    #[inline]
+    pub const fn usecs(self) -> i64 {
+        match self {
+            AnyDateTime::Date(date) => date.days() as i64 * USECONDS_PER_DAY,
+            AnyDateTime::Time(time) => time.usecs(),
+            AnyDateTime::Timestamp(timestamp) => timestamp.usecs(),
+        }
+    }
+}
+
+impl From<Date> for AnyDateTime {
+    #[inline]
+    fn from(date: Date) -> Self {
+        AnyDateTime::Date(date)
+    }
+}
+
+impl From<Time> for AnyDateTime {
+    #[inline]
+    fn from(time: Time) -> Self {
+        AnyDateTime::Time(time)
+    }
+}
+
+impl From<Timestamp> for AnyDateTime {
+    #[inline]
+    fn from(timestamp: Timestamp) -> Self {
+        AnyDateTime::Timestamp(timestamp)
+    }
+}
+
+impl PartialEq for AnyDateTime {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.usecs() == other.usecs()
+    }
+}
+
+impl Eq for AnyDateTime {}
+
+impl PartialOrd for AnyDateTime {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for AnyDateTime {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.usecs().cmp(&other.usecs())
+    }
+}
+
+impl Hash for AnyDateTime {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.usecs().hash(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn generate_ts(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        min: u32,
+        sec: u32,
+        usec: u32,
+    ) -> Timestamp {
+        Timestamp::new(
+            Date::try_from_ymd(year, month, day).unwrap(),
+            Time::try_from_hms(hour, min, sec, usec).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_any_date_time_eq() {
+        let date = Date::try_from_ymd(2021, 6, 1).unwrap();
+        assert_eq!(
+            AnyDateTime::from(date),
+            AnyDateTime::from(generate_ts(2021, 6, 1, 0, 0, 0, 0))
+        );
+        assert_ne!(
+            AnyDateTime::from(date),
+            AnyDateTime::from(generate_ts(2021, 6, 1, 0, 0, 0, 1))
+        );
+
+        let date = Date::try_from_ymd(1969, 12, 31).unwrap();
+        assert_eq!(
+            AnyDateTime::from(date),
+            AnyDateTime::from(generate_ts(1969, 12, 31, 0, 0, 0, 0))
+        );
+
+        let time = Time::try_from_hms(12, 30, 0, 0).unwrap();
+        assert_eq!(
+            AnyDateTime::from(time),
+            AnyDateTime::from(generate_ts(1970, 1, 1, 12, 30, 0, 0))
+        );
+        assert_eq!(
+            AnyDateTime::from(Time::ZERO),
+            AnyDateTime::from(Date::try_from_ymd(1970, 1, 1).unwrap())
+        );
+
+        assert_eq!(
+            AnyDateTime::from(Date::MIN),
+            AnyDateTime::from(Timestamp::MIN)
+        );
+
+        // Hash is consistent with Eq
+        let mut set = HashSet::new();
+        set.insert(AnyDateTime::from(date));
+        assert!(set.contains(&AnyDateTime::from(generate_ts(1969, 12, 31, 0, 0, 0, 0))));
+    }
+
+    #[test]
+    fn test_any_date_time_ord() {
+        let mut values = [
+            AnyDateTime::from(generate_ts(2021, 6, 1, 0, 0, 0, 1)),
+            AnyDateTime::from(Date::try_from_ymd(2021, 6, 1).unwrap()),
+            AnyDateTime::from(Time::try_from_hms(23, 59, 59, 999999).unwrap()),
+            AnyDateTime::from(Timestamp::MAX),
+            AnyDateTime::from(Date::MIN),
+            AnyDateTime::from(generate_ts(1969, 12, 31, 23, 59, 59, 999999)),
+            AnyDateTime::from(Time::ZERO),
+        ];
+        values.sort();
+
+        assert_eq!(
+            values.iter().map(|v| v.usecs()).collect::<Vec<_>>(),
+            vec![
+                Timestamp::MIN.usecs(),
+                -1,
+                0,
+                USECONDS_PER_DAY - 1,
+                generate_ts(2021, 6, 1, 0, 0, 0, 0).usecs(),
+                generate_ts(2021, 6, 1, 0, 0, 0, 1).usecs(),
+                Timestamp::MAX.usecs(),
+            ]
+        );
+
+        assert!(
+            AnyDateTime::from(Date::try_from_ymd(2021, 6, 1).unwrap())
+                < AnyDateTime::from(generate_ts(2021, 6, 1, 0, 0, 0, 1))
+        );
+        assert!(
+            AnyDateTime::from(Date::try_from_ymd(2021, 6, 2).unwrap())
+                > AnyDateTime::from(generate_ts(2021, 6, 1, 23, 59, 59, 999999))
+        );
+        assert_eq!(
+            AnyDateTime::from(Date::MAX).cmp(&AnyDateTime::from(Timestamp::MAX)),
+            Ordering::Less
+        );
+    }
+}
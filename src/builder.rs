@@ -0,0 +1,343 @@
+//! Date/Timestamp builder implementation.
+
+use crate::error::{Error, Result};
+use crate::{Date, Time, Timestamp};
+
+/// Builder for `Date` from individually set fields.
+///
+/// Unlike parsing, unset fields are never filled from the current date,
+/// and building fails if any of year, month or day is not set.
+///
+/// ## Example
+///
+/// ```
+/// use sqldatetime::{Date, DateBuilder};
+///
+/// let date = DateBuilder::new().year(2021).month(6).day(1).build().unwrap();
+/// assert_eq!(date, Date::try_from_ymd(2021, 6, 1).unwrap());
+/// assert!(DateBuilder::new().year(2021).day(1).build().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DateBuilder {
+    year: Option<i32>,
+    month: Option<u32>,
+    day: Option<u32>,
+}
+
+impl DateBuilder {
+    /// Creates a new `DateBuilder` with no field set.
+    #[inline]
+    pub const fn new() -> Self {
+        DateBuilder {
+            year: None,
+            month: None,
+            day: None,
+        }
+    }
+
+    /// Sets the year.
+    #[inline]
+    pub fn year(mut self, year: i32) -> Self {
+        self.year = Some(year);
+        self
+    }
+
+    /// Sets the month.
+    #[inline]
+    pub fn month(mut self, month: u32) -> Self {
+        self.month = Some(month);
+        self
+    }
+
+    /// Sets the day of month.
+    #[inline]
+    pub fn day(mut self, day: u32) -> Self {
+        self.day = Some(day);
+        self
+    }
+
+    /// Builds the `Date`, returns `MissingField` error if any field is missing, or an error if
+    /// any field is invalid.
+    #[inline]
+    pub fn build(self) -> Result<Date> {
+        let year = expect_field(self.year, "year")?;
+        let month = expect_field(self.month, "month")?;
+        let day = expect_field(self.day, "day")?;
+        Date::try_from_ymd(year, month, day)
+    }
+}
+
+/// Builder for `Timestamp` from individually set fields.
+///
+/// Building fails if any of year, month or day is not set, while unset time fields default to zero.
+///
+/// ## Example
+///
+/// ```
+/// use sqldatetime::{Date, Time, TimestampBuilder};
+///
+/// let ts = TimestampBuilder::new()
+///     .year(2021)
+///     .month(6)
+///     .day(1)
+///     .hour(12)
+///     .build()
+///     .unwrap();
+/// assert_eq!(
+///     ts,
+///     Date::try_from_ymd(2021, 6, 1)
+///         .unwrap()
+///         .and_time(Time::try_from_hms(12, 0, 0, 0).unwrap())
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TimestampBuilder {
+    date: DateBuilder,
+    hour: Option<u32>,
+    minute: Option<u32>,
+    second: Option<u32>,
+    usec: Option<u32>,
+}
+
+impl TimestampBuilder {
+    /// Creates a new `TimestampBuilder` with no field set.
+    #[inline]
+    pub const fn new() -> Self {
+        TimestampBuilder {
+            date: DateBuilder::new(),
+            hour: None,
+            minute: None,
+            second: None,
+            usec: None,
+        }
+    }
+
+    /// Sets the year.
+    #[inline]
+    pub fn year(mut self, year: i32) -> Self {
+        self.date = self.date.year(year);
+        self
+    }
+
+    /// Sets the month.
+    #[inline]
+    pub fn month(mut self, month: u32) -> Self {
+        self.date = self.date.month(month);
+        self
+    }
+
+    /// Sets the day of month.
+    #[inline]
+    pub fn day(mut self, day: u32) -> Self {
+        self.date = self.date.day(day);
+        self
+    }
+
+    /// Sets the hour, defaults to zero if not set.
+    #[inline]
+    pub fn hour(mut self, hour: u32) -> Self {
+        self.hour = Some(hour);
+        self
+    }
+
+    /// Sets the minute, defaults to zero if not set.
+    #[inline]
+    pub fn minute(mut self, minute: u32) -> Self {
+        self.minute = Some(minute);
+        self
+    }
+
+    /// Sets the second, defaults to zero if not set.
+    #[inline]
+    pub fn second(mut self, second: u32) -> Self {
+        self.second = Some(second);
+        self
+    }
+
+    /// Sets the microsecond, defaults to zero if not set.
+    #[inline]
+    pub fn microsecond(mut self, usec: u32) -> Self {
+        self.usec = Some(usec);
+        self
+    }
+
+    /// Builds the `Timestamp`, returns `MissingField` error if any date field is missing, or an
+    /// error if any field is invalid.
+    #[inline]
+    pub fn build(self) -> Result<Timestamp> {
+        let date = self.date.build()?;
+        let time = Time::try_from_hms(
+            self.hour.unwrap_or(0),
+            self.minute.unwrap_or(0),
+            self.second.unwrap_or(0),
+            self.usec.unwrap_or(0),
+        )?;
+        Ok(date.and_time(time))
+    }
+}
+
+#[inline]
+fn expect_field<T>(field: Option<T>, name: &'static str) -> Result<T> {
+    field.ok_or(Error::MissingField(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_date_builder() {
+        let date = DateBuilder::new()
+            .year(2021)
+            .month(6)
+            .day(1)
+            .build()
+            .unwrap();
+        assert_eq!(date, Date::try_from_ymd(2021, 6, 1).unwrap());
+
+        // Setting a field twice keeps the last one
+        let date = DateBuilder::new()
+            .day(1)
+            .month(2)
+            .year(2020)
+            .day(29)
+            .build()
+            .unwrap();
+        assert_eq!(date, Date::try_from_ymd(2020, 2, 29).unwrap());
+
+        assert_eq!(
+            DateBuilder::new().month(6).day(1).build().unwrap_err(),
+            Error::MissingField("year")
+        );
+        assert_eq!(
+            DateBuilder::new().year(2021).day(1).build().unwrap_err(),
+            Error::MissingField("month")
+        );
+        assert_eq!(
+            DateBuilder::new().year(2021).month(6).build().unwrap_err(),
+            Error::MissingField("day")
+        );
+        assert_eq!(
+            DateBuilder::new().build().unwrap_err(),
+            Error::MissingField("year")
+        );
+
+        // Invalid fields
+        assert_eq!(
+            DateBuilder::new()
+                .year(0)
+                .month(6)
+                .day(1)
+                .build()
+                .unwrap_err(),
+            Error::DateOutOfRange
+        );
+        assert_eq!(
+            DateBuilder::new()
+                .year(2021)
+                .month(13)
+                .day(1)
+                .build()
+                .unwrap_err(),
+            Error::InvalidMonth
+        );
+        assert_eq!(
+            DateBuilder::new()
+                .year(2021)
+                .month(2)
+                .day(29)
+                .build()
+                .unwrap_err(),
+            Error::InvalidDate
+        );
+    }
+
+    #[test]
+    fn test_timestamp_builder() {
+        let ts = TimestampBuilder::new()
+            .year(2021)
+            .month(6)
+            .day(1)
+            .hour(12)
+            .minute(30)
+            .second(5)
+            .microsecond(123456)
+            .build()
+            .unwrap();
+        assert_eq!(
+            ts,
+            Timestamp::new(
+                Date::try_from_ymd(2021, 6, 1).unwrap(),
+                Time::try_from_hms(12, 30, 5, 123456).unwrap()
+            )
+        );
+
+        // Unset time fields default to zero
+        let ts = TimestampBuilder::new()
+            .year(2021)
+            .month(6)
+            .day(1)
+            .build()
+            .unwrap();
+        assert_eq!(ts, Date::try_from_ymd(2021, 6, 1).unwrap().and_zero_time());
+        let ts = TimestampBuilder::new()
+            .year(2021)
+            .month(6)
+            .day(1)
+            .minute(30)
+            .build()
+            .unwrap();
+        assert_eq!(
+            ts,
+            Timestamp::new(
+                Date::try_from_ymd(2021, 6, 1).unwrap(),
+                Time::try_from_hms(0, 30, 0, 0).unwrap()
+            )
+        );
+
+        // Missing date fields
+        assert_eq!(
+            TimestampBuilder::new().hour(1).build().unwrap_err(),
+            Error::MissingField("year")
+        );
+        assert_eq!(
+            TimestampBuilder::new()
+                .year(2021)
+                .day(1)
+                .hour(1)
+                .build()
+                .unwrap_err(),
+            Error::MissingField("month")
+        );
+        assert_eq!(
+            TimestampBuilder::new()
+                .year(2021)
+                .month(6)
+                .build()
+                .unwrap_err(),
+            Error::MissingField("day")
+        );
+
+        // Invalid time fields
+        assert_eq!(
+            TimestampBuilder::new()
+                .year(2021)
+                .month(6)
+                .day(1)
+                .hour(24)
+                .build()
+                .unwrap_err(),
+            Error::TimeOutOfRange
+        );
+        assert_eq!(
+            TimestampBuilder::new()
+                .year(2021)
+                .month(6)
+                .day(1)
+                .microsecond(1_000_000)
+                .build()
+                .unwrap_err(),
+            Error::InvalidFraction
+        );
+    }
+}
@@ -4,6 +4,7 @@ pub const MONTHS_PER_YEAR: u32 = 12;
 pub const HOURS_PER_DAY: u32 = 24;
 pub const MINUTES_PER_HOUR: u32 = 60;
 pub const SECONDS_PER_MINUTE: u32 = 60;
+pub const SECONDS_PER_DAY: u32 = 86_400;
 
 pub const USECONDS_MAX: u32 = 999_999;
 pub const USECONDS_PER_DAY: i64 = 86_400_000_000;
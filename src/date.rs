@@ -1,16 +1,21 @@
 //! Date implementation.
 
 use crate::common::{
-    date2julian, days_of_month, is_valid_date, julian2date, DATE_MAX_YEAR, DATE_MIN_YEAR,
-    MONTHS_PER_YEAR, UNIX_EPOCH_JULIAN,
+    date2julian, days_of_month, is_leap_year, is_valid_date, julian2date, DATE_MAX_YEAR,
+    DATE_MIN_YEAR, MONTHS_PER_YEAR, UNIX_EPOCH_JULIAN, USECONDS_PER_DAY,
 };
 use crate::error::{Error, Result};
 use crate::format::{Formatter, LazyFormat, NaiveDateTime};
 use crate::local::Local;
+use crate::timestamp::RelativeTime;
+use crate::util::StrExt;
 use crate::{DateTime, IntervalDT, IntervalYM, Round, Time, Timestamp, Trunc};
 use std::cmp::{min, Ordering};
 use std::convert::TryFrom;
 use std::fmt::Display;
+use std::hash::{Hash, Hasher};
+use std::iter::FusedIterator;
+use std::ops::{Add, Sub};
 
 type DateSubMethod = fn(Date, i32) -> Result<Date>;
 
@@ -18,6 +23,8 @@ pub const UNIX_EPOCH_DOW: WeekDay = WeekDay::Thursday;
 
 const ROUNDS_UP_DAY: u32 = 16;
 
+const DEFAULT_WEEKEND: [WeekDay; 2] = [WeekDay::Saturday, WeekDay::Sunday];
+
 const ADD_MONTHS_MAX_MONTH: i32 = 9999 * 12 - 1;
 
 const ISO_YEAR_TABLE: [(DateSubMethod, i32); 8] = [
@@ -43,6 +50,26 @@ pub enum WeekDay {
     Saturday = 7,
 }
 
+impl WeekDay {
+    /// Returns all weekdays in the order of Sunday..=Saturday.
+    #[inline]
+    pub const fn all() -> [WeekDay; 7] {
+        use crate::date::WeekDay::*;
+        [
+            Sunday, Monday, Tuesday, Wednesday, Thursday, Friday, Saturday,
+        ]
+    }
+
+    /// Returns all weekdays in the ISO 8601 order of Monday..=Sunday.
+    #[inline]
+    pub const fn all_iso() -> [WeekDay; 7] {
+        use crate::date::WeekDay::*;
+        [
+            Monday, Tuesday, Wednesday, Thursday, Friday, Saturday, Sunday,
+        ]
+    }
+}
+
 impl From<usize> for WeekDay {
     /// Converts `usize` to `WeekDay` in the order of 1..=7 to Sunday..=Saturday
     ///
@@ -50,14 +77,33 @@ impl From<usize> for WeekDay {
     /// Panics if `weekday` is out of range of 1..=7
     #[inline]
     fn from(weekday: usize) -> Self {
-        use crate::date::WeekDay::*;
-        const WEEKDAY_TABLE: [WeekDay; 7] = [
-            Sunday, Monday, Tuesday, Wednesday, Thursday, Friday, Saturday,
-        ];
+        const WEEKDAY_TABLE: [WeekDay; 7] = WeekDay::all();
         WEEKDAY_TABLE[weekday - 1]
     }
 }
 
+impl Add<i32> for WeekDay {
+    type Output = WeekDay;
+
+    /// Adds days to `WeekDay` with wrap-around, e.g. `Saturday + 2` is `Monday`.
+    #[inline]
+    fn add(self, days: i32) -> WeekDay {
+        let index = (self as i64 - 1 + days as i64).rem_euclid(7);
+        WeekDay::from(index as usize + 1)
+    }
+}
+
+impl Sub<i32> for WeekDay {
+    type Output = WeekDay;
+
+    /// Subtracts days from `WeekDay` with wrap-around, e.g. `Monday - 2` is `Saturday`.
+    #[inline]
+    fn sub(self, days: i32) -> WeekDay {
+        let index = (self as i64 - 1 - days as i64).rem_euclid(7);
+        WeekDay::from(index as usize + 1)
+    }
+}
+
 /// Months in the order of 1..=12 January..=December for formatting use
 #[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Eq)]
 pub enum Month {
@@ -75,6 +121,18 @@ pub enum Month {
     December = 12,
 }
 
+impl Month {
+    /// Returns all months in the order of January..=December.
+    #[inline]
+    pub const fn all() -> [Month; 12] {
+        use crate::date::Month::*;
+        [
+            January, February, March, April, May, June, July, August, September, October, November,
+            December,
+        ]
+    }
+}
+
 impl From<usize> for Month {
     /// Converts `usize` to `Month` in the order of 1..=12 to January..=December
     ///
@@ -82,17 +140,143 @@ impl From<usize> for Month {
     /// Panics if `month` is out of range of 1..=12
     #[inline]
     fn from(month: usize) -> Self {
-        use crate::date::Month::*;
-        const MONTH_TABLE: [Month; 12] = [
-            January, February, March, April, May, June, July, August, September, October, November,
-            December,
-        ];
+        const MONTH_TABLE: [Month; 12] = Month::all();
         MONTH_TABLE[month - 1]
     }
 }
 
+impl Add<i32> for Month {
+    type Output = Month;
+
+    /// Adds months to `Month` with wrap-around, e.g. `November + 3` is `February`.
+    #[inline]
+    fn add(self, months: i32) -> Month {
+        let index = (self as i64 - 1 + months as i64).rem_euclid(12);
+        Month::from(index as usize + 1)
+    }
+}
+
+impl Sub<i32> for Month {
+    type Output = Month;
+
+    /// Subtracts months from `Month` with wrap-around, e.g. `February - 3` is `November`.
+    #[inline]
+    fn sub(self, months: i32) -> Month {
+        let index = (self as i64 - 1 - months as i64).rem_euclid(12);
+        Month::from(index as usize + 1)
+    }
+}
+
+/// How adding months handles a day that does not exist in the resulting month,
+/// e.g. adding one month to January 31.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MonthOverflow {
+    /// Returns `InvalidDate` error, like adding an SQL year-month interval.
+    Error,
+    /// Clamps the day to the last day of the month, e.g. `2021-01-31` plus one month is
+    /// `2021-02-28`.
+    ClampToMonthEnd,
+    /// Rolls the excess days over into the following month, e.g. `2021-01-31` plus one month is
+    /// `2021-03-03`.
+    Overflow,
+}
+
+/// Quarters of year in the order of 1..=4 Q1..=Q4, displayed as `"Q1"`..=`"Q4"`
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Eq)]
+pub enum Quarter {
+    Q1 = 1,
+    Q2 = 2,
+    Q3 = 3,
+    Q4 = 4,
+}
+
+impl Quarter {
+    const NAMES: [&'static str; 4] = ["Q1", "Q2", "Q3", "Q4"];
+
+    /// Returns the quarter containing the given month in 1..=12, or `None` if out of range.
+    #[inline]
+    pub const fn from_month(month: u32) -> Option<Quarter> {
+        match month {
+            1..=3 => Some(Quarter::Q1),
+            4..=6 => Some(Quarter::Q2),
+            7..=9 => Some(Quarter::Q3),
+            10..=12 => Some(Quarter::Q4),
+            _ => None,
+        }
+    }
+
+    /// Parses a quarter from its name `"Q1"`..=`"Q4"`, ignoring ASCII case.
+    #[inline]
+    pub fn from_name(name: &str) -> Option<Quarter> {
+        use crate::date::Quarter::*;
+        Quarter::NAMES
+            .iter()
+            .position(|n| n.eq_ignore_ascii_case(name))
+            .map(|i| [Q1, Q2, Q3, Q4][i])
+    }
+
+    /// Returns the name of this quarter, e.g. `"Q1"`.
+    #[inline]
+    pub const fn name(self) -> &'static str {
+        Quarter::NAMES[self as usize - 1]
+    }
+}
+
+impl Display for Quarter {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Halves of year in the order of 1..=2 H1..=H2, displayed as `"H1"`..=`"H2"`
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Eq)]
+pub enum Half {
+    H1 = 1,
+    H2 = 2,
+}
+
+impl Half {
+    const NAMES: [&'static str; 2] = ["H1", "H2"];
+
+    /// Returns the half containing the given month in 1..=12, or `None` if out of range.
+    #[inline]
+    pub const fn from_month(month: u32) -> Option<Half> {
+        match month {
+            1..=6 => Some(Half::H1),
+            7..=12 => Some(Half::H2),
+            _ => None,
+        }
+    }
+
+    /// Parses a half from its name `"H1"`..=`"H2"`, ignoring ASCII case.
+    #[inline]
+    pub fn from_name(name: &str) -> Option<Half> {
+        Half::NAMES
+            .iter()
+            .position(|n| n.eq_ignore_ascii_case(name))
+            .map(|i| [Half::H1, Half::H2][i])
+    }
+
+    /// Returns the name of this half, e.g. `"H1"`.
+    #[inline]
+    pub const fn name(self) -> &'static str {
+        Half::NAMES[self as usize - 1]
+    }
+}
+
+impl Display for Half {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
 /// Date represents a valid Gregorian date.
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+///
+/// `Date` hashes like the `Timestamp` at its midnight, so that values comparing equal across
+/// types, e.g. `Date` and `Timestamp`, also hash equally.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
 #[repr(transparent)]
 pub struct Date(i32);
 
@@ -136,6 +320,94 @@ impl Date {
         Ok(unsafe { Date::from_ymd_unchecked(year, month, day) })
     }
 
+    /// Validates the given year, month, and day fields, returning the error of the first invalid field:
+    /// - `DateOutOfRange` if the year is out of range
+    /// - `InvalidMonth` if the month is not in `1..=12`
+    /// - `InvalidDay` if the day is not in `1..=31`
+    /// - `InvalidDate` if the day does not exist in that month, e.g. `2021-02-29`
+    #[inline]
+    pub const fn validate_ymd_detailed(year: i32, month: u32, day: u32) -> Result<()> {
+        if year < DATE_MIN_YEAR || year > DATE_MAX_YEAR {
+            return Err(Error::DateOutOfRange);
+        }
+
+        if month < 1 || month > MONTHS_PER_YEAR {
+            return Err(Error::InvalidMonth);
+        }
+
+        if day < 1 || day > 31 {
+            return Err(Error::InvalidDay);
+        }
+
+        if day > days_of_month(year, month) {
+            return Err(Error::InvalidDate);
+        }
+
+        Ok(())
+    }
+
+    /// Creates a `Date` from the given year, month, and day as `i64`.
+    ///
+    /// Each field is range checked before narrowing, so huge values are rejected
+    /// instead of being truncated into a valid-looking date.
+    #[inline]
+    pub const fn try_from_ymd_i64(year: i64, month: i64, day: i64) -> Result<Date> {
+        if year < DATE_MIN_YEAR as i64 || year > DATE_MAX_YEAR as i64 {
+            return Err(Error::DateOutOfRange);
+        }
+
+        if month < 1 || month > MONTHS_PER_YEAR as i64 {
+            return Err(Error::InvalidMonth);
+        }
+
+        if day < 1 || day > 31 {
+            return Err(Error::InvalidDay);
+        }
+
+        Date::try_from_ymd(year as i32, month as u32, day as u32)
+    }
+
+    /// Creates a `Date` from the given year, month, and day, clamping instead of rejecting
+    /// an out of range year or day.
+    ///
+    /// The year is clamped into `1..=9999` and the day to the last day of the month.
+    /// The returned flag tells whether any field was clamped. An invalid month or a zero
+    /// day is still an error.
+    #[inline]
+    pub const fn try_from_ymd_clamped(year: i64, month: u32, day: u32) -> (Result<Date>, bool) {
+        if month < 1 || month > MONTHS_PER_YEAR {
+            return (Err(Error::InvalidMonth), false);
+        }
+
+        if day < 1 {
+            return (Err(Error::InvalidDay), false);
+        }
+
+        let mut clamped = false;
+        let year = if year < DATE_MIN_YEAR as i64 {
+            clamped = true;
+            DATE_MIN_YEAR
+        } else if year > DATE_MAX_YEAR as i64 {
+            clamped = true;
+            DATE_MAX_YEAR
+        } else {
+            year as i32
+        };
+
+        let last_day = days_of_month(year, month);
+        let day = if day > last_day {
+            clamped = true;
+            last_day
+        } else {
+            day
+        };
+
+        (
+            Ok(unsafe { Date::from_ymd_unchecked(year, month, day) }),
+            clamped,
+        )
+    }
+
     /// Checks if the given year, month, and day fields are valid.
     #[inline]
     pub const fn is_valid(year: i32, month: u32, day: u32) -> bool {
@@ -206,12 +478,108 @@ impl Date {
         }
     }
 
+    /// Creates a `Date` from the given Julian Day Number, e.g. `2440588` for `1970-01-01`.
+    #[inline]
+    pub const fn try_from_julian_day_number(jdn: i32) -> Result<Date> {
+        match jdn.checked_sub(UNIX_EPOCH_JULIAN) {
+            Some(days) => Date::try_from_days(days),
+            None => Err(Error::DateOutOfRange),
+        }
+    }
+
+    /// Gets the Julian Day Number of this `Date`, e.g. `2440588` for `1970-01-01`.
+    #[inline]
+    pub const fn to_julian_day_number(self) -> i32 {
+        self.0 + UNIX_EPOCH_JULIAN
+    }
+
+    /// Creates a `Date` from a packed `YYYYMMDD` integer, e.g. `20211024` for `2021-10-24`.
+    #[inline]
+    pub const fn try_from_packed_i32(packed: i32) -> Result<Date> {
+        if packed < 0 {
+            return Err(Error::DateOutOfRange);
+        }
+        let year = packed / 10000;
+        let month = (packed / 100 % 100) as u32;
+        let day = (packed % 100) as u32;
+        Date::try_from_ymd(year, month, day)
+    }
+
+    /// Gets the packed `YYYYMMDD` integer of this `Date`, e.g. `20211024` for `2021-10-24`.
+    #[inline]
+    pub const fn to_packed_i32(self) -> i32 {
+        let (year, month, day) = self.extract();
+        year * 10000 + month as i32 * 100 + day as i32
+    }
+
+    /// Creates a `Date` from the given year and ordinal day of the year (1..=366).
+    #[inline]
+    pub const fn try_from_ordinal(year: i32, ordinal: u32) -> Result<Date> {
+        if year < DATE_MIN_YEAR || year > DATE_MAX_YEAR {
+            return Err(Error::DateOutOfRange);
+        }
+
+        let days_of_year = if is_leap_year(year) { 366 } else { 365 };
+        if ordinal < 1 || ordinal > days_of_year {
+            return Err(Error::InvalidDay);
+        }
+
+        let first_day = unsafe { Date::from_ymd_unchecked(year, 1, 1) };
+        Ok(unsafe { Date::from_days_unchecked(first_day.0 + ordinal as i32 - 1) })
+    }
+
+    /// Creates a `Date` from the given ISO year, ISO week (1..=53) and ISO day of week
+    /// (1..=7 Monday..=Sunday).
+    #[inline]
+    pub fn try_from_iso_week_date(iso_year: i32, week: u32, week_day: u32) -> Result<Date> {
+        if !(DATE_MIN_YEAR..=DATE_MAX_YEAR).contains(&iso_year) {
+            return Err(Error::DateOutOfRange);
+        }
+
+        if !(1..=7).contains(&week_day) {
+            return Err(Error::InvalidDay);
+        }
+
+        // The week containing December 28 is always the last week of the ISO year
+        let weeks = unsafe { Date::from_ymd_unchecked(iso_year, 12, 28) }
+            .iso_year_week()
+            .1;
+        if !(1..=weeks).contains(&week) {
+            return Err(Error::InvalidDate);
+        }
+
+        // The week containing January 4 is always the first week of the ISO year
+        let fourth_day = unsafe { Date::from_ymd_unchecked(iso_year, 1, 4) };
+        let first_monday = fourth_day.0 - (fourth_day.iso_day_of_week() as i32 - 1);
+        Date::try_from_days(first_monday + (week as i32 - 1) * 7 + week_day as i32 - 1)
+    }
+
     /// Extracts `(year, month, day)` from the date.
     #[inline]
     pub const fn extract(self) -> (i32, u32, u32) {
         julian2date(self.0 + UNIX_EPOCH_JULIAN)
     }
 
+    /// Gets the quarter of year of this `Date`.
+    #[inline]
+    pub const fn quarter_enum(self) -> Quarter {
+        let (_, month, _) = self.extract();
+        match Quarter::from_month(month) {
+            Some(quarter) => quarter,
+            None => unreachable!(),
+        }
+    }
+
+    /// Gets the half of year of this `Date`.
+    #[inline]
+    pub const fn half_enum(self) -> Half {
+        let (_, month, _) = self.extract();
+        match Half::from_month(month) {
+            Some(half) => half,
+            None => unreachable!(),
+        }
+    }
+
     /// Makes a new `Timestamp` from the current date, hour, minute, second and microsecond.
     #[inline]
     pub fn and_hms(self, hour: u32, minute: u32, sec: u32, usec: u32) -> Result<Timestamp> {
@@ -227,6 +595,92 @@ impl Date {
         Timestamp::new(self, time)
     }
 
+    /// Formats `Date` as a fixed-width `YYYYMMDD` string, e.g. `20210601`, which sorts as a
+    /// string in the same order as the dates for the full range of `Date`.
+    #[inline]
+    pub fn to_sortable_string(self) -> Result<String> {
+        let (year, month, day) = self.extract();
+        try_format!("{:04}{:02}{:02}", year, month, day)
+    }
+
+    /// Parses `Date` from a `YYYYMMDD` string produced by [`to_sortable_string`].
+    ///
+    /// [`to_sortable_string`]: Date::to_sortable_string
+    #[inline]
+    pub fn from_sortable_string<S: AsRef<str>>(input: S) -> Result<Self> {
+        let s = input.as_ref().as_bytes();
+        if s.len() != 8 || !s.iter().all(|ch| ch.is_ascii_digit()) {
+            return Err(Error::ParseError(try_format!(
+                "the input {} is not a valid sortable date",
+                input.as_ref()
+            )?));
+        }
+        let number = |s: &[u8]| s.iter().fold(0, |n, &ch| n * 10 + (ch - b'0') as u32);
+        Date::try_from_ymd(number(&s[..4]) as i32, number(&s[4..6]), number(&s[6..]))
+    }
+
+    /// Formats the year and quarter of `Date` as `YYYY-Qn`, e.g. `2021-Q4`.
+    #[inline]
+    pub fn format_year_quarter(self) -> Result<String> {
+        let (year, _, _) = self.extract();
+        try_format!("{:04}-{}", year, self.quarter_enum())
+    }
+
+    /// Parses a `YYYY-Qn` string produced by [`format_year_quarter`] as the first day of the
+    /// quarter, e.g. `2021-Q4` is `2021-10-01`. The `Q` is case-insensitive.
+    ///
+    /// [`format_year_quarter`]: Date::format_year_quarter
+    #[inline]
+    pub fn parse_year_quarter<S: AsRef<str>>(input: S) -> Result<Self> {
+        let s = input.as_ref();
+        let quarter = match (s.get(..4), s.get(4..5), s.get(5..)) {
+            (Some(year), Some("-"), Some(quarter))
+                if year.bytes().all(|ch| ch.is_ascii_digit()) =>
+            {
+                Quarter::from_name(quarter).map(|quarter| (year, quarter))
+            }
+            _ => None,
+        };
+        match quarter {
+            Some((year, quarter)) => {
+                let year = year.bytes().fold(0, |n, ch| n * 10 + (ch - b'0') as i32);
+                Date::try_from_ymd(year, (quarter as u32 - 1) * 3 + 1, 1)
+            }
+            None => Err(Error::ParseError(try_format!(
+                "the input {} is not a valid year and quarter",
+                s
+            )?)),
+        }
+    }
+
+    /// Parses the relative keywords `today`, `yesterday`, `tomorrow` and `now`
+    /// (case-insensitive) into a `Date` relative to `reference`, or to the local system date if
+    /// `reference` is `None`. `now` is the same as `today`.
+    ///
+    /// Other input returns `ParseError` error, so that callers can fall back to parsing by
+    /// format.
+    #[inline]
+    pub fn parse_relative<S: AsRef<str>>(input: S, reference: Option<Date>) -> Result<Self> {
+        let s = input.as_ref().trim();
+        let days = if s.eq_ignore_ascii_case("today") || s.eq_ignore_ascii_case("now") {
+            0
+        } else if s.eq_ignore_ascii_case("yesterday") {
+            -1
+        } else if s.eq_ignore_ascii_case("tomorrow") {
+            1
+        } else {
+            return Err(Error::ParseError(try_format!(
+                "the input {} is not a relative date keyword",
+                s
+            )?));
+        };
+        let reference = match reference {
+            Some(date) => date,
+            None => Date::now()?,
+        };
+        reference.add_days(days)
+    }
+
     /// Formats `Date` by given format string.
     #[inline]
     pub fn format<S: AsRef<str>>(self, fmt: S) -> Result<impl Display> {
@@ -234,6 +688,12 @@ impl Date {
         Ok(LazyFormat::new(fmt, self))
     }
 
+    /// Formats `Date` into a new `String` by given formatter, which can be reused across calls.
+    #[inline]
+    pub fn to_string_with(self, fmt: &Formatter) -> Result<String> {
+        fmt.format_to_string(self)
+    }
+
     /// Parses `Date` from given string and format.
     #[inline]
     pub fn parse<S1: AsRef<str>, S2: AsRef<str>>(input: S1, fmt: S2) -> Result<Self> {
@@ -241,6 +701,34 @@ impl Date {
         fmt.parse(input)
     }
 
+    /// Parses `Date` from given string and format, returns `default` if the input cannot be
+    /// parsed.
+    #[inline]
+    pub fn parse_or<S1: AsRef<str>, S2: AsRef<str>>(input: S1, fmt: S2, default: Self) -> Self {
+        Date::parse(input, fmt).unwrap_or(default)
+    }
+
+    /// Parses `Date` from given string and format, returns the result of `f` if the input
+    /// cannot be parsed.
+    #[inline]
+    pub fn parse_or_else<S1: AsRef<str>, S2: AsRef<str>, F: FnOnce() -> Self>(
+        input: S1,
+        fmt: S2,
+        f: F,
+    ) -> Self {
+        Date::parse(input, fmt).unwrap_or_else(|_| f())
+    }
+
+    /// Parses `Date` from given string and format, returns the local system date if the
+    /// input cannot be parsed.
+    #[inline]
+    pub fn parse_or_now<S1: AsRef<str>, S2: AsRef<str>>(input: S1, fmt: S2) -> Result<Self> {
+        match Date::parse(input, fmt) {
+            Ok(date) => Ok(date),
+            Err(_) => Date::now(),
+        }
+    }
+
     /// Makes a new `Timestamp` from the current date and 00:00:00.
     #[inline(always)]
     pub(crate) const fn and_zero_time(self) -> Timestamp {
@@ -300,14 +788,36 @@ impl Date {
     /// The last day of the month is not considered.
     #[inline]
     pub fn add_months(self, months: i32) -> Result<Date> {
-        let (new_year, new_month, new_day) =
-            self.add_months_internal::<true, true, false>(months)?;
-        Date::try_from_ymd(new_year, new_month, new_day)
+        self.add_months_with(months, MonthOverflow::ClampToMonthEnd)
     }
 
-    /// `Date` adds months.
-    /// The date will be adjusted.
-    /// The last day of the month is considered.
+    /// `Date` adds months, handling a day that does not exist in the resulting month as
+    /// specified by `overflow`.
+    #[inline]
+    pub fn add_months_with(self, months: i32, overflow: MonthOverflow) -> Result<Date> {
+        let (new_year, new_month, day) = self.add_months_internal::<true, false, false>(months)?;
+        match overflow {
+            MonthOverflow::Error => Date::try_from_ymd(new_year, new_month, day),
+            MonthOverflow::ClampToMonthEnd => Date::try_from_ymd(
+                new_year,
+                new_month,
+                min(day, days_of_month(new_year, new_month)),
+            ),
+            MonthOverflow::Overflow => {
+                Date::try_from_ymd(new_year, new_month, 1)?.add_days(day as i32 - 1)
+            }
+        }
+    }
+
+    /// `Date` adds months, preserving the end of month, a convention common in finance.
+    ///
+    /// If this `Date` is the last day of its month, the result snaps to the last day of the
+    /// resulting month, e.g. `2021-02-28` plus one month is `2021-03-31`. Otherwise the day is
+    /// kept and clamped to the month end, e.g. `2021-02-27` plus one month is `2021-03-27`, and
+    /// `2021-01-30` plus one month is `2021-02-28`.
+    ///
+    /// Note that adding an `IntervalYM` keeps the literal day instead.
+    #[doc(alias = "add_months_preserve_eom")]
     #[inline]
     pub fn add_months2(self, months: i32) -> Result<Date> {
         let (new_year, new_month, new_day) =
@@ -315,12 +825,15 @@ impl Date {
         Date::try_from_ymd(new_year, new_month, new_day)
     }
 
+    /// An alias of [`add_months2`](Date::add_months2).
     #[inline]
-    pub(crate) fn add_interval_ym_internal(self, interval: IntervalYM) -> Result<Date> {
-        let (new_year, new_month, day) =
-            self.add_months_internal::<false, false, false>(interval.months())?;
+    pub fn add_months_preserve_eom(self, months: i32) -> Result<Date> {
+        self.add_months2(months)
+    }
 
-        Date::try_from_ymd(new_year, new_month, day)
+    #[inline]
+    pub(crate) fn add_interval_ym_internal(self, interval: IntervalYM) -> Result<Date> {
+        self.add_months_with(interval.months(), MonthOverflow::Error)
     }
 
     /// `Date` adds `IntervalYM`
@@ -331,11 +844,38 @@ impl Date {
     }
 
     /// `Date` adds `IntervalDT`
+    ///
+    /// Returns `DateUnderflow` error if the result is before the minimum value, or
+    /// `DateOverflow` error if it is after the maximum value.
     #[inline]
     pub const fn add_interval_dt(self, interval: IntervalDT) -> Result<Timestamp> {
         self.and_zero_time().add_interval_dt(interval)
     }
 
+    /// `Date` adds `IntervalYM`, returning a `Date` so that date-level chains keep the type,
+    /// e.g. `date.add_interval_ym_date(interval)?.trunc_month()?`.
+    /// The date will not be adjusted.
+    #[inline]
+    pub fn add_interval_ym_date(self, interval: IntervalYM) -> Result<Date> {
+        self.add_interval_ym_internal(interval)
+    }
+
+    /// `Date` adds `IntervalDT` of whole days, returning a `Date` so that date-level chains keep
+    /// the type.
+    ///
+    /// Returns `InvalidInterval` error if the interval is not a whole number of days, since the
+    /// result would not stay at midnight, and the same range errors as
+    /// [`add_interval_dt`](Date::add_interval_dt).
+    #[inline]
+    pub fn add_interval_dt_date(self, interval: IntervalDT) -> Result<Date> {
+        if interval.usecs() % USECONDS_PER_DAY != 0 {
+            return Err(Error::InvalidInterval(
+                "the interval is not a whole number of days".try_to_string()?,
+            ));
+        }
+        Ok(self.add_interval_dt(interval)?.extract().0)
+    }
+
     /// `Date` adds `Time`
     #[inline]
     pub const fn add_time(self, time: Time) -> Timestamp {
@@ -343,21 +883,90 @@ impl Date {
     }
 
     /// `Date` subtracts `Date`. Returns the difference in days between two `Date`
+    ///
+    /// This never overflows: both dates are within `0001-01-01..=9999-12-31`, so the
+    /// difference is within `-3652058..=3652058`, far inside the range of `i32`.
     #[inline]
     pub const fn sub_date(self, date: Date) -> i32 {
         self.days() - date.days()
     }
 
-    ///`Date` subtracts days.
+    /// Checked `Date` subtracts `Date`. Returns the difference in days between two `Date`,
+    /// or `None` if overflow occurred.
+    ///
+    /// As explained in [`sub_date`](Date::sub_date), this always returns `Some` for valid dates.
     #[inline]
-    pub const fn sub_days(self, days: i32) -> Result<Date> {
-        let result = self.days().checked_sub(days);
-        match result {
+    pub const fn checked_sub_date(self, date: Date) -> Option<i32> {
+        self.days().checked_sub(date.days())
+    }
+
+    /// Saturating `Date` subtracts `Date`. Returns the difference in days between two `Date`,
+    /// saturating at the bounds of `i32` instead of overflowing.
+    ///
+    /// As explained in [`sub_date`](Date::sub_date), this never saturates for valid dates.
+    #[inline]
+    pub const fn saturating_sub_date(self, date: Date) -> i32 {
+        self.days().saturating_sub(date.days())
+    }
+
+    /// Gets the number of whole weeks from `date` to `self`, i.e. the floor of the difference
+    /// in days divided by 7.
+    #[inline]
+    pub const fn weeks_between(self, date: Date) -> i32 {
+        self.sub_date(date).div_euclid(7)
+    }
+
+    /// Gets the number of ISO week boundaries crossed from `date` to `self`, i.e. the difference
+    /// of their ISO weeks starting on Monday.
+    ///
+    /// Unlike [`weeks_between`](Date::weeks_between), a Sunday and the following Monday are
+    /// one week apart, while a Monday and the following Sunday are in the same week.
+    #[inline]
+    pub const fn iso_weeks_between(self, date: Date) -> i32 {
+        (self.iso_week_monday_days() - date.iso_week_monday_days()) / 7
+    }
+
+    /// Gets the days from Unix Epoch of the Monday starting the ISO week of this `Date`.
+    #[inline]
+    const fn iso_week_monday_days(self) -> i32 {
+        // Unix Epoch is Thursday, the fourth day of its ISO week
+        self.days() - (self.days() + 3).rem_euclid(7)
+    }
+
+    ///`Date` subtracts days.
+    #[inline]
+    pub const fn sub_days(self, days: i32) -> Result<Date> {
+        let result = self.days().checked_sub(days);
+        match result {
             Some(d) => Date::try_from_days(d),
             None => Err(Error::DateOutOfRange),
         }
     }
 
+    /// `Date` adds days, wrapping around within the window of `window_len` days
+    /// starting at `window_start`, i.e. `[window_start, window_start + window_len)`.
+    ///
+    /// This is useful for calendar grids, e.g. a 42-day month view.
+    #[inline]
+    pub const fn wrapping_add_days(
+        self,
+        days: i32,
+        window_start: Date,
+        window_len: i32,
+    ) -> Result<Date> {
+        if window_len <= 0 {
+            return Err(Error::InvalidNumber);
+        }
+
+        let offset =
+            (self.sub_date(window_start) as i64 + days as i64).rem_euclid(window_len as i64);
+        let result = window_start.days() as i64 + offset;
+        if result > i32::MAX as i64 {
+            return Err(Error::DateOutOfRange);
+        }
+        Date::try_from_days(result as i32)
+    }
+
     /// `Date` subtracts `Timestamp`
     #[inline]
     pub const fn sub_timestamp(self, timestamp: Timestamp) -> IntervalDT {
@@ -371,11 +980,33 @@ impl Date {
     }
 
     /// `Date` subtracts `IntervalDT`
+    ///
+    /// Returns `DateUnderflow` error if the result is before the minimum value, or
+    /// `DateOverflow` error if it is after the maximum value.
     #[inline]
     pub const fn sub_interval_dt(self, interval: IntervalDT) -> Result<Timestamp> {
         self.and_zero_time().sub_interval_dt(interval)
     }
 
+    /// `Date` subtracts `IntervalYM`, returning a `Date` as
+    /// [`add_interval_ym_date`](Date::add_interval_ym_date).
+    #[inline]
+    pub fn sub_interval_ym_date(self, interval: IntervalYM) -> Result<Date> {
+        self.add_interval_ym_internal(-interval)
+    }
+
+    /// `Date` subtracts `IntervalDT` of whole days, returning a `Date` as
+    /// [`add_interval_dt_date`](Date::add_interval_dt_date).
+    #[inline]
+    pub fn sub_interval_dt_date(self, interval: IntervalDT) -> Result<Date> {
+        if interval.usecs() % USECONDS_PER_DAY != 0 {
+            return Err(Error::InvalidInterval(
+                "the interval is not a whole number of days".try_to_string()?,
+            ));
+        }
+        Ok(self.sub_interval_dt(interval)?.extract().0)
+    }
+
     /// `Date` subtracts `Time`
     #[inline]
     pub const fn sub_time(self, time: Time) -> Result<Timestamp> {
@@ -395,6 +1026,114 @@ impl Date {
         WeekDay::from(date as usize + 1)
     }
 
+    /// Extract ISO day of week (1..=7 Monday..=Sunday)
+    #[inline]
+    pub fn iso_day_of_week(self) -> u32 {
+        match self.day_of_week() {
+            WeekDay::Sunday => 7,
+            week_day => week_day as u32 - 1,
+        }
+    }
+
+    /// Checks if the date is on a weekend, i.e. Saturday or Sunday.
+    #[inline]
+    pub fn is_weekend(self) -> bool {
+        matches!(self.day_of_week(), WeekDay::Saturday | WeekDay::Sunday)
+    }
+
+    /// Checks if the date is on a weekday, i.e. Monday to Friday.
+    #[inline]
+    pub fn is_weekday(self) -> bool {
+        !self.is_weekend()
+    }
+
+    /// Gets the Sunday on or before the date, i.e. the first day of a Sunday-based week.
+    #[inline]
+    pub fn sunday_on_or_before(self) -> Result<Date> {
+        self.sub_days(self.day_of_week() as i32 - WeekDay::Sunday as i32)
+    }
+
+    /// Gets the Saturday on or after the date, i.e. the last day of a Sunday-based week.
+    #[inline]
+    pub fn saturday_on_or_after(self) -> Result<Date> {
+        self.add_days(WeekDay::Saturday as i32 - self.day_of_week() as i32)
+    }
+
+    /// Gets the Monday on or before the date, i.e. the first day of an ISO week.
+    #[inline]
+    pub fn monday_on_or_before(self) -> Result<Date> {
+        self.sub_days(self.iso_day_of_week() as i32 - 1)
+    }
+
+    /// Gets the Sunday on or after the date, i.e. the last day of an ISO week.
+    #[inline]
+    pub fn sunday_on_or_after(self) -> Result<Date> {
+        self.add_days(7 - self.iso_day_of_week() as i32)
+    }
+
+    /// Gets the coarse bucket of the time from the midnight of `Date` to `now`, see
+    /// [`Timestamp::humanize_since`].
+    #[inline]
+    pub fn humanize_since(self, now: Timestamp) -> RelativeTime {
+        self.and_zero_time().humanize_since(now)
+    }
+
+    /// Gets the `n`th (1-based) business day of the given month, counting the weekdays from the
+    /// first day of month, e.g. the 3rd business day of `2021-05` is `2021-05-05`, as the month
+    /// starts on a Saturday.
+    ///
+    /// Returns `InvalidDay` if `n` is 0 or exceeds the number of business days in the month.
+    #[inline]
+    pub fn nth_business_day_of_month(year: i32, month: u32, n: u32) -> Result<Date> {
+        Date::nth_business_day_of_month_with(year, month, n, &DEFAULT_WEEKEND)
+    }
+
+    /// Gets the `n`th (1-based) business day of the given month like
+    /// [`Date::nth_business_day_of_month`], with the days in `weekend` as non-business days.
+    #[inline]
+    pub fn nth_business_day_of_month_with(
+        year: i32,
+        month: u32,
+        n: u32,
+        weekend: &[WeekDay],
+    ) -> Result<Date> {
+        let first_day = Date::try_from_ymd(year, month, 1)?;
+        let mut count = 0;
+        for day in 0..days_of_month(year, month) {
+            let date = unsafe { Date::from_days_unchecked(first_day.0 + day as i32) };
+            if !weekend.contains(&date.day_of_week()) {
+                count += 1;
+                if count == n {
+                    return Ok(date);
+                }
+            }
+        }
+        Err(Error::InvalidDay)
+    }
+
+    /// Gets which business day of its month this `Date` is, counting the weekdays from the first
+    /// day of month, e.g. `3` for `2021-05-05`. Returns `0` if the date is on a weekend.
+    #[inline]
+    pub fn business_day_of_month(self) -> u32 {
+        self.business_day_of_month_with(&DEFAULT_WEEKEND)
+    }
+
+    /// Gets which business day of its month this `Date` is like [`Date::business_day_of_month`],
+    /// with the days in `weekend` as non-business days.
+    #[inline]
+    pub fn business_day_of_month_with(self, weekend: &[WeekDay]) -> u32 {
+        if weekend.contains(&self.day_of_week()) {
+            return 0;
+        }
+        let first_day = self.0 - self.day_of_month() as i32 + 1;
+        (first_day..=self.0)
+            .filter(|&days| {
+                let date = unsafe { Date::from_days_unchecked(days) };
+                !weekend.contains(&date.day_of_week())
+            })
+            .count() as u32
+    }
+
     /// Get local system date
     #[inline]
     pub fn now() -> Result<Date> {
@@ -402,9 +1141,65 @@ impl Date {
         Date::try_from_ymd(now.year(), now.month(), now.day())
     }
 
+    /// Gets the date of Easter Sunday in the given year, using the Gregorian computus
+    /// (the Anonymous algorithm, also known as Meeus/Jones/Butcher algorithm).
+    #[inline]
+    pub const fn easter(year: i32) -> Result<Date> {
+        if year < DATE_MIN_YEAR || year > DATE_MAX_YEAR {
+            return Err(Error::DateOutOfRange);
+        }
+
+        let a = year % 19;
+        let b = year / 100;
+        let c = year % 100;
+        let d = b / 4;
+        let e = b % 4;
+        let f = (b + 8) / 25;
+        let g = (b - f + 1) / 3;
+        let h = (19 * a + b - d - g + 15) % 30;
+        let i = c / 4;
+        let k = c % 4;
+        let l = (32 + 2 * e + 2 * i - h - k) % 7;
+        let m = (a + 11 * h + 22 * l) / 451;
+        let month = (h + l - 7 * m + 114) / 31;
+        let day = (h + l - 7 * m + 114) % 31 + 1;
+
+        Ok(unsafe { Date::from_ymd_unchecked(year, month as u32, day as u32) })
+    }
+
+    /// Gets the date of Orthodox Easter Sunday in the given year, using the Julian computus
+    /// (the Meeus Julian algorithm). The result is converted to the Gregorian calendar.
+    #[inline]
+    pub const fn orthodox_easter(year: i32) -> Result<Date> {
+        if year < DATE_MIN_YEAR || year > DATE_MAX_YEAR {
+            return Err(Error::DateOutOfRange);
+        }
+
+        let a = year % 4;
+        let b = year % 7;
+        let c = year % 19;
+        let d = (19 * c + 15) % 30;
+        let e = (2 * a + 4 * b - d + 34) % 7;
+        let month = (d + e + 114) / 31;
+        let day = (d + e + 114) % 31 + 1;
+
+        // Converts the Julian calendar date to Julian day
+        let y = year + 4800;
+        let m = month - 3;
+        let julian = day + (153 * m + 2) / 5 + 365 * y + y / 4 - 32083;
+
+        Date::try_from_days(julian - UNIX_EPOCH_JULIAN)
+    }
+
     /// Converts date to ISO year.
     #[inline]
     fn date_to_iso_year(self) -> i32 {
+        self.iso_year_week().0
+    }
+
+    /// Converts date to `(ISO year, ISO week)`.
+    #[inline]
+    pub(crate) fn iso_year_week(self) -> (i32, u32) {
         // Converts Julian date to day-of-week (0..6 == Mon..Sun)
         #[inline]
         fn week_day_of_julian(date: i32) -> i32 {
@@ -417,7 +1212,7 @@ impl Date {
             date
         }
 
-        let mut year = self.year().unwrap();
+        let mut year = self.extract().0;
         // current day
         let current_julian_day = self.days() + UNIX_EPOCH_JULIAN;
         // fourth day of current year
@@ -435,16 +1230,17 @@ impl Date {
 
         // Sometimes the last few days in a year will fall into the first week of
         // the next year, so check for this
-        let num_of_week = (current_julian_day - (fourth_julian_day - offset_to_monday)) / 7 + 1;
+        let mut num_of_week = (current_julian_day - (fourth_julian_day - offset_to_monday)) / 7 + 1;
         if num_of_week >= 52 {
             fourth_julian_day = date2julian(year + 1, 1, 4);
             offset_to_monday = week_day_of_julian(fourth_julian_day);
             if current_julian_day >= fourth_julian_day - offset_to_monday {
                 year += 1;
+                num_of_week = 1;
             }
         }
 
-        year
+        (year, num_of_week as u32)
     }
 
     #[inline]
@@ -493,12 +1289,103 @@ impl Date {
 
         unsafe { Date::from_days_unchecked(result) }
     }
+
+    /// Gets the day of month (1..=31) of this `Date`.
+    #[inline]
+    pub const fn day_of_month(self) -> u32 {
+        self.extract().2
+    }
+
+    /// Gets the number of days in the month of this `Date`.
+    #[inline]
+    pub const fn days_in_current_month(self) -> u32 {
+        let (year, month, _) = self.extract();
+        days_of_month(year, month)
+    }
+
+    /// Gets the number of days after this `Date` until the end of its month, e.g. `0` for the
+    /// last day of month.
+    #[inline]
+    pub const fn days_remaining_in_month(self) -> u32 {
+        let (year, month, day) = self.extract();
+        days_of_month(year, month) - day
+    }
+
+    /// Checks if this `Date` is the first day of its month.
+    #[inline]
+    pub const fn is_first_day_of_month(self) -> bool {
+        self.extract().2 == 1
+    }
+
+    /// Checks if this `Date` is the last day of its month, e.g. `2021-02-28` or `2020-02-29`.
+    #[inline]
+    pub const fn is_last_day_of_month(self) -> bool {
+        self.days_remaining_in_month() == 0
+    }
+
+    /// Checks if this `Date` is the first day of its year, i.e. January 1.
+    #[inline]
+    pub const fn is_first_day_of_year(self) -> bool {
+        matches!(self.extract(), (_, 1, 1))
+    }
+
+    /// Checks if this `Date` is the last day of its year, i.e. December 31.
+    #[inline]
+    pub const fn is_last_day_of_year(self) -> bool {
+        matches!(self.extract(), (_, 12, 31))
+    }
+
+    /// Makes a new `Date` with the year replaced, returns error if the result is invalid,
+    /// e.g. `2020-02-29` with year `2021`.
+    #[inline]
+    pub const fn with_year(self, year: i32) -> Result<Date> {
+        let (_, month, day) = self.extract();
+        Date::try_from_ymd(year, month, day)
+    }
+
+    /// Makes a new `Date` with the month replaced, returns error if the result is invalid,
+    /// e.g. `2021-01-31` with month `2`.
+    #[inline]
+    pub const fn with_month(self, month: u32) -> Result<Date> {
+        let (year, _, day) = self.extract();
+        Date::try_from_ymd(year, month, day)
+    }
+
+    /// Makes a new `Date` with the month replaced, and the day clamped to the last day of
+    /// that month, e.g. `2021-01-31` with month `2` is `2021-02-28`.
+    #[inline]
+    pub const fn with_month_clamped(self, month: u32) -> Result<Date> {
+        if month < 1 || month > MONTHS_PER_YEAR {
+            return Err(Error::InvalidMonth);
+        }
+
+        let (year, _, day) = self.extract();
+        let last_day = days_of_month(year, month);
+        let day = if day > last_day { last_day } else { day };
+        Ok(unsafe { Date::from_ymd_unchecked(year, month, day) })
+    }
+
+    /// Makes a new `Date` with the day of month replaced, returns error if the result is invalid,
+    /// e.g. `2021-02-01` with day `31`.
+    #[inline]
+    pub const fn with_day(self, day: u32) -> Result<Date> {
+        let (year, month, _) = self.extract();
+        Date::try_from_ymd(year, month, day)
+    }
+
+    /// Makes a new `Date` with the day of year replaced, returns error if the result is invalid,
+    /// e.g. `2021-01-01` with ordinal `366`.
+    #[inline]
+    pub const fn with_ordinal(self, ordinal: u32) -> Result<Date> {
+        let (year, _, _) = self.extract();
+        Date::try_from_ordinal(year, ordinal)
+    }
 }
 
 impl Trunc for Date {
     #[inline]
     fn trunc_century(self) -> Result<Self> {
-        let mut year = self.year().unwrap();
+        let mut year = self.extract().0;
 
         if year % 100 == 0 {
             year -= 1;
@@ -510,7 +1397,7 @@ impl Trunc for Date {
 
     #[inline]
     fn trunc_year(self) -> Result<Self> {
-        Ok(unsafe { Date::from_ymd_unchecked(self.year().unwrap(), 1, 1) })
+        Ok(unsafe { Date::from_ymd_unchecked(self.extract().0, 1, 1) })
     }
 
     #[inline]
@@ -541,7 +1428,7 @@ impl Trunc for Date {
     #[inline]
     fn trunc_week(self) -> Result<Self> {
         let trunc_day =
-            self.sub_date(unsafe { Date::from_ymd_unchecked(self.year().unwrap(), 1, 1) }) % 7;
+            self.sub_date(unsafe { Date::from_ymd_unchecked(self.extract().0, 1, 1) }) % 7;
         let res_date = self.sub_days(trunc_day)?;
         Ok(res_date)
     }
@@ -566,7 +1453,7 @@ impl Trunc for Date {
 
     #[inline]
     fn trunc_month_start_week(self) -> Result<Self> {
-        let remain_day = self.day().unwrap() % 7;
+        let remain_day = self.extract().2 as i32 % 7;
         let trunc_day = if remain_day == 0 { 6 } else { remain_day - 1 };
         let res_date = self.sub_days(trunc_day)?;
         Ok(res_date)
@@ -607,7 +1494,7 @@ fn sub_to_date(date: Date, sub_day: i32) -> Result<Date> {
 impl Round for Date {
     #[inline]
     fn round_century(self) -> Result<Self> {
-        let input_year = self.year().unwrap();
+        let input_year = self.extract().0;
         if input_year > DATE_MAX_YEAR - 50 {
             return Err(Error::DateOutOfRange);
         }
@@ -696,7 +1583,7 @@ impl Round for Date {
 
     #[inline]
     fn round_week(self) -> Result<Self> {
-        self.round_week_internal(self.year().unwrap())
+        self.round_week_internal(self.extract().0)
     }
 
     #[inline]
@@ -719,7 +1606,7 @@ impl Round for Date {
 
     #[inline]
     fn round_month_start_week(self) -> Result<Self> {
-        self.round_month_start_week_internal(self.day().unwrap())
+        self.round_month_start_week_internal(self.extract().2 as i32)
     }
 
     #[inline]
@@ -770,6 +1657,13 @@ impl From<Date> for NaiveDateTime {
     }
 }
 
+impl Hash for Date {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.and_zero_time().hash(state)
+    }
+}
+
 impl PartialEq<Timestamp> for Date {
     #[inline]
     fn eq(&self, other: &Timestamp) -> bool {
@@ -836,12 +1730,101 @@ impl DateTime for Date {
         None
     }
 
+    #[inline(always)]
+    fn microsecond(&self) -> Option<u32> {
+        None
+    }
+
     #[inline(always)]
     fn date(&self) -> Option<Date> {
         Some(*self)
     }
 }
 
+/// Returns an iterator over the dates from `start` to `end`, both inclusive.
+///
+/// The iterator is empty if `start` is after `end`.
+#[inline]
+pub const fn date_range_inclusive(start: Date, end: Date) -> DateRangeInclusive {
+    DateRangeInclusive {
+        front: start.days(),
+        back: end.days(),
+    }
+}
+
+/// An iterator over the dates of an inclusive range, created by [`date_range_inclusive`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct DateRangeInclusive {
+    front: i32,
+    back: i32,
+}
+
+impl Iterator for DateRangeInclusive {
+    type Item = Date;
+
+    #[inline]
+    fn next(&mut self) -> Option<Date> {
+        if self.front > self.back {
+            return None;
+        }
+        let date = unsafe { Date::from_days_unchecked(self.front) };
+        self.front += 1;
+        Some(date)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Date> {
+        self.next_back()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Date> {
+        if n >= self.len() {
+            self.front = self.back + 1;
+            return None;
+        }
+        self.front += n as i32;
+        self.next()
+    }
+}
+
+impl DoubleEndedIterator for DateRangeInclusive {
+    #[inline]
+    fn next_back(&mut self) -> Option<Date> {
+        if self.front > self.back {
+            return None;
+        }
+        let date = unsafe { Date::from_days_unchecked(self.back) };
+        self.back -= 1;
+        Some(date)
+    }
+}
+
+impl ExactSizeIterator for DateRangeInclusive {
+    #[inline]
+    fn len(&self) -> usize {
+        if self.front > self.back {
+            0
+        } else {
+            // Both ends are valid dates, so the difference cannot overflow
+            (self.back - self.front) as usize + 1
+        }
+    }
+}
+
+impl FusedIterator for DateRangeInclusive {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1076,75 +2059,527 @@ mod tests {
     }
 
     #[test]
-    fn test_add_sub_days() {
-        let upper_date = Date::try_from_ymd(9999, 12, 31).unwrap();
-        let lower_date = Date::try_from_ymd(1, 1, 1).unwrap();
+    fn test_quarter() {
+        let quarters = [
+            (Quarter::Q1, "Q1", [1, 2, 3]),
+            (Quarter::Q2, "Q2", [4, 5, 6]),
+            (Quarter::Q3, "Q3", [7, 8, 9]),
+            (Quarter::Q4, "Q4", [10, 11, 12]),
+        ];
+        for (i, (quarter, name, months)) in quarters.iter().enumerate() {
+            assert_eq!(*quarter as usize, i + 1);
+            assert_eq!(quarter.name(), *name);
+            assert_eq!(quarter.to_string(), *name);
+            assert_eq!(Quarter::from_name(name), Some(*quarter));
+            assert_eq!(Quarter::from_name(&name.to_lowercase()), Some(*quarter));
+            for month in months.iter() {
+                assert_eq!(Quarter::from_month(*month), Some(*quarter));
+                assert_eq!(generate_date(2021, *month, 1).quarter_enum(), *quarter);
+                let last_day = generate_date(2021, *month, 1).last_day_of_month();
+                assert_eq!(last_day.quarter_enum(), *quarter);
+            }
+        }
 
-        // Out of range
-        assert!(lower_date.add_days(i32::MAX).is_err());
-        assert!(lower_date.add_days(234253258).is_err());
-        assert!(upper_date.add_days(1).is_err());
-        assert!(upper_date.add_days(i32::MIN).is_err());
+        assert_eq!(Quarter::from_month(0), None);
+        assert_eq!(Quarter::from_month(13), None);
+        assert_eq!(Quarter::from_name(""), None);
+        assert_eq!(Quarter::from_name("Q0"), None);
+        assert_eq!(Quarter::from_name("Q5"), None);
+        assert_eq!(Quarter::from_name(" Q1"), None);
+        assert_eq!(Quarter::from_name("Quarter1"), None);
 
-        assert!(lower_date.sub_days(1).is_err());
-        assert!(upper_date.sub_days(234253258).is_err());
-        assert!(lower_date.sub_days(i32::MAX).is_err());
-        assert!(upper_date.sub_days(i32::MIN).is_err());
+        assert_eq!(Date::MIN.quarter_enum(), Quarter::Q1);
+        assert_eq!(Date::MAX.quarter_enum(), Quarter::Q4);
+    }
 
-        // Normal
-        assert_eq!(upper_date.sub_days(0).unwrap(), upper_date);
-        assert_eq!(upper_date.add_days(0).unwrap(), upper_date);
-        assert_eq!(
-            upper_date.sub_days(366).unwrap(),
-            Date::try_from_ymd(9998, 12, 30).unwrap()
-        );
-        assert_eq!(
-            lower_date.add_days(366).unwrap(),
-            Date::try_from_ymd(2, 1, 2).unwrap()
-        );
+    #[test]
+    fn test_week_day_month_all() {
+        let week_days = WeekDay::all();
+        assert_eq!(week_days.len(), 7);
+        assert_eq!(week_days[0], WeekDay::Sunday);
+        assert_eq!(week_days[6], WeekDay::Saturday);
+        for (i, week_day) in week_days.iter().enumerate() {
+            assert_eq!(*week_day as usize, i + 1);
+            assert_eq!(WeekDay::from(i + 1), *week_day);
+        }
 
-        let date = Date::try_from_ymd(5000, 6, 15).unwrap();
-        assert_eq!(
-            date.add_days(718).unwrap(),
-            Date::try_from_ymd(5002, 6, 3).unwrap()
-        );
-        assert_eq!(
-            date.sub_days(718).unwrap(),
-            Date::try_from_ymd(4998, 6, 27).unwrap()
-        );
-        assert_eq!(date.sub_days(718).unwrap(), date.add_days(-718).unwrap());
-        assert_eq!(date.sub_days(-718).unwrap(), date.add_days(718).unwrap());
+        let iso_week_days = WeekDay::all_iso();
+        assert_eq!(iso_week_days.len(), 7);
+        assert_eq!(iso_week_days[0], WeekDay::Monday);
+        assert_eq!(iso_week_days[6], WeekDay::Sunday);
+        assert_eq!(iso_week_days[..6], week_days[1..]);
+
+        let months = Month::all();
+        assert_eq!(months.len(), 12);
+        assert_eq!(months[0], Month::January);
+        assert_eq!(months[11], Month::December);
+        for (i, month) in months.iter().enumerate() {
+            assert_eq!(*month as usize, i + 1);
+            assert_eq!(Month::from(i + 1), *month);
+        }
     }
 
     #[test]
-    fn test_add_months() {
-        let upper_date = Date::try_from_ymd(9999, 12, 31).unwrap();
-        let lower_date = Date::try_from_ymd(1, 1, 1).unwrap();
+    fn test_week_day_month_add_sub() {
+        assert_eq!(WeekDay::Saturday + 2, WeekDay::Monday);
+        assert_eq!(WeekDay::Monday - 2, WeekDay::Saturday);
+        assert_eq!(WeekDay::Sunday + 0, WeekDay::Sunday);
+        assert_eq!(WeekDay::Sunday + 7, WeekDay::Sunday);
+        assert_eq!(WeekDay::Sunday + 15, WeekDay::Monday);
+        assert_eq!(WeekDay::Sunday + -1, WeekDay::Saturday);
+        assert_eq!(WeekDay::Sunday - -1, WeekDay::Monday);
+        assert_eq!(WeekDay::Wednesday - 702, WeekDay::Monday);
+        assert_eq!(WeekDay::Thursday + i32::MAX, WeekDay::Friday);
+        assert_eq!(WeekDay::Thursday - i32::MIN, WeekDay::Saturday);
+
+        // Same as the weekday of the shifted date
+        let date = Date::try_from_ymd(2021, 10, 24).unwrap();
+        for days in [-1000, -8, -1, 0, 1, 6, 100] {
+            assert_eq!(
+                date.day_of_week() + days,
+                date.add_days(days).unwrap().day_of_week()
+            );
+        }
 
-        // Out of range
-        assert!(lower_date.add_months(i32::MAX).is_err());
-        assert!(lower_date.add_months(234253258).is_err());
-        assert!(lower_date.add_months(9999 * 12).is_err());
-        assert!(upper_date.add_months(1).is_err());
-        assert!(upper_date.add_months(i32::MIN).is_err());
-        assert!(upper_date.add_months(-(9999 * 12)).is_err());
+        assert_eq!(Month::November + 3, Month::February);
+        assert_eq!(Month::February - 3, Month::November);
+        assert_eq!(Month::June + 3, Month::September);
+        assert_eq!(Month::January + 12, Month::January);
+        assert_eq!(Month::January + 25, Month::February);
+        assert_eq!(Month::January + -1, Month::December);
+        assert_eq!(Month::December - -1, Month::January);
+        assert_eq!(Month::March - 38, Month::January);
+        assert_eq!(Month::January + i32::MAX, Month::August);
+        assert_eq!(Month::January - i32::MIN, Month::September);
+    }
+
+    #[test]
+    fn test_try_from_ymd_clamped() {
+        let ymd = |y, m, d| Date::try_from_ymd(y, m, d).unwrap();
 
-        // Normal
-        assert_eq!(upper_date.add_months(0).unwrap(), upper_date);
         assert_eq!(
-            upper_date.add_months(-13).unwrap(),
-            Date::try_from_ymd(9998, 11, 30).unwrap()
+            Date::try_from_ymd_clamped(2021, 6, 1),
+            (Ok(ymd(2021, 6, 1)), false)
         );
         assert_eq!(
-            upper_date.add_months(-(9999 * 12 - 1)).unwrap(),
-            Date::try_from_ymd(1, 1, 31).unwrap()
+            Date::try_from_ymd_clamped(0, 6, 1),
+            (Ok(ymd(1, 6, 1)), true)
         );
         assert_eq!(
-            lower_date.add_months(13).unwrap(),
-            Date::try_from_ymd(2, 2, 1).unwrap()
+            Date::try_from_ymd_clamped(-100_000, 1, 1),
+            (Ok(ymd(1, 1, 1)), true)
         );
         assert_eq!(
-            lower_date.add_months(9999 * 12 - 1).unwrap(),
+            Date::try_from_ymd_clamped(10000, 12, 31),
+            (Ok(ymd(9999, 12, 31)), true)
+        );
+        assert_eq!(
+            Date::try_from_ymd_clamped(i64::MAX, 6, 15),
+            (Ok(ymd(9999, 6, 15)), true)
+        );
+
+        // Day clamped to the end of month
+        assert_eq!(
+            Date::try_from_ymd_clamped(2021, 2, 29),
+            (Ok(ymd(2021, 2, 28)), true)
+        );
+        assert_eq!(
+            Date::try_from_ymd_clamped(2020, 2, 29),
+            (Ok(ymd(2020, 2, 29)), false)
+        );
+        assert_eq!(
+            Date::try_from_ymd_clamped(2021, 4, 99),
+            (Ok(ymd(2021, 4, 30)), true)
+        );
+        // Year clamped before the end of month is computed
+        assert_eq!(
+            Date::try_from_ymd_clamped(10000, 2, 29),
+            (Ok(ymd(9999, 2, 28)), true)
+        );
+
+        // Month and zero day are not clamped
+        assert_eq!(
+            Date::try_from_ymd_clamped(0, 13, 1),
+            (Err(Error::InvalidMonth), false)
+        );
+        assert_eq!(
+            Date::try_from_ymd_clamped(10000, 1, 0),
+            (Err(Error::InvalidDay), false)
+        );
+    }
+
+    #[test]
+    fn test_validate_ymd_detailed() {
+        assert!(Date::validate_ymd_detailed(2021, 6, 1).is_ok());
+        assert!(Date::validate_ymd_detailed(2020, 2, 29).is_ok());
+        assert!(Date::validate_ymd_detailed(1, 1, 1).is_ok());
+        assert!(Date::validate_ymd_detailed(9999, 12, 31).is_ok());
+
+        assert_eq!(
+            Date::validate_ymd_detailed(0, 1, 1).unwrap_err(),
+            Error::DateOutOfRange
+        );
+        assert_eq!(
+            Date::validate_ymd_detailed(10000, 1, 1).unwrap_err(),
+            Error::DateOutOfRange
+        );
+        assert_eq!(
+            Date::validate_ymd_detailed(2021, 0, 1).unwrap_err(),
+            Error::InvalidMonth
+        );
+        assert_eq!(
+            Date::validate_ymd_detailed(2021, 13, 1).unwrap_err(),
+            Error::InvalidMonth
+        );
+        assert_eq!(
+            Date::validate_ymd_detailed(2021, 6, 0).unwrap_err(),
+            Error::InvalidDay
+        );
+        assert_eq!(
+            Date::validate_ymd_detailed(2021, 6, 32).unwrap_err(),
+            Error::InvalidDay
+        );
+        assert_eq!(
+            Date::validate_ymd_detailed(2021, 2, 29).unwrap_err(),
+            Error::InvalidDate
+        );
+        assert_eq!(
+            Date::validate_ymd_detailed(2021, 6, 31).unwrap_err(),
+            Error::InvalidDate
+        );
+
+        // The first invalid field is reported
+        assert_eq!(
+            Date::validate_ymd_detailed(0, 13, 32).unwrap_err(),
+            Error::DateOutOfRange
+        );
+        assert_eq!(
+            Date::validate_ymd_detailed(2021, 13, 32).unwrap_err(),
+            Error::InvalidMonth
+        );
+
+        // Consistent with is_valid and try_from_ymd
+        for (y, m, d) in [(2021, 6, 1), (2021, 2, 29), (2021, 13, 1), (0, 1, 1)] {
+            assert_eq!(
+                Date::validate_ymd_detailed(y, m, d).is_ok(),
+                Date::is_valid(y, m, d)
+            );
+            assert_eq!(
+                Date::validate_ymd_detailed(y, m, d).err(),
+                Date::try_from_ymd(y, m, d).err()
+            );
+        }
+    }
+
+    #[test]
+    fn test_try_from_ordinal_and_iso_week_date() {
+        let ymd = |y, m, d| Date::try_from_ymd(y, m, d).unwrap();
+
+        assert_eq!(Date::try_from_ordinal(2021, 1).unwrap(), ymd(2021, 1, 1));
+        assert_eq!(Date::try_from_ordinal(2021, 152).unwrap(), ymd(2021, 6, 1));
+        assert_eq!(
+            Date::try_from_ordinal(2021, 365).unwrap(),
+            ymd(2021, 12, 31)
+        );
+        assert_eq!(
+            Date::try_from_ordinal(2020, 366).unwrap(),
+            ymd(2020, 12, 31)
+        );
+        assert_eq!(Date::try_from_ordinal(1, 1).unwrap(), Date::MIN);
+        assert_eq!(Date::try_from_ordinal(9999, 365).unwrap(), Date::MAX);
+        assert_eq!(
+            Date::try_from_ordinal(2021, 0).unwrap_err(),
+            Error::InvalidDay
+        );
+        assert_eq!(
+            Date::try_from_ordinal(2021, 366).unwrap_err(),
+            Error::InvalidDay
+        );
+        assert_eq!(
+            Date::try_from_ordinal(0, 1).unwrap_err(),
+            Error::DateOutOfRange
+        );
+        assert_eq!(
+            Date::try_from_ordinal(10000, 1).unwrap_err(),
+            Error::DateOutOfRange
+        );
+
+        assert_eq!(
+            Date::try_from_iso_week_date(2021, 22, 2).unwrap(),
+            ymd(2021, 6, 1)
+        );
+        assert_eq!(
+            Date::try_from_iso_week_date(2020, 1, 1).unwrap(),
+            ymd(2019, 12, 30)
+        );
+        assert_eq!(
+            Date::try_from_iso_week_date(2020, 53, 7).unwrap(),
+            ymd(2021, 1, 3)
+        );
+        assert_eq!(Date::try_from_iso_week_date(1, 1, 1).unwrap(), Date::MIN);
+        assert_eq!(
+            Date::try_from_iso_week_date(9999, 52, 5).unwrap(),
+            Date::MAX
+        );
+        assert_eq!(
+            Date::try_from_iso_week_date(2021, 22, 0).unwrap_err(),
+            Error::InvalidDay
+        );
+        assert_eq!(
+            Date::try_from_iso_week_date(2021, 22, 8).unwrap_err(),
+            Error::InvalidDay
+        );
+        assert_eq!(
+            Date::try_from_iso_week_date(2021, 0, 1).unwrap_err(),
+            Error::InvalidDate
+        );
+        assert_eq!(
+            Date::try_from_iso_week_date(2021, 53, 1).unwrap_err(),
+            Error::InvalidDate
+        );
+        assert_eq!(
+            Date::try_from_iso_week_date(9999, 52, 6).unwrap_err(),
+            Error::DateOutOfRange
+        );
+        assert_eq!(
+            Date::try_from_iso_week_date(0, 52, 1).unwrap_err(),
+            Error::DateOutOfRange
+        );
+
+        // Round trip with iso_year_week
+        let mut date = ymd(2015, 12, 20);
+        while date < ymd(2027, 1, 10) {
+            let (iso_year, week) = date.iso_year_week();
+            assert_eq!(
+                Date::try_from_iso_week_date(iso_year, week, date.iso_day_of_week()).unwrap(),
+                date
+            );
+            date = date.add_days(1).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_try_from_ymd_i64() {
+        assert_eq!(
+            Date::try_from_ymd_i64(2021, 6, 1).unwrap(),
+            generate_date(2021, 6, 1)
+        );
+        assert_eq!(Date::try_from_ymd_i64(1, 1, 1).unwrap(), Date::MIN);
+        assert_eq!(Date::try_from_ymd_i64(9999, 12, 31).unwrap(), Date::MAX);
+
+        // Values that would wrap into valid fields when narrowed
+        assert_eq!(
+            Date::try_from_ymd_i64(2021, 4294967297, 1).unwrap_err(),
+            Error::InvalidMonth
+        );
+        assert_eq!(
+            Date::try_from_ymd_i64(2021, 1, 4294967297).unwrap_err(),
+            Error::InvalidDay
+        );
+        assert_eq!(
+            Date::try_from_ymd_i64(4294969317, 1, 1).unwrap_err(),
+            Error::DateOutOfRange
+        );
+        assert_eq!(
+            Date::try_from_ymd_i64(-4294965275, 1, 1).unwrap_err(),
+            Error::DateOutOfRange
+        );
+        assert_eq!(
+            Date::try_from_ymd_i64(2021, -4294967295, 1).unwrap_err(),
+            Error::InvalidMonth
+        );
+        assert_eq!(
+            Date::try_from_ymd_i64(i64::MAX, i64::MIN, i64::MAX).unwrap_err(),
+            Error::DateOutOfRange
+        );
+
+        // Same errors as `try_from_ymd` for values in range
+        assert_eq!(
+            Date::try_from_ymd_i64(0, 1, 1).unwrap_err(),
+            Error::DateOutOfRange
+        );
+        assert_eq!(
+            Date::try_from_ymd_i64(2021, 13, 1).unwrap_err(),
+            Error::InvalidMonth
+        );
+        assert_eq!(
+            Date::try_from_ymd_i64(2021, 1, 0).unwrap_err(),
+            Error::InvalidDay
+        );
+        assert_eq!(
+            Date::try_from_ymd_i64(2021, 2, 29).unwrap_err(),
+            Error::InvalidDate
+        );
+    }
+
+    #[test]
+    fn test_date_iso_format() {
+        fn assert_iso(date: Date, expected: &str) {
+            assert_eq!(
+                format!("{}", date.format("IYYY-IW IYY IY I").unwrap()),
+                expected
+            );
+        }
+
+        assert_iso(generate_date(2021, 1, 1), "2020-53 020 20 0");
+        assert_iso(generate_date(2021, 1, 3), "2020-53 020 20 0");
+        assert_iso(generate_date(2021, 1, 4), "2021-01 021 21 1");
+        assert_iso(generate_date(2020, 12, 31), "2020-53 020 20 0");
+        assert_iso(generate_date(2019, 12, 29), "2019-52 019 19 9");
+        assert_iso(generate_date(2019, 12, 30), "2020-01 020 20 0");
+        assert_iso(generate_date(2018, 12, 31), "2019-01 019 19 9");
+        assert_iso(generate_date(2010, 1, 3), "2009-53 009 09 9");
+        assert_iso(generate_date(2022, 6, 15), "2022-24 022 22 2");
+        assert_iso(generate_date(1, 1, 1), "0001-01 001 01 1");
+        assert_iso(generate_date(9999, 12, 31), "9999-52 999 99 9");
+
+        // Mixed with calendar fields
+        assert_eq!(
+            format!(
+                "{}",
+                generate_date(2021, 1, 1)
+                    .format("YYYY-MM-DD WW IYYY-IW")
+                    .unwrap()
+            ),
+            "2021-01-01 01 2020-53"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                generate_date(2021, 1, 1)
+                    .and_time(Time::try_from_hms(12, 0, 0, 0).unwrap())
+                    .format("iyyy-iw hh24")
+                    .unwrap()
+            ),
+            "2020-53 12"
+        );
+
+        // Not supported in parsing
+        assert!(Date::parse("2020-53", "IYYY-IW").is_err());
+        assert!(Date::parse("2020", "IYYY").is_err());
+        assert!(Date::parse("53", "IW").is_err());
+
+        // Not supported for time
+        let mut s = String::new();
+        assert!(Formatter::try_new("IW")
+            .unwrap()
+            .format(Time::try_from_hms(1, 2, 3, 0).unwrap(), &mut s)
+            .is_err());
+    }
+
+    #[test]
+    fn test_add_sub_days() {
+        let upper_date = Date::try_from_ymd(9999, 12, 31).unwrap();
+        let lower_date = Date::try_from_ymd(1, 1, 1).unwrap();
+
+        // Out of range
+        assert!(lower_date.add_days(i32::MAX).is_err());
+        assert!(lower_date.add_days(234253258).is_err());
+        assert!(upper_date.add_days(1).is_err());
+        assert!(upper_date.add_days(i32::MIN).is_err());
+
+        assert!(lower_date.sub_days(1).is_err());
+        assert!(upper_date.sub_days(234253258).is_err());
+        assert!(lower_date.sub_days(i32::MAX).is_err());
+        assert!(upper_date.sub_days(i32::MIN).is_err());
+
+        // Normal
+        assert_eq!(upper_date.sub_days(0).unwrap(), upper_date);
+        assert_eq!(upper_date.add_days(0).unwrap(), upper_date);
+        assert_eq!(
+            upper_date.sub_days(366).unwrap(),
+            Date::try_from_ymd(9998, 12, 30).unwrap()
+        );
+        assert_eq!(
+            lower_date.add_days(366).unwrap(),
+            Date::try_from_ymd(2, 1, 2).unwrap()
+        );
+
+        let date = Date::try_from_ymd(5000, 6, 15).unwrap();
+        assert_eq!(
+            date.add_days(718).unwrap(),
+            Date::try_from_ymd(5002, 6, 3).unwrap()
+        );
+        assert_eq!(
+            date.sub_days(718).unwrap(),
+            Date::try_from_ymd(4998, 6, 27).unwrap()
+        );
+        assert_eq!(date.sub_days(718).unwrap(), date.add_days(-718).unwrap());
+        assert_eq!(date.sub_days(-718).unwrap(), date.add_days(718).unwrap());
+    }
+
+    #[test]
+    fn test_wrapping_add_days() {
+        let start = Date::try_from_ymd(2021, 9, 26).unwrap();
+        let end = Date::try_from_ymd(2021, 11, 6).unwrap();
+        assert_eq!(start.add_days(41).unwrap(), end);
+
+        // Normal
+        assert_eq!(start.wrapping_add_days(0, start, 42).unwrap(), start);
+        assert_eq!(
+            start.wrapping_add_days(7, start, 42).unwrap(),
+            Date::try_from_ymd(2021, 10, 3).unwrap()
+        );
+
+        // Wraps at the end of the window
+        assert_eq!(end.wrapping_add_days(1, start, 42).unwrap(), start);
+        assert_eq!(
+            end.wrapping_add_days(7, start, 42).unwrap(),
+            Date::try_from_ymd(2021, 10, 2).unwrap()
+        );
+        assert_eq!(start.wrapping_add_days(42 * 3, start, 42).unwrap(), start);
+
+        // Wraps at the start of the window
+        assert_eq!(start.wrapping_add_days(-1, start, 42).unwrap(), end);
+        assert_eq!(
+            start.wrapping_add_days(-7, start, 42).unwrap(),
+            Date::try_from_ymd(2021, 10, 31).unwrap()
+        );
+
+        // Date outside of the window
+        assert_eq!(
+            Date::try_from_ymd(2021, 11, 7)
+                .unwrap()
+                .wrapping_add_days(0, start, 42)
+                .unwrap(),
+            start
+        );
+
+        // Invalid window
+        assert!(start.wrapping_add_days(1, start, 0).is_err());
+        assert!(start.wrapping_add_days(1, start, -42).is_err());
+        assert!(Date::MAX.wrapping_add_days(1, Date::MAX, 42).is_err());
+    }
+
+    #[test]
+    fn test_add_months() {
+        let upper_date = Date::try_from_ymd(9999, 12, 31).unwrap();
+        let lower_date = Date::try_from_ymd(1, 1, 1).unwrap();
+
+        // Out of range
+        assert!(lower_date.add_months(i32::MAX).is_err());
+        assert!(lower_date.add_months(234253258).is_err());
+        assert!(lower_date.add_months(9999 * 12).is_err());
+        assert!(upper_date.add_months(1).is_err());
+        assert!(upper_date.add_months(i32::MIN).is_err());
+        assert!(upper_date.add_months(-(9999 * 12)).is_err());
+
+        // Normal
+        assert_eq!(upper_date.add_months(0).unwrap(), upper_date);
+        assert_eq!(
+            upper_date.add_months(-13).unwrap(),
+            Date::try_from_ymd(9998, 11, 30).unwrap()
+        );
+        assert_eq!(
+            upper_date.add_months(-(9999 * 12 - 1)).unwrap(),
+            Date::try_from_ymd(1, 1, 31).unwrap()
+        );
+        assert_eq!(
+            lower_date.add_months(13).unwrap(),
+            Date::try_from_ymd(2, 2, 1).unwrap()
+        );
+        assert_eq!(
+            lower_date.add_months(9999 * 12 - 1).unwrap(),
             Date::try_from_ymd(9999, 12, 1).unwrap()
         );
 
@@ -1186,6 +2621,305 @@ mod tests {
         assert_eq!(date.add_months(36).unwrap(), generate_date(2004, 2, 28));
     }
 
+    #[test]
+    fn test_add_months_with() {
+        let date = generate_date(2021, 1, 31);
+
+        // Strict
+        assert_eq!(
+            date.add_months_with(1, MonthOverflow::Error).unwrap_err(),
+            Error::InvalidDate
+        );
+        assert_eq!(
+            date.add_months_with(2, MonthOverflow::Error).unwrap(),
+            generate_date(2021, 3, 31)
+        );
+        assert_eq!(
+            date.add_interval_ym(IntervalYM::try_from_ym(0, 1).unwrap())
+                .unwrap_err(),
+            Error::InvalidDate
+        );
+
+        // Clamp
+        assert_eq!(
+            date.add_months_with(1, MonthOverflow::ClampToMonthEnd)
+                .unwrap(),
+            generate_date(2021, 2, 28)
+        );
+        assert_eq!(
+            date.add_months_with(13, MonthOverflow::ClampToMonthEnd)
+                .unwrap(),
+            generate_date(2022, 2, 28)
+        );
+        assert_eq!(
+            generate_date(2020, 1, 31)
+                .add_months_with(1, MonthOverflow::ClampToMonthEnd)
+                .unwrap(),
+            generate_date(2020, 2, 29)
+        );
+        assert_eq!(
+            date.add_months_with(1, MonthOverflow::ClampToMonthEnd)
+                .unwrap(),
+            date.add_months(1).unwrap()
+        );
+
+        // Overflow
+        assert_eq!(
+            date.add_months_with(1, MonthOverflow::Overflow).unwrap(),
+            generate_date(2021, 3, 3)
+        );
+        assert_eq!(
+            generate_date(2020, 1, 31)
+                .add_months_with(1, MonthOverflow::Overflow)
+                .unwrap(),
+            generate_date(2020, 3, 2)
+        );
+        assert_eq!(
+            date.add_months_with(-2, MonthOverflow::Overflow).unwrap(),
+            generate_date(2020, 12, 1)
+        );
+        assert_eq!(
+            generate_date(9999, 10, 31)
+                .add_months_with(1, MonthOverflow::Overflow)
+                .unwrap(),
+            generate_date(9999, 12, 1)
+        );
+
+        // Days that exist are kept in every mode
+        for overflow in [
+            MonthOverflow::Error,
+            MonthOverflow::ClampToMonthEnd,
+            MonthOverflow::Overflow,
+        ] {
+            assert_eq!(
+                generate_date(2021, 1, 28)
+                    .add_months_with(1, overflow)
+                    .unwrap(),
+                generate_date(2021, 2, 28)
+            );
+            assert_eq!(
+                Date::MAX.add_months_with(1, overflow).unwrap_err(),
+                Error::DateOutOfRange
+            );
+            assert_eq!(
+                Date::MIN.add_months_with(-1, overflow).unwrap_err(),
+                Error::DateOutOfRange
+            );
+            assert_eq!(
+                Date::MIN.add_months_with(i32::MAX, overflow).unwrap_err(),
+                Error::DateOutOfRange
+            );
+        }
+    }
+
+    #[test]
+    fn test_add_months_preserve_eom() {
+        // Source is the end of month
+        let feb_28 = generate_date(2021, 2, 28);
+        assert_eq!(
+            feb_28.add_months_preserve_eom(1).unwrap(),
+            generate_date(2021, 3, 31)
+        );
+        assert_eq!(
+            feb_28.add_months_preserve_eom(2).unwrap(),
+            generate_date(2021, 4, 30)
+        );
+        assert_eq!(
+            feb_28.add_months_preserve_eom(12).unwrap(),
+            generate_date(2022, 2, 28)
+        );
+        assert_eq!(
+            feb_28.add_months_preserve_eom(-1).unwrap(),
+            generate_date(2021, 1, 31)
+        );
+        assert_eq!(
+            generate_date(2021, 1, 31)
+                .add_months_preserve_eom(1)
+                .unwrap(),
+            generate_date(2021, 2, 28)
+        );
+        assert_eq!(
+            generate_date(2020, 1, 31)
+                .add_months_preserve_eom(1)
+                .unwrap(),
+            generate_date(2020, 2, 29)
+        );
+        assert_eq!(
+            generate_date(2020, 2, 29)
+                .add_months_preserve_eom(12)
+                .unwrap(),
+            generate_date(2021, 2, 28)
+        );
+        assert_eq!(
+            generate_date(2021, 4, 30)
+                .add_months_preserve_eom(1)
+                .unwrap(),
+            generate_date(2021, 5, 31)
+        );
+
+        // Source is not the end of month
+        assert_eq!(
+            generate_date(2021, 2, 27)
+                .add_months_preserve_eom(1)
+                .unwrap(),
+            generate_date(2021, 3, 27)
+        );
+        assert_eq!(
+            generate_date(2021, 1, 30)
+                .add_months_preserve_eom(1)
+                .unwrap(),
+            generate_date(2021, 2, 28)
+        );
+        assert_eq!(
+            generate_date(2020, 2, 28)
+                .add_months_preserve_eom(1)
+                .unwrap(),
+            generate_date(2020, 3, 28)
+        );
+
+        // Adding IntervalYM keeps the literal day
+        assert_eq!(
+            feb_28
+                .add_interval_ym(IntervalYM::try_from_ym(0, 1).unwrap())
+                .unwrap(),
+            generate_date(2021, 3, 28).and_zero_time()
+        );
+
+        // Out of range
+        assert_eq!(
+            Date::MAX.add_months_preserve_eom(1).unwrap_err(),
+            Error::DateOutOfRange
+        );
+    }
+
+    #[test]
+    fn test_date_fluent_chain() {
+        use crate::DateTimeUnit;
+
+        let date = generate_date(2021, 10, 15);
+        let one_month = IntervalYM::try_from_ym(0, 1).unwrap();
+        let one_week = IntervalDT::try_from_dhms(7, 0, 0, 0, 0).unwrap();
+
+        // The end of next month
+        let result = date
+            .trunc(DateTimeUnit::Month)
+            .and_then(|d| d.add_interval_ym_date(one_month))
+            .and_then(|d| d.add_interval_ym_date(one_month))
+            .and_then(|d| d.sub_days(1))
+            .unwrap();
+        assert_eq!(result, generate_date(2021, 11, 30));
+
+        let chain = || -> Result<Date> {
+            date.trunc(DateTimeUnit::Week)?
+                .add_interval_dt_date(one_week)?
+                .sub_interval_ym_date(one_month)?
+                .round(DateTimeUnit::Month)
+        };
+        assert_eq!(chain().unwrap(), generate_date(2021, 10, 1));
+
+        // Same as the Timestamp-returning methods
+        assert_eq!(
+            date.add_interval_ym_date(one_month)
+                .unwrap()
+                .and_zero_time(),
+            date.add_interval_ym(one_month).unwrap()
+        );
+        assert_eq!(
+            date.sub_interval_ym_date(one_month)
+                .unwrap()
+                .and_zero_time(),
+            date.sub_interval_ym(one_month).unwrap()
+        );
+        assert_eq!(
+            date.add_interval_dt_date(one_week).unwrap().and_zero_time(),
+            date.add_interval_dt(one_week).unwrap()
+        );
+        assert_eq!(
+            date.sub_interval_dt_date(-one_week).unwrap(),
+            generate_date(2021, 10, 22)
+        );
+
+        // Errors
+        assert_eq!(
+            generate_date(2021, 1, 31)
+                .add_interval_ym_date(one_month)
+                .unwrap_err(),
+            Error::InvalidDate
+        );
+        let one_hour = IntervalDT::try_from_dhms(0, 1, 0, 0, 0).unwrap();
+        for interval in [
+            one_hour,
+            -one_hour,
+            one_week.add_interval_dt(one_hour).unwrap(),
+        ] {
+            let expected =
+                Error::InvalidInterval("the interval is not a whole number of days".to_string());
+            assert_eq!(date.add_interval_dt_date(interval).unwrap_err(), expected);
+            assert_eq!(date.sub_interval_dt_date(interval).unwrap_err(), expected);
+        }
+        assert_eq!(
+            Date::MAX.add_interval_dt_date(one_week).unwrap_err(),
+            Error::DateOverflow
+        );
+        assert_eq!(
+            Date::MIN.sub_interval_dt_date(one_week).unwrap_err(),
+            Error::DateUnderflow
+        );
+        assert_eq!(
+            date.trunc(DateTimeUnit::Second).unwrap_err(),
+            Error::UnsupportedUnit("the unit Second is not supported by trunc".to_string())
+        );
+        assert_eq!(
+            date.round(DateTimeUnit::Microsecond).unwrap_err(),
+            Error::UnsupportedUnit("the unit Microsecond is not supported by round".to_string())
+        );
+    }
+
+    #[test]
+    fn test_date_parse_relative() {
+        let reference = generate_date(2021, 3, 1);
+        for (input, expected) in [
+            ("today", generate_date(2021, 3, 1)),
+            ("now", generate_date(2021, 3, 1)),
+            ("yesterday", generate_date(2021, 2, 28)),
+            ("tomorrow", generate_date(2021, 3, 2)),
+            ("TODAY", generate_date(2021, 3, 1)),
+            ("  Yesterday ", generate_date(2021, 2, 28)),
+            ("ToMorrow", generate_date(2021, 3, 2)),
+        ] {
+            assert_eq!(
+                Date::parse_relative(input, Some(reference)).unwrap(),
+                expected
+            );
+        }
+
+        // Defaults to today
+        let today = Date::now().unwrap();
+        let parsed = Date::parse_relative("today", None).unwrap();
+        assert!(parsed == today || parsed == today.add_days(1).unwrap());
+
+        // Out of range
+        assert_eq!(
+            Date::parse_relative("yesterday", Some(Date::MIN)).unwrap_err(),
+            Error::DateOutOfRange
+        );
+        assert_eq!(
+            Date::parse_relative("tomorrow", Some(Date::MAX)).unwrap_err(),
+            Error::DateOutOfRange
+        );
+
+        // Unrecognized
+        for input in ["", "to day", "todays", "2021-03-01", "yesterday1"] {
+            assert_eq!(
+                Date::parse_relative(input, Some(reference)).unwrap_err(),
+                Error::ParseError(format!(
+                    "the input {} is not a relative date keyword",
+                    input
+                ))
+            );
+        }
+    }
+
     #[test]
     fn test_add_months2() {
         let upper_date = Date::try_from_ymd(9999, 12, 31).unwrap();
@@ -1325,10 +3059,16 @@ mod tests {
         assert_eq!(date.sub_interval_dt(interval).unwrap(), expect);
 
         let interval = IntervalDT::try_from_dhms(1, 0, 0, 0, 1).unwrap();
-        assert!(date.add_interval_dt(interval).is_err());
+        assert_eq!(
+            date.add_interval_dt(interval).unwrap_err(),
+            Error::DateOverflow
+        );
 
         let interval = IntervalDT::try_from_dhms(12345, 12, 3, 5, 6).unwrap();
-        assert!(date.add_interval_dt(interval).is_err());
+        assert_eq!(
+            date.add_interval_dt(interval).unwrap_err(),
+            Error::DateOverflow
+        );
 
         let date = generate_date(1, 1, 1);
         let interval = IntervalDT::try_from_dhms(5, 4, 3, 2, 1).unwrap();
@@ -1336,10 +3076,34 @@ mod tests {
         assert_eq!(date.add_interval_dt(interval).unwrap(), expect);
 
         let interval = IntervalDT::try_from_dhms(0, 0, 0, 0, 1).unwrap();
-        assert!(date.sub_interval_dt(interval).is_err());
+        assert_eq!(
+            date.sub_interval_dt(interval).unwrap_err(),
+            Error::DateUnderflow
+        );
 
         let interval = IntervalDT::try_from_dhms(12345, 12, 3, 5, 6).unwrap();
-        assert!(date.sub_interval_dt(interval).is_err());
+        assert_eq!(
+            date.sub_interval_dt(interval).unwrap_err(),
+            Error::DateUnderflow
+        );
+
+        // The direction of out of range results
+        assert_eq!(
+            date.add_interval_dt(-interval).unwrap_err(),
+            Error::DateUnderflow
+        );
+        assert_eq!(
+            date.add_interval_dt(IntervalDT::MIN).unwrap_err(),
+            Error::DateUnderflow
+        );
+        assert_eq!(
+            date.sub_interval_dt(IntervalDT::MAX).unwrap_err(),
+            Error::DateUnderflow
+        );
+        assert_eq!(
+            date.add_interval_dt(IntervalDT::MAX).unwrap_err(),
+            Error::DateOverflow
+        );
     }
 
     #[test]
@@ -1462,60 +3226,343 @@ mod tests {
         let interval = IntervalYM::try_from_ym(0, 11).unwrap();
         assert!(date.add_interval_ym(-interval).is_err());
 
-        let interval = IntervalYM::try_from_ym(2, 1).unwrap();
-        assert!(date.add_interval_ym(-interval).is_err());
+        let interval = IntervalYM::try_from_ym(2, 1).unwrap();
+        assert!(date.add_interval_ym(-interval).is_err());
+
+        let date = generate_date(2000, 2, 29);
+        let interval = IntervalYM::try_from_ym(2, 0).unwrap();
+        assert!(date.add_interval_ym(interval).is_err());
+    }
+
+    #[test]
+    fn test_add_sub_time() {
+        assert_eq!(
+            Date::try_from_ymd(9999, 12, 31)
+                .unwrap()
+                .add_time(Time::try_from_hms(12, 34, 56, 999999).unwrap()),
+            generate_ts(9999, 12, 31, 12, 34, 56, 999999)
+        );
+
+        assert_eq!(
+            Date::try_from_ymd(9999, 12, 31)
+                .unwrap()
+                .sub_time(Time::try_from_hms(12, 34, 56, 999999).unwrap())
+                .unwrap(),
+            generate_ts(9999, 12, 30, 11, 25, 3, 1)
+        );
+
+        // Out of range
+        assert!(Date::try_from_ymd(1, 1, 1)
+            .unwrap()
+            .sub_time(Time::try_from_hms(12, 34, 56, 999999).unwrap())
+            .is_err());
+    }
+
+    #[test]
+    fn test_date_sub_timestamp() {
+        let upper_date = generate_date(9999, 12, 31);
+        let lower_date = generate_date(1, 1, 1);
+        let upper_ts = generate_ts(9999, 12, 31, 23, 59, 59, 999999);
+        let lower_ts = generate_ts(1, 1, 1, 0, 0, 0, 0);
+        let ts = generate_ts(5000, 6, 15, 12, 30, 30, 500000);
+
+        assert_eq!(
+            upper_date.sub_timestamp(lower_ts),
+            IntervalDT::try_from_dhms(3652058, 0, 0, 0, 0).unwrap()
+        );
+
+        assert_eq!(
+            upper_date.sub_timestamp(ts),
+            IntervalDT::try_from_dhms(1826045, 11, 29, 29, 500000).unwrap()
+        );
+
+        assert_eq!(
+            lower_date.sub_timestamp(upper_ts),
+            -IntervalDT::try_from_dhms(3652058, 23, 59, 59, 999999).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_date_weeks_between() {
+        // Thursday to the following Monday across the year boundary
+        let thu = generate_date(2020, 12, 31);
+        let mon = generate_date(2021, 1, 4);
+        assert_eq!(mon.weeks_between(thu), 0);
+        assert_eq!(mon.iso_weeks_between(thu), 1);
+        assert_eq!(thu.weeks_between(mon), -1);
+        assert_eq!(thu.iso_weeks_between(mon), -1);
+
+        // Monday to Sunday of the same ISO week across the year boundary
+        let mon = generate_date(2020, 12, 28);
+        let sun = generate_date(2021, 1, 3);
+        assert_eq!(sun.weeks_between(mon), 0);
+        assert_eq!(sun.iso_weeks_between(mon), 0);
+        assert_eq!(mon.weeks_between(sun), -1);
+        assert_eq!(mon.iso_weeks_between(sun), 0);
+
+        // Sunday to the following Monday
+        let next_mon = generate_date(2021, 1, 4);
+        assert_eq!(next_mon.weeks_between(sun), 0);
+        assert_eq!(next_mon.iso_weeks_between(sun), 1);
+
+        // Whole weeks agree
+        let date = generate_date(2021, 6, 15);
+        for weeks in [-53, -1, 0, 1, 52] {
+            let other = date.add_days(weeks * 7).unwrap();
+            assert_eq!(other.weeks_between(date), weeks);
+            assert_eq!(other.iso_weeks_between(date), weeks);
+        }
+        assert_eq!(date.weeks_between(date), 0);
+        assert_eq!(date.add_days(6).unwrap().weeks_between(date), 0);
+        assert_eq!(date.add_days(-1).unwrap().weeks_between(date), -1);
+
+        // Around Unix Epoch (Thursday)
+        let epoch = generate_date(1970, 1, 1);
+        assert_eq!(generate_date(1969, 12, 29).iso_weeks_between(epoch), 0);
+        assert_eq!(generate_date(1969, 12, 28).iso_weeks_between(epoch), -1);
+        assert_eq!(generate_date(1970, 1, 5).iso_weeks_between(epoch), 1);
+
+        // Range bounds, 0001-01-01 is Monday
+        assert_eq!(Date::MAX.weeks_between(Date::MIN), 3652058 / 7);
+        assert_eq!(Date::MAX.iso_weeks_between(Date::MIN), 3652058 / 7);
+        assert_eq!(Date::MIN.iso_weeks_between(Date::MAX), -(3652058 / 7));
+    }
+
+    #[test]
+    fn test_julian_day_number() {
+        assert_eq!(generate_date(1970, 1, 1).to_julian_day_number(), 2440588);
+        assert_eq!(generate_date(2000, 1, 1).to_julian_day_number(), 2451545);
+        assert_eq!(Date::MIN.to_julian_day_number(), 1721426);
+        assert_eq!(Date::MAX.to_julian_day_number(), 5373484);
+        assert_eq!(
+            Date::try_from_julian_day_number(2440588).unwrap(),
+            generate_date(1970, 1, 1)
+        );
+        assert_eq!(
+            Date::try_from_julian_day_number(2451545).unwrap(),
+            generate_date(2000, 1, 1)
+        );
+
+        // Round trip
+        let mut date = Ok(Date::MIN);
+        while let Ok(d) = date {
+            let jdn = d.to_julian_day_number();
+            assert_eq!(Date::try_from_julian_day_number(jdn).unwrap(), d);
+            date = d.add_days(997);
+        }
+        assert_eq!(
+            Date::try_from_julian_day_number(Date::MAX.to_julian_day_number()).unwrap(),
+            Date::MAX
+        );
+
+        // Out of range
+        for jdn in [1721425, 5373485, 0, -1, i32::MIN, i32::MAX] {
+            assert_eq!(
+                Date::try_from_julian_day_number(jdn).unwrap_err(),
+                Error::DateOutOfRange
+            );
+        }
+    }
+
+    #[test]
+    fn test_sortable_string() {
+        let date = generate_date(2021, 6, 1);
+        assert_eq!(date.to_sortable_string().unwrap(), "20210601");
+        assert_eq!(Date::MIN.to_sortable_string().unwrap(), "00010101");
+        assert_eq!(Date::MAX.to_sortable_string().unwrap(), "99991231");
+
+        let mut dates = vec![
+            Date::MAX,
+            generate_date(1970, 1, 1),
+            generate_date(1969, 12, 31),
+            generate_date(999, 12, 31),
+            generate_date(1000, 1, 1),
+            date,
+            Date::MIN,
+            generate_date(2021, 10, 1),
+            generate_date(2021, 9, 30),
+        ];
+        let mut strings: Vec<String> = dates
+            .iter()
+            .map(|date| date.to_sortable_string().unwrap())
+            .collect();
+        dates.sort();
+        strings.sort();
+        for (date, s) in dates.iter().zip(strings.iter()) {
+            assert_eq!(date.to_sortable_string().unwrap(), *s);
+            assert_eq!(Date::from_sortable_string(s).unwrap(), *date);
+        }
 
-        let date = generate_date(2000, 2, 29);
-        let interval = IntervalYM::try_from_ym(2, 0).unwrap();
-        assert!(date.add_interval_ym(interval).is_err());
+        assert_eq!(
+            Date::from_sortable_string("20210230").unwrap_err(),
+            Error::InvalidDate
+        );
+        for input in ["", "2021061", "202106010", "2021-6-1", "+2021061"] {
+            assert_eq!(
+                Date::from_sortable_string(input).unwrap_err(),
+                Error::ParseError(format!("the input {} is not a valid sortable date", input))
+            );
+        }
     }
 
     #[test]
-    fn test_add_sub_time() {
+    fn test_year_quarter() {
+        for (date, s, first) in [
+            (
+                generate_date(2021, 2, 14),
+                "2021-Q1",
+                generate_date(2021, 1, 1),
+            ),
+            (
+                generate_date(2021, 6, 30),
+                "2021-Q2",
+                generate_date(2021, 4, 1),
+            ),
+            (
+                generate_date(2021, 7, 1),
+                "2021-Q3",
+                generate_date(2021, 7, 1),
+            ),
+            (
+                generate_date(2021, 12, 31),
+                "2021-Q4",
+                generate_date(2021, 10, 1),
+            ),
+            (Date::MIN, "0001-Q1", Date::MIN),
+            (Date::MAX, "9999-Q4", generate_date(9999, 10, 1)),
+        ] {
+            assert_eq!(date.format_year_quarter().unwrap(), s);
+            assert_eq!(Date::parse_year_quarter(s).unwrap(), first);
+            assert_eq!(first.format_year_quarter().unwrap(), s);
+        }
         assert_eq!(
-            Date::try_from_ymd(9999, 12, 31)
-                .unwrap()
-                .add_time(Time::try_from_hms(12, 34, 56, 999999).unwrap()),
-            generate_ts(9999, 12, 31, 12, 34, 56, 999999)
+            Date::parse_year_quarter("2021-q4").unwrap(),
+            generate_date(2021, 10, 1)
         );
 
         assert_eq!(
-            Date::try_from_ymd(9999, 12, 31)
-                .unwrap()
-                .sub_time(Time::try_from_hms(12, 34, 56, 999999).unwrap())
-                .unwrap(),
-            generate_ts(9999, 12, 30, 11, 25, 3, 1)
+            Date::parse_year_quarter("0000-Q1").unwrap_err(),
+            Error::DateOutOfRange
         );
-
-        // Out of range
-        assert!(Date::try_from_ymd(1, 1, 1)
-            .unwrap()
-            .sub_time(Time::try_from_hms(12, 34, 56, 999999).unwrap())
-            .is_err());
+        for input in [
+            "", "2021", "2021-Q", "2021-Q5", "2021-Q0", "2021Q4", "2021 Q4", "21-Q4", "20210-Q4",
+            "+202-Q4", "2021-Q4 ", "2021-Q44",
+        ] {
+            assert_eq!(
+                Date::parse_year_quarter(input).unwrap_err(),
+                Error::ParseError(format!(
+                    "the input {} is not a valid year and quarter",
+                    input
+                ))
+            );
+        }
     }
 
     #[test]
-    fn test_date_sub_timestamp() {
-        let upper_date = generate_date(9999, 12, 31);
-        let lower_date = generate_date(1, 1, 1);
-        let upper_ts = generate_ts(9999, 12, 31, 23, 59, 59, 999999);
-        let lower_ts = generate_ts(1, 1, 1, 0, 0, 0, 0);
-        let ts = generate_ts(5000, 6, 15, 12, 30, 30, 500000);
-
+    fn test_packed_i32() {
         assert_eq!(
-            upper_date.sub_timestamp(lower_ts),
-            IntervalDT::try_from_dhms(3652058, 0, 0, 0, 0).unwrap()
+            Date::try_from_packed_i32(20211024).unwrap(),
+            generate_date(2021, 10, 24)
         );
+        assert_eq!(generate_date(2021, 10, 24).to_packed_i32(), 20211024);
+        assert_eq!(Date::MIN.to_packed_i32(), 10101);
+        assert_eq!(Date::MAX.to_packed_i32(), 99991231);
+        assert_eq!(Date::try_from_packed_i32(10101).unwrap(), Date::MIN);
+        assert_eq!(Date::try_from_packed_i32(99991231).unwrap(), Date::MAX);
+
+        // Round trip
+        let mut date = Ok(Date::MIN);
+        while let Ok(d) = date {
+            assert_eq!(Date::try_from_packed_i32(d.to_packed_i32()).unwrap(), d);
+            date = d.add_days(997);
+        }
 
+        // Invalid fields
         assert_eq!(
-            upper_date.sub_timestamp(ts),
-            IntervalDT::try_from_dhms(1826045, 11, 29, 29, 500000).unwrap()
+            Date::try_from_packed_i32(20211032).unwrap_err(),
+            Error::InvalidDay
+        );
+        assert_eq!(
+            Date::try_from_packed_i32(20210229).unwrap_err(),
+            Error::InvalidDate
         );
+        assert_eq!(
+            Date::try_from_packed_i32(20211324).unwrap_err(),
+            Error::InvalidMonth
+        );
+        assert_eq!(
+            Date::try_from_packed_i32(20210024).unwrap_err(),
+            Error::InvalidMonth
+        );
+        assert_eq!(
+            Date::try_from_packed_i32(20211000).unwrap_err(),
+            Error::InvalidDay
+        );
+        for packed in [0, 1024, 100000000, -20211024, i32::MIN, i32::MAX] {
+            assert_eq!(
+                Date::try_from_packed_i32(packed).unwrap_err(),
+                Error::DateOutOfRange
+            );
+        }
+    }
 
+    #[test]
+    fn test_date_range_inclusive() {
+        let start = generate_date(2021, 2, 26);
+        let end = generate_date(2021, 3, 2);
+        let range = date_range_inclusive(start, end);
+        assert_eq!(range.len(), 5);
+        assert_eq!(range.size_hint(), (5, Some(5)));
+        let dates: Vec<Date> = range.collect();
+        assert_eq!(dates.len(), 5);
         assert_eq!(
-            lower_date.sub_timestamp(upper_ts),
-            -IntervalDT::try_from_dhms(3652058, 23, 59, 59, 999999).unwrap()
+            dates,
+            vec![
+                generate_date(2021, 2, 26),
+                generate_date(2021, 2, 27),
+                generate_date(2021, 2, 28),
+                generate_date(2021, 3, 1),
+                generate_date(2021, 3, 2),
+            ]
+        );
+        assert_eq!(*dates.last().unwrap(), end);
+
+        // Reverse
+        let dates: Vec<Date> = date_range_inclusive(start, end).rev().collect();
+        assert_eq!(dates.first().unwrap(), &end);
+        assert_eq!(dates.last().unwrap(), &start);
+        assert_eq!(dates.len(), 5);
+
+        // Both ends
+        let mut range = date_range_inclusive(start, end);
+        assert_eq!(range.next(), Some(start));
+        assert_eq!(range.next_back(), Some(end));
+        assert_eq!(range.len(), 3);
+        assert_eq!(range.nth(1), Some(generate_date(2021, 2, 28)));
+        assert_eq!(range.len(), 1);
+        assert_eq!(range.next_back(), Some(generate_date(2021, 3, 1)));
+        assert_eq!(range.len(), 0);
+        assert_eq!(range.next(), None);
+        assert_eq!(range.next_back(), None);
+
+        // Single and empty ranges
+        assert_eq!(date_range_inclusive(start, start).len(), 1);
+        assert_eq!(
+            date_range_inclusive(start, start).collect::<Vec<_>>(),
+            vec![start]
         );
+        assert_eq!(date_range_inclusive(end, start).len(), 0);
+        assert_eq!(date_range_inclusive(end, start).next(), None);
+        assert_eq!(date_range_inclusive(start, end).nth(5), None);
+        assert_eq!(date_range_inclusive(start, end).last(), Some(end));
+        assert_eq!(date_range_inclusive(start, end).count(), 5);
+
+        // Full range
+        let range = date_range_inclusive(Date::MIN, Date::MAX);
+        assert_eq!(range.len(), Date::MAX.sub_date(Date::MIN) as usize + 1);
+        assert_eq!(range.clone().next(), Some(Date::MIN));
+        assert_eq!(range.clone().next_back(), Some(Date::MAX));
+        assert_eq!(range.count(), 3652059);
     }
 
     #[test]
@@ -1529,6 +3576,52 @@ mod tests {
         assert_eq!(lower_date.sub_date(upper_date), -3652058);
 
         assert_eq!(upper_date.sub_date(date), 1826046);
+
+        assert_eq!(upper_date.checked_sub_date(lower_date), Some(3652058));
+        assert_eq!(lower_date.checked_sub_date(upper_date), Some(-3652058));
+        assert_eq!(Date::MAX.checked_sub_date(Date::MAX), Some(0));
+        assert_eq!(Date::MIN.checked_sub_date(Date::MIN), Some(0));
+        assert_eq!(upper_date.saturating_sub_date(lower_date), 3652058);
+        assert_eq!(lower_date.saturating_sub_date(upper_date), -3652058);
+        assert_eq!(date.saturating_sub_date(date), 0);
+        for (a, b) in [
+            (Date::MIN, Date::MAX),
+            (Date::MAX, Date::MIN),
+            (date, lower_date),
+        ] {
+            assert_eq!(a.checked_sub_date(b), Some(a.sub_date(b)));
+            assert_eq!(a.saturating_sub_date(b), a.sub_date(b));
+        }
+    }
+
+    #[test]
+    fn test_date_hash_consistent_with_eq() {
+        use crate::util::testing::hash_of;
+
+        for date in [
+            Date::MIN,
+            generate_date(1969, 12, 31),
+            generate_date(1970, 1, 1),
+            generate_date(2021, 6, 1),
+            Date::MAX,
+        ] {
+            let ts = date.and_zero_time();
+            assert_eq!(date, ts);
+            assert_eq!(ts, date);
+            assert_eq!(hash_of(&date), hash_of(&ts));
+        }
+
+        // Still usable as keys on its own
+        let set: std::collections::HashSet<Date> = [
+            generate_date(2021, 6, 1),
+            generate_date(2021, 6, 1),
+            Date::MAX,
+        ]
+        .iter()
+        .copied()
+        .collect();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&generate_date(2021, 6, 1)));
     }
 
     #[test]
@@ -1540,6 +3633,77 @@ mod tests {
         assert!(date == ts);
     }
 
+    #[test]
+    fn test_half() {
+        for (half, name, months) in [
+            (Half::H1, "H1", [1, 2, 3, 4, 5, 6]),
+            (Half::H2, "H2", [7, 8, 9, 10, 11, 12]),
+        ] {
+            assert_eq!(half.name(), name);
+            assert_eq!(half.to_string(), name);
+            assert_eq!(Half::from_name(name), Some(half));
+            assert_eq!(Half::from_name(&name.to_lowercase()), Some(half));
+            for month in months {
+                assert_eq!(Half::from_month(month), Some(half));
+                let date = generate_date(2021, month, 1);
+                assert_eq!(date.half_enum(), half);
+                assert_eq!(date.half(), Some(half as i32));
+                assert_eq!(date.last_day_of_month().half(), Some(half as i32));
+            }
+        }
+        assert_eq!(Half::from_month(0), None);
+        assert_eq!(Half::from_month(13), None);
+        assert_eq!(Half::from_name("H3"), None);
+        assert_eq!(Half::from_name(""), None);
+
+        // Boundaries
+        assert_eq!(generate_date(2021, 6, 30).half(), Some(1));
+        assert_eq!(generate_date(2021, 7, 1).half(), Some(2));
+        assert_eq!(generate_ts(2021, 6, 30, 23, 59, 59, 999999).half(), Some(1));
+        assert_eq!(generate_ts(2021, 7, 1, 0, 0, 0, 0).half(), Some(2));
+        assert_eq!(Date::MIN.half(), Some(1));
+        assert_eq!(Date::MAX.half(), Some(2));
+        assert_eq!(Timestamp::MAX.half(), Some(2));
+        assert_eq!(Time::MAX.half(), None);
+        assert_eq!(IntervalYM::MAX.half(), None);
+        assert_eq!(IntervalDT::MAX.half(), None);
+
+        // Consistent with the quarter
+        for month in 1..=12 {
+            let date = generate_date(2021, month, 15);
+            assert_eq!(date.half(), Some((date.quarter_enum() as i32 + 1) / 2));
+        }
+    }
+
+    #[test]
+    fn test_century() {
+        assert_eq!(generate_date(2021, 6, 1).century(), Some(21));
+        assert_eq!(generate_date(2000, 12, 31).century(), Some(20));
+        assert_eq!(generate_date(2001, 1, 1).century(), Some(21));
+        assert_eq!(generate_date(1, 1, 1).century(), Some(1));
+        assert_eq!(Date::MIN.century(), Some(1));
+        assert_eq!(Date::MAX.century(), Some(100));
+        assert_eq!(
+            generate_ts(2021, 6, 1, 23, 59, 59, 999999).century(),
+            Some(21)
+        );
+        assert_eq!(Timestamp::MIN.century(), Some(1));
+        assert_eq!(Time::MAX.century(), None);
+        assert_eq!(IntervalYM::MAX.century(), None);
+        assert_eq!(IntervalDT::MAX.century(), None);
+
+        // Consistent with the century truncation
+        for date in [
+            generate_date(1900, 1, 1),
+            generate_date(1901, 1, 1),
+            generate_date(2000, 12, 31),
+            generate_date(2021, 6, 1),
+        ] {
+            let first_year = date.trunc_century().unwrap().year().unwrap();
+            assert_eq!(date.century().unwrap(), first_year / 100 + 1);
+        }
+    }
+
     fn test_extract(year: i32, month: u32, day: u32) {
         let date = generate_date(year, month, day);
         assert_eq!(year, date.year().unwrap());
@@ -1549,6 +3713,69 @@ mod tests {
         assert!(date.hour().is_none());
         assert!(date.minute().is_none());
         assert!(date.second().is_none());
+        assert!(date.millisecond().is_none());
+        assert!(date.microsecond().is_none());
+        assert_eq!(
+            date.epoch_seconds().unwrap(),
+            date.and_zero_time().epoch_seconds().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_date_parse_or() {
+        let fmt = "YYYY-MM-DD";
+        let default = generate_date(1970, 1, 1);
+
+        assert_eq!(
+            Date::parse_or("2021-06-01", fmt, default),
+            generate_date(2021, 6, 1)
+        );
+        assert_eq!(
+            Date::parse_or_else("2021-06-01", fmt, || unreachable!()),
+            generate_date(2021, 6, 1)
+        );
+        assert_eq!(
+            Date::parse_or_now("2021-06-01", fmt).unwrap(),
+            generate_date(2021, 6, 1)
+        );
+
+        for input in ["2021-02-29", "2021-06", "not a date"] {
+            assert_eq!(Date::parse_or(input, fmt, default), default);
+            assert_eq!(Date::parse_or_else(input, fmt, || default), default);
+            let before = Date::now().unwrap();
+            let now = Date::parse_or_now(input, fmt).unwrap();
+            assert!(now >= before && now <= Date::now().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_date_to_string_with() {
+        let date = generate_date(2021, 6, 1);
+        for fmt in ["YYYY-MM-DD", "DD/MM/YYYY Day", "FMMM/DD/YYYY"] {
+            let formatter = Formatter::try_new(fmt).unwrap();
+            assert_eq!(
+                date.to_string_with(&formatter).unwrap(),
+                date.format(fmt).unwrap().to_string()
+            );
+        }
+        assert!(date
+            .to_string_with(&Formatter::try_new("HH24:MI").unwrap())
+            .is_err());
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_date_epoch_seconds() {
+        assert_eq!(generate_date(1970, 1, 1).epoch_seconds().unwrap(), 0.0);
+        assert_eq!(generate_date(1970, 1, 2).epoch_seconds().unwrap(), 86400.0);
+        assert_eq!(
+            generate_date(1969, 12, 31).epoch_seconds().unwrap(),
+            -86400.0
+        );
+        assert_eq!(
+            generate_date(2021, 6, 1).epoch_seconds().unwrap(),
+            1622505600.0
+        );
     }
 
     #[test]
@@ -1779,6 +4006,434 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_business_day_of_month() {
+        // 2021-05 starts on a Saturday and has 21 weekdays
+        assert_eq!(
+            Date::nth_business_day_of_month(2021, 5, 1).unwrap(),
+            generate_date(2021, 5, 3)
+        );
+        assert_eq!(
+            Date::nth_business_day_of_month(2021, 5, 3).unwrap(),
+            generate_date(2021, 5, 5)
+        );
+        assert_eq!(
+            Date::nth_business_day_of_month(2021, 5, 6).unwrap(),
+            generate_date(2021, 5, 10)
+        );
+        assert_eq!(
+            Date::nth_business_day_of_month(2021, 5, 21).unwrap(),
+            generate_date(2021, 5, 31)
+        );
+        assert_eq!(
+            Date::nth_business_day_of_month(2021, 5, 22).unwrap_err(),
+            Error::InvalidDay
+        );
+        assert_eq!(
+            Date::nth_business_day_of_month(2021, 5, 0).unwrap_err(),
+            Error::InvalidDay
+        );
+        assert_eq!(
+            Date::nth_business_day_of_month(2021, 13, 1).unwrap_err(),
+            Error::InvalidMonth
+        );
+        assert_eq!(
+            Date::nth_business_day_of_month(10000, 1, 1).unwrap_err(),
+            Error::DateOutOfRange
+        );
+
+        assert_eq!(generate_date(2021, 5, 1).business_day_of_month(), 0);
+        assert_eq!(generate_date(2021, 5, 2).business_day_of_month(), 0);
+        assert_eq!(generate_date(2021, 5, 3).business_day_of_month(), 1);
+        assert_eq!(generate_date(2021, 5, 5).business_day_of_month(), 3);
+        assert_eq!(generate_date(2021, 5, 8).business_day_of_month(), 0);
+        assert_eq!(generate_date(2021, 5, 31).business_day_of_month(), 21);
+
+        // Round trip
+        for n in 1..=21 {
+            let date = Date::nth_business_day_of_month(2021, 5, n).unwrap();
+            assert_eq!(date.business_day_of_month(), n);
+        }
+
+        // Friday and Saturday weekend
+        let weekend = [WeekDay::Friday, WeekDay::Saturday];
+        assert_eq!(
+            Date::nth_business_day_of_month_with(2021, 5, 1, &weekend).unwrap(),
+            generate_date(2021, 5, 2)
+        );
+        assert_eq!(
+            Date::nth_business_day_of_month_with(2021, 5, 6, &weekend).unwrap(),
+            generate_date(2021, 5, 9)
+        );
+        assert_eq!(
+            generate_date(2021, 5, 2).business_day_of_month_with(&weekend),
+            1
+        );
+        assert_eq!(
+            generate_date(2021, 5, 7).business_day_of_month_with(&weekend),
+            0
+        );
+
+        // No weekend, every day is a business day
+        assert_eq!(
+            Date::nth_business_day_of_month_with(2021, 5, 31, &[]).unwrap(),
+            generate_date(2021, 5, 31)
+        );
+        assert_eq!(
+            generate_date(2021, 5, 31).business_day_of_month_with(&[]),
+            31
+        );
+
+        // All days are weekend
+        let all = [
+            WeekDay::Sunday,
+            WeekDay::Monday,
+            WeekDay::Tuesday,
+            WeekDay::Wednesday,
+            WeekDay::Thursday,
+            WeekDay::Friday,
+            WeekDay::Saturday,
+        ];
+        assert_eq!(
+            Date::nth_business_day_of_month_with(2021, 5, 1, &all).unwrap_err(),
+            Error::InvalidDay
+        );
+
+        // Boundaries
+        assert_eq!(Date::nth_business_day_of_month(1, 1, 1).unwrap(), Date::MIN);
+        assert_eq!(
+            Date::nth_business_day_of_month(9999, 12, 23).unwrap(),
+            Date::MAX
+        );
+        assert_eq!(Date::MAX.business_day_of_month(), 23);
+    }
+
+    #[test]
+    fn test_week_bounds() {
+        // 2022-06-01 is Wednesday
+        let date = generate_date(2022, 6, 1);
+        assert_eq!(
+            date.sunday_on_or_before().unwrap(),
+            generate_date(2022, 5, 29)
+        );
+        assert_eq!(
+            date.saturday_on_or_after().unwrap(),
+            generate_date(2022, 6, 4)
+        );
+        assert_eq!(
+            date.monday_on_or_before().unwrap(),
+            generate_date(2022, 5, 30)
+        );
+        assert_eq!(
+            date.sunday_on_or_after().unwrap(),
+            generate_date(2022, 6, 5)
+        );
+
+        // The date itself when already on the bound
+        let sunday = generate_date(2022, 6, 5);
+        assert_eq!(sunday.sunday_on_or_before().unwrap(), sunday);
+        assert_eq!(sunday.sunday_on_or_after().unwrap(), sunday);
+        assert_eq!(
+            sunday.saturday_on_or_after().unwrap(),
+            generate_date(2022, 6, 11)
+        );
+        assert_eq!(
+            sunday.monday_on_or_before().unwrap(),
+            generate_date(2022, 5, 30)
+        );
+        let saturday = generate_date(2022, 6, 4);
+        assert_eq!(saturday.saturday_on_or_after().unwrap(), saturday);
+        let monday = generate_date(2022, 5, 30);
+        assert_eq!(monday.monday_on_or_before().unwrap(), monday);
+
+        // Across year end
+        let date = generate_date(2021, 1, 1);
+        assert_eq!(
+            date.sunday_on_or_before().unwrap(),
+            generate_date(2020, 12, 27)
+        );
+        assert_eq!(
+            date.monday_on_or_before().unwrap(),
+            generate_date(2020, 12, 28)
+        );
+
+        // Every day of a week maps to the same bounds
+        for date in date_range_inclusive(generate_date(2022, 5, 29), generate_date(2022, 6, 4)) {
+            assert_eq!(
+                date.sunday_on_or_before().unwrap(),
+                generate_date(2022, 5, 29)
+            );
+            assert_eq!(
+                date.saturday_on_or_after().unwrap(),
+                generate_date(2022, 6, 4)
+            );
+        }
+
+        // 0001-01-01 is Monday, and 9999-12-31 is Friday
+        assert_eq!(
+            Date::MIN.sunday_on_or_before().unwrap_err(),
+            Error::DateOutOfRange
+        );
+        assert_eq!(Date::MIN.monday_on_or_before().unwrap(), Date::MIN);
+        assert_eq!(
+            Date::MIN.saturday_on_or_after().unwrap(),
+            generate_date(1, 1, 6)
+        );
+        assert_eq!(
+            Date::MAX.saturday_on_or_after().unwrap_err(),
+            Error::DateOutOfRange
+        );
+        assert_eq!(
+            Date::MAX.sunday_on_or_after().unwrap_err(),
+            Error::DateOutOfRange
+        );
+        assert_eq!(
+            Date::MAX.sunday_on_or_before().unwrap(),
+            generate_date(9999, 12, 26)
+        );
+        assert_eq!(
+            Date::MAX.monday_on_or_before().unwrap(),
+            generate_date(9999, 12, 27)
+        );
+    }
+
+    #[test]
+    fn test_weekend_weekday() {
+        // 2021-06-05 is Saturday
+        let saturday = generate_date(2021, 6, 5);
+        assert!(saturday.is_weekend());
+        assert!(!saturday.is_weekday());
+        assert_eq!(saturday.iso_day_of_week(), 6);
+
+        let sunday = generate_date(2021, 6, 6);
+        assert!(sunday.is_weekend());
+        assert!(!sunday.is_weekday());
+        assert_eq!(sunday.iso_day_of_week(), 7);
+
+        let monday = generate_date(2021, 6, 7);
+        assert!(!monday.is_weekend());
+        assert!(monday.is_weekday());
+        assert_eq!(monday.iso_day_of_week(), 1);
+
+        let friday = generate_date(2021, 6, 11);
+        assert!(!friday.is_weekend());
+        assert!(friday.is_weekday());
+        assert_eq!(friday.iso_day_of_week(), 5);
+
+        // 0001-01-01 is Monday, 9999-12-31 is Friday
+        assert_eq!(Date::MIN.iso_day_of_week(), 1);
+        assert!(Date::MIN.is_weekday());
+        assert_eq!(Date::MAX.iso_day_of_week(), 5);
+        assert!(Date::MAX.is_weekday());
+
+        let mut date = generate_date(1969, 12, 29);
+        for expected in 1..=14 {
+            assert_eq!(date.iso_day_of_week(), (expected - 1) % 7 + 1);
+            date = date.add_days(1).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_easter() {
+        assert_eq!(Date::easter(2021).unwrap(), generate_date(2021, 4, 4));
+        assert_eq!(Date::easter(2024).unwrap(), generate_date(2024, 3, 31));
+        assert_eq!(Date::easter(2000).unwrap(), generate_date(2000, 4, 23));
+        assert_eq!(Date::easter(1818).unwrap(), generate_date(1818, 3, 22));
+        assert_eq!(Date::easter(2038).unwrap(), generate_date(2038, 4, 25));
+        assert_eq!(Date::easter(1).unwrap().year().unwrap(), 1);
+        assert_eq!(Date::easter(9999).unwrap().year().unwrap(), 9999);
+
+        // Good Friday
+        assert_eq!(
+            Date::easter(2021).unwrap().sub_days(2).unwrap(),
+            generate_date(2021, 4, 2)
+        );
+
+        assert_eq!(
+            Date::orthodox_easter(2021).unwrap(),
+            generate_date(2021, 5, 2)
+        );
+        assert_eq!(
+            Date::orthodox_easter(2023).unwrap(),
+            generate_date(2023, 4, 16)
+        );
+        assert_eq!(
+            Date::orthodox_easter(2024).unwrap(),
+            generate_date(2024, 5, 5)
+        );
+        assert_eq!(Date::orthodox_easter(1).unwrap().year().unwrap(), 1);
+        assert_eq!(Date::orthodox_easter(9999).unwrap().year().unwrap(), 9999);
+
+        // Out of range
+        assert!(Date::easter(0).is_err());
+        assert!(Date::easter(10000).is_err());
+        assert!(Date::orthodox_easter(0).is_err());
+        assert!(Date::orthodox_easter(10000).is_err());
+    }
+
+    #[test]
+    fn test_date_with_ordinal() {
+        let date = generate_date(2021, 6, 1);
+        assert_eq!(date.with_ordinal(1).unwrap(), generate_date(2021, 1, 1));
+        assert_eq!(date.with_ordinal(152).unwrap(), date);
+        assert_eq!(date.with_ordinal(365).unwrap(), generate_date(2021, 12, 31));
+        assert_eq!(date.with_ordinal(366).unwrap_err(), Error::InvalidDay);
+        assert_eq!(date.with_ordinal(0).unwrap_err(), Error::InvalidDay);
+
+        // Leap year
+        let date = generate_date(2020, 6, 1);
+        assert_eq!(date.with_ordinal(60).unwrap(), generate_date(2020, 2, 29));
+        assert_eq!(date.with_ordinal(366).unwrap(), generate_date(2020, 12, 31));
+        assert_eq!(date.with_ordinal(367).unwrap_err(), Error::InvalidDay);
+
+        // Iterating the ordinals of a year
+        let date = generate_date(2021, 1, 1);
+        for ordinal in 1..=365 {
+            let day = date.with_ordinal(ordinal).unwrap();
+            assert_eq!(day.sub_date(date), ordinal as i32 - 1);
+        }
+
+        assert_eq!(Date::MIN.with_ordinal(1).unwrap(), Date::MIN);
+        assert_eq!(
+            Date::MAX.with_ordinal(1).unwrap(),
+            generate_date(9999, 1, 1)
+        );
+    }
+
+    #[test]
+    fn test_date_with_fields() {
+        let date = generate_date(2021, 1, 31);
+
+        assert_eq!(date.with_year(2020).unwrap(), generate_date(2020, 1, 31));
+        assert_eq!(date.with_year(1).unwrap(), generate_date(1, 1, 31));
+        assert_eq!(date.with_year(0).unwrap_err(), Error::DateOutOfRange);
+        assert_eq!(date.with_year(10000).unwrap_err(), Error::DateOutOfRange);
+        assert_eq!(
+            generate_date(2020, 2, 29).with_year(2021).unwrap_err(),
+            Error::InvalidDate
+        );
+        assert_eq!(
+            generate_date(2020, 2, 29).with_year(2024).unwrap(),
+            generate_date(2024, 2, 29)
+        );
+
+        assert_eq!(date.with_month(3).unwrap(), generate_date(2021, 3, 31));
+        assert_eq!(date.with_month(2).unwrap_err(), Error::InvalidDate);
+        assert_eq!(date.with_month(4).unwrap_err(), Error::InvalidDate);
+        assert_eq!(date.with_month(0).unwrap_err(), Error::InvalidMonth);
+        assert_eq!(date.with_month(13).unwrap_err(), Error::InvalidMonth);
+
+        assert_eq!(
+            date.with_month_clamped(2).unwrap(),
+            generate_date(2021, 2, 28)
+        );
+        assert_eq!(
+            generate_date(2020, 1, 31).with_month_clamped(2).unwrap(),
+            generate_date(2020, 2, 29)
+        );
+        assert_eq!(
+            date.with_month_clamped(4).unwrap(),
+            generate_date(2021, 4, 30)
+        );
+        assert_eq!(
+            date.with_month_clamped(12).unwrap(),
+            generate_date(2021, 12, 31)
+        );
+        assert_eq!(
+            generate_date(2021, 1, 15).with_month_clamped(2).unwrap(),
+            generate_date(2021, 2, 15)
+        );
+        assert_eq!(date.with_month_clamped(0).unwrap_err(), Error::InvalidMonth);
+        assert_eq!(
+            date.with_month_clamped(13).unwrap_err(),
+            Error::InvalidMonth
+        );
+
+        assert_eq!(date.with_day(1).unwrap(), generate_date(2021, 1, 1));
+        assert_eq!(
+            generate_date(2021, 2, 1).with_day(31).unwrap_err(),
+            Error::InvalidDate
+        );
+        assert_eq!(date.with_day(0).unwrap_err(), Error::InvalidDay);
+        assert_eq!(date.with_day(32).unwrap_err(), Error::InvalidDay);
+    }
+
+    #[test]
+    fn test_day_of_month_progress() {
+        let date = generate_date(2021, 6, 12);
+        assert_eq!(date.day_of_month(), 12);
+        assert_eq!(date.days_in_current_month(), 30);
+        assert_eq!(date.days_remaining_in_month(), 18);
+
+        // The last day of February
+        let date = generate_date(2020, 2, 29);
+        assert_eq!(date.day_of_month(), 29);
+        assert_eq!(date.days_in_current_month(), 29);
+        assert_eq!(date.days_remaining_in_month(), 0);
+        let date = generate_date(2021, 2, 28);
+        assert_eq!(date.day_of_month(), 28);
+        assert_eq!(date.days_in_current_month(), 28);
+        assert_eq!(date.days_remaining_in_month(), 0);
+        let date = generate_date(2100, 2, 1);
+        assert_eq!(date.days_in_current_month(), 28);
+        assert_eq!(date.days_remaining_in_month(), 27);
+        let date = generate_date(2000, 2, 1);
+        assert_eq!(date.days_in_current_month(), 29);
+        assert_eq!(date.days_remaining_in_month(), 28);
+
+        assert_eq!(Date::MIN.day_of_month(), 1);
+        assert_eq!(Date::MIN.days_remaining_in_month(), 30);
+        assert_eq!(Date::MAX.day_of_month(), 31);
+        assert_eq!(Date::MAX.days_remaining_in_month(), 0);
+
+        let mut date = generate_date(2021, 1, 1);
+        while date.extract().0 == 2021 {
+            assert_eq!(
+                date.day_of_month() + date.days_remaining_in_month(),
+                date.days_in_current_month()
+            );
+            assert_eq!(
+                date.last_day_of_month().day_of_month(),
+                date.days_in_current_month()
+            );
+            date = date.add_days(1).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_month_year_boundary_predicates() {
+        // Leap year
+        assert!(generate_date(2020, 2, 29).is_last_day_of_month());
+        assert!(!generate_date(2020, 2, 28).is_last_day_of_month());
+        // Non-leap year
+        assert!(generate_date(2021, 2, 28).is_last_day_of_month());
+        assert!(!generate_date(2021, 2, 27).is_last_day_of_month());
+        // Century years
+        assert!(generate_date(1900, 2, 28).is_last_day_of_month());
+        assert!(!generate_date(2000, 2, 28).is_last_day_of_month());
+        assert!(generate_date(2000, 2, 29).is_last_day_of_month());
+
+        assert!(generate_date(2021, 4, 30).is_last_day_of_month());
+        assert!(!generate_date(2021, 4, 30).is_first_day_of_month());
+        assert!(generate_date(2021, 5, 1).is_first_day_of_month());
+        assert!(!generate_date(2021, 5, 1).is_last_day_of_month());
+        assert!(!generate_date(2021, 5, 2).is_first_day_of_month());
+
+        assert!(generate_date(2021, 1, 1).is_first_day_of_year());
+        assert!(generate_date(2021, 1, 1).is_first_day_of_month());
+        assert!(!generate_date(2021, 1, 2).is_first_day_of_year());
+        assert!(!generate_date(2021, 2, 1).is_first_day_of_year());
+        assert!(generate_date(2021, 12, 31).is_last_day_of_year());
+        assert!(generate_date(2020, 12, 31).is_last_day_of_year());
+        assert!(!generate_date(2021, 12, 30).is_last_day_of_year());
+        assert!(!generate_date(2021, 1, 31).is_last_day_of_year());
+
+        assert!(Date::MIN.is_first_day_of_month());
+        assert!(Date::MIN.is_first_day_of_year());
+        assert!(Date::MAX.is_last_day_of_month());
+        assert!(Date::MAX.is_last_day_of_year());
+    }
+
     #[test]
     fn test_last_day_of_month() {
         assert_eq!(
@@ -10,6 +10,18 @@ pub type Result<T> = std::result::Result<T, Error>;
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Error {
     DateOutOfRange,
+    /// The result of adding or subtracting an `IntervalDT` is before `0001-01-01`.
+    ///
+    /// Only `add_interval_dt`, `sub_interval_dt` and their variants return it, other
+    /// arithmetic such as `add_days`, `add_months` and `add_interval_ym` returns
+    /// `DateOutOfRange` instead.
+    DateUnderflow,
+    /// The result of adding or subtracting an `IntervalDT` is after `9999-12-31`.
+    ///
+    /// Only `add_interval_dt`, `sub_interval_dt` and their variants return it, other
+    /// arithmetic such as `add_days`, `add_months` and `add_interval_ym` returns
+    /// `DateOutOfRange` instead.
+    DateOverflow,
     TimeOutOfRange,
     IntervalOutOfRange,
     InvalidNumber,
@@ -21,7 +33,13 @@ pub enum Error {
     InvalidDate,
     NumericOverflow,
     DivideByZero,
+    PrecisionLoss,
+    InvalidInterval(String),
     InvalidFormat(String),
+    /// The unit is not supported by the operation, e.g. `trunc` by `DateTimeUnit::Second`.
+    UnsupportedUnit(String),
+    /// A required field is not set, e.g. the year of `DateBuilder`.
+    MissingField(&'static str),
     FormatError(String),
     ParseError(String),
     TryReserveError(TryReserveError),
@@ -36,10 +54,45 @@ impl std::error::Error for Error {
     }
 }
 
-impl fmt::Display for Error {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl Error {
+    /// Returns a stable code of the error variant, e.g. `"date_out_of_range"`, for mapping
+    /// errors downstream without matching on messages.
+    ///
+    /// The codes are the snake case names of the variants and will not change.
+    #[inline]
+    pub const fn code(&self) -> &'static str {
+        match self {
+            Error::DateOutOfRange => "date_out_of_range",
+            Error::DateUnderflow => "date_underflow",
+            Error::DateOverflow => "date_overflow",
+            Error::TimeOutOfRange => "time_out_of_range",
+            Error::IntervalOutOfRange => "interval_out_of_range",
+            Error::InvalidNumber => "invalid_number",
+            Error::InvalidMonth => "invalid_month",
+            Error::InvalidDay => "invalid_day",
+            Error::InvalidMinute => "invalid_minute",
+            Error::InvalidSecond => "invalid_second",
+            Error::InvalidFraction => "invalid_fraction",
+            Error::InvalidDate => "invalid_date",
+            Error::NumericOverflow => "numeric_overflow",
+            Error::DivideByZero => "divide_by_zero",
+            Error::PrecisionLoss => "precision_loss",
+            Error::InvalidInterval(_) => "invalid_interval",
+            Error::InvalidFormat(_) => "invalid_format",
+            Error::UnsupportedUnit(_) => "unsupported_unit",
+            Error::MissingField(_) => "missing_field",
+            Error::FormatError(_) => "format_error",
+            Error::ParseError(_) => "parse_error",
+            Error::TryReserveError(_) => "try_reserve_error",
+        }
+    }
+
+    /// Writes the detail message of the error, without the prefix.
+    fn fmt_detail(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self {
             Error::DateOutOfRange => write!(f, "(full) year must be between 1 and 9999"),
+            Error::DateUnderflow => write!(f, "date is before 0001-01-01"),
+            Error::DateOverflow => write!(f, "date is after 9999-12-31"),
             Error::TimeOutOfRange => write!(f, "(full) hour must be between 0 and 23"),
             Error::IntervalOutOfRange => {
                 write!(f, "the leading precision of the interval is too small")
@@ -55,7 +108,11 @@ impl fmt::Display for Error {
             Error::InvalidDate => write!(f, "date not valid for month specified"),
             Error::NumericOverflow => write!(f, "numeric overflow"),
             Error::DivideByZero => write!(f, "divisor is equal to zero"),
+            Error::PrecisionLoss => write!(f, "fractional seconds would be lost in conversion"),
+            Error::InvalidInterval(ref e) => write!(f, "{}", e),
             Error::InvalidFormat(ref e) => write!(f, "{}", e),
+            Error::UnsupportedUnit(ref e) => write!(f, "{}", e),
+            Error::MissingField(name) => write!(f, "{} field is missing", name),
             Error::FormatError(ref e) => write!(f, "{}", e),
             Error::ParseError(ref e) => write!(f, "{}", e),
             Error::TryReserveError(ref e) => write!(f, "{}", e),
@@ -63,6 +120,15 @@ impl fmt::Display for Error {
     }
 }
 
+impl fmt::Display for Error {
+    /// Formats the error as `"sqldatetime: <code>: <detail>"`, where `<code>` is the stable
+    /// [`code`](Error::code) of the variant.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "sqldatetime: {}: ", self.code())?;
+        self.fmt_detail(f)
+    }
+}
+
 impl From<fmt::Error> for Error {
     #[inline]
     fn from(e: fmt::Error) -> Self {
@@ -79,3 +145,69 @@ impl From<TryReserveError> for Error {
         Error::TryReserveError(e)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_code_and_display() {
+        let try_reserve_error = Vec::<u8>::new().try_reserve(usize::MAX).unwrap_err();
+        let errors = [
+            (Error::DateOutOfRange, "date_out_of_range"),
+            (Error::DateUnderflow, "date_underflow"),
+            (Error::DateOverflow, "date_overflow"),
+            (Error::TimeOutOfRange, "time_out_of_range"),
+            (Error::IntervalOutOfRange, "interval_out_of_range"),
+            (Error::InvalidNumber, "invalid_number"),
+            (Error::InvalidMonth, "invalid_month"),
+            (Error::InvalidDay, "invalid_day"),
+            (Error::InvalidMinute, "invalid_minute"),
+            (Error::InvalidSecond, "invalid_second"),
+            (Error::InvalidFraction, "invalid_fraction"),
+            (Error::InvalidDate, "invalid_date"),
+            (Error::NumericOverflow, "numeric_overflow"),
+            (Error::DivideByZero, "divide_by_zero"),
+            (Error::PrecisionLoss, "precision_loss"),
+            (Error::InvalidInterval("a".to_string()), "invalid_interval"),
+            (Error::InvalidFormat("b".to_string()), "invalid_format"),
+            (Error::UnsupportedUnit("e".to_string()), "unsupported_unit"),
+            (Error::MissingField("year"), "missing_field"),
+            (Error::FormatError("c".to_string()), "format_error"),
+            (Error::ParseError("d".to_string()), "parse_error"),
+            (
+                Error::TryReserveError(try_reserve_error),
+                "try_reserve_error",
+            ),
+        ];
+
+        for (error, code) in errors.iter() {
+            assert_eq!(error.code(), *code);
+
+            let message = error.to_string();
+            let prefix = format!("sqldatetime: {}: ", code);
+            assert!(message.starts_with(&prefix), "{}", message);
+            assert!(message.len() > prefix.len());
+        }
+
+        // Codes are unique
+        for (i, (error, _)) in errors.iter().enumerate() {
+            assert!(errors[i + 1..]
+                .iter()
+                .all(|(e, _)| e.code() != error.code()));
+        }
+
+        assert_eq!(
+            Error::DateOutOfRange.to_string(),
+            "sqldatetime: date_out_of_range: (full) year must be between 1 and 9999"
+        );
+        assert_eq!(
+            Error::ParseError("invalid input".to_string()).to_string(),
+            "sqldatetime: parse_error: invalid input"
+        );
+        assert_eq!(
+            Error::MissingField("year").to_string(),
+            "sqldatetime: missing_field: year field is missing"
+        );
+    }
+}
@@ -1,6 +1,10 @@
 //! Formatting (and parsing) utilities for date and time.
 
-use crate::common::{is_leap_year, the_day_of_year, the_month_day_of_days, DATE_MIN_YEAR};
+use crate::common::{
+    is_leap_year, the_day_of_year, the_month_day_of_days, DATE_MIN_YEAR, HOURS_PER_DAY,
+    MINUTES_PER_HOUR, MONTHS_PER_YEAR, SECONDS_PER_DAY, SECONDS_PER_MINUTE, USECONDS_MAX,
+    USECONDS_PER_SECOND,
+};
 use crate::date::{Month, WeekDay};
 use crate::error::Result;
 use crate::format::NameStyle::{AbbrCapital, Capital};
@@ -8,16 +12,19 @@ use crate::local::Local;
 use crate::util::StrExt;
 use crate::{Date, DateTime, Error, IntervalDT, IntervalYM, Time, Timestamp};
 use stack_buf::StackVec;
+use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard};
 
-const MAX_FIELDS: usize = 36;
+/// The maximum number of fields in a format, e.g. `YYYY-MM-DD` has 5 fields.
+///
+/// The fields are stored inline in `Formatter` to avoid allocation, so this is a tradeoff between
+/// the size of `Formatter` and the length of supported formats.
+const MAX_FIELDS: usize = 64;
 
-const FRACTION_FACTOR: [f64; 10] = [
-    1000000.0, 100000.0, 10000.0, 1000.0, 100.0, 10.0, 1.0, 0.1, 0.01, 0.001,
-];
-
-const YEAR_MODIFIER: [u32; 4] = [10, 100, 1000, 10000];
+/// The initial capacity reserved for formatting into a `String`, enough for most formats.
+const FORMAT_STRING_CAPACITY: usize = 32;
 
 const MONTH_TABLE: [&str; 13] = [
     "00", "01", "02", "03", "04", "05", "06", "07", "08", "09", "10", "11", "12",
@@ -382,10 +389,84 @@ impl NaiveDateTime {
         self.usec
     }
 
+    /// Gets the fractional seconds truncated to `p` digits, see [`round_fraction`] for rounding.
+    ///
+    /// [`round_fraction`]: NaiveDateTime::round_fraction
     #[inline]
     pub fn fraction(&self, p: u8) -> u32 {
         debug_assert!(p < 10);
-        (self.usec() as f64 / FRACTION_FACTOR[p as usize]) as u32
+        if p <= 6 {
+            self.usec() / 10_u32.pow(6 - p as u32)
+        } else {
+            self.usec() * 10_u32.pow(p as u32 - 6)
+        }
+    }
+
+    /// Rounds the fractional seconds half up to `p` digits, carrying into the seconds and the
+    /// larger fields, e.g. `2021-01-01 23:59:59.96` is `2021-01-02 00:00:00.0` with one digit.
+    /// If the carry exceeds the maximum of `T`, the fractional seconds are truncated instead.
+    #[inline]
+    pub fn round_fraction<T: DateTimeFormat>(&mut self, p: u8) {
+        debug_assert!(p <= 6);
+        let unit = 10_u32.pow(6 - p as u32);
+        let usec = (self.usec + unit / 2) / unit * unit;
+        if usec < USECONDS_PER_SECOND as u32 {
+            self.usec = usec;
+        } else if !self.carry_second::<T>() {
+            self.usec = USECONDS_MAX.min(self.usec) / unit * unit;
+        }
+    }
+
+    /// Replaces the fractional seconds by one more second, returns `false` and keeps the fields
+    /// unchanged if the result is out of the range of `T`.
+    fn carry_second<T: DateTimeFormat>(&mut self) -> bool {
+        let (mut year, mut month, mut day) = (self.year, self.month, self.day);
+        let (mut hour, mut minute, mut sec) = (self.hour, self.minute, self.sec + 1);
+        if sec == SECONDS_PER_MINUTE {
+            sec = 0;
+            minute += 1;
+        }
+        if minute == MINUTES_PER_HOUR {
+            minute = 0;
+            hour += 1;
+        }
+        if hour == HOURS_PER_DAY {
+            if T::IS_INTERVAL_DT {
+                hour = 0;
+                day += 1;
+            } else if T::HAS_DATE {
+                hour = 0;
+                match Date::try_from_ymd(year, month, day).and_then(|date| date.add_days(1)) {
+                    Ok(date) => (year, month, day) = date.extract(),
+                    Err(_) => return false,
+                }
+            } else {
+                return false;
+            }
+        }
+
+        let carried = NaiveDateTime {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            sec,
+            usec: 0,
+            ampm: None,
+            negative: self.negative,
+        };
+        if T::try_from(carried).is_err() {
+            return false;
+        }
+        self.year = year;
+        self.month = month;
+        self.day = day;
+        self.hour = hour;
+        self.minute = minute;
+        self.sec = sec;
+        self.usec = 0;
+        true
     }
 
     #[inline]
@@ -489,6 +570,15 @@ impl NaiveDateTime {
     pub const fn week_of_year_str(&self) -> &str {
         WEEK_OF_YEAR_TABLE[the_day_of_year(self.year, self.month, self.day) as usize]
     }
+
+    #[inline]
+    pub fn iso_year_week(&self, date: Option<Date>) -> Result<(i32, u32)> {
+        if let Some(d) = date {
+            Ok(d.iso_year_week())
+        } else {
+            Ok(Date::try_from_ymd(self.year, self.month, self.day)?.iso_year_week())
+        }
+    }
 }
 
 impl WeekDay {
@@ -535,6 +625,12 @@ pub enum Field {
     T,
     /// 'YYYY'
     Year(u8),
+    /// 'SYYYY', signed year, formatted with a leading space for AD years like Oracle
+    SignedYear(u8),
+    /// 'CC', century, e.g. '21' for the years 2001..=2100
+    Century,
+    /// 'SCC', signed century, formatted with a leading space for AD years like Oracle
+    SignedCentury,
     /// 'MM'
     Month,
     /// 'DD'
@@ -551,8 +647,24 @@ pub enum Field {
     Minute,
     /// 'SS'
     Second,
-    /// 'FF[1..9]'
+    /// 'SSSSS', seconds past midnight
+    SecondOfDay,
+    /// 'FF[0..9]'
+    ///
+    /// Both formatting and parsing round the fractional seconds half up. Formatting with `FF1`
+    /// to `FF5` rounds to the given precision, carrying into the seconds and the larger fields,
+    /// e.g. `23:59:59.96` is `00:00:00.0` of the next day with `FF1`. At the maximum value, such
+    /// as `Time::MAX` and `Timestamp::MAX`, the carry saturates and the digits are truncated.
+    /// Parsing reads at most the given number of digits, and rounds digits beyond microseconds
+    /// the same way, except that a carry beyond the maximum value is an out of range error.
+    /// So formatting and parsing again with the same `FFn` is a fixed point.
+    ///
+    /// `FF0` is the precision of a `TIMESTAMP(0)` column: it formats no digits, and the `.` or
+    /// `X` right before it is omitted as well. On parse it reads no digits, and that radix
+    /// character is optional.
     Fraction(Option<u8>),
+    /// 'X', radix character, i.e. the decimal separator set by `Formatter::with_radix_char`
+    RadixChar,
     /// 'AM', 'A.M.', 'PM', 'P.M.'
     AmPm(AmPmStyle),
     /// 'D'
@@ -563,6 +675,21 @@ pub enum Field {
     WeekOfMonth,
     /// 'WW'
     WeekOfYear,
+    /// 'IYYY', 'IYY', 'IY', 'I'
+    IsoYear(u8),
+    /// 'IW'
+    IsoWeek,
+    /// 'FM', toggles fill mode
+    FillMode,
+    /// 'DL', long date of the default English locale, i.e. 'fmDay, Month DD, YYYY'
+    LongDate,
+    /// 'DS', short date of the default English locale, i.e. 'MM/DD/YYYY'
+    ShortDate,
+    /// 'TZD', zone abbreviation passed through as a literal, e.g. 'PDT'
+    ///
+    /// Formatting writes the string set by `Formatter::with_zone_abbr`, empty by default.
+    /// Parsing consumes any run of letters and ignores it, i.e. the zone is not applied.
+    ZoneAbbr,
 }
 
 #[derive(Debug)]
@@ -705,6 +832,29 @@ impl<'a> FormatParser<'a> {
         }
     }
 
+    #[inline]
+    fn parse_iso(&mut self) -> Field {
+        let remain = match self.remain() {
+            Some(rem) => rem,
+            None => return Field::Invalid,
+        };
+
+        if remain.len() >= 2 && remain[1].eq_ignore_ascii_case(&b'w') {
+            self.advance(2);
+            return Field::IsoWeek;
+        }
+
+        let len = remain
+            .iter()
+            .skip(1)
+            .take(3)
+            .take_while(|&y| y.eq_ignore_ascii_case(&b'y'))
+            .count();
+
+        self.advance(len + 1);
+        Field::IsoYear(len as u8 + 1)
+    }
+
     #[inline]
     fn parse_hour(&mut self) -> Field {
         let ch = match self.pop() {
@@ -732,10 +882,46 @@ impl<'a> FormatParser<'a> {
         }
     }
 
+    #[inline]
+    fn parse_century(&mut self) -> Field {
+        match self.pop() {
+            Some(b'C') | Some(b'c') => Field::Century,
+            _ => Field::Invalid,
+        }
+    }
+
     #[inline]
     fn parse_second(&mut self) -> Field {
+        if let Some(b'Y') | Some(b'y') = self.peek() {
+            return match self.parse_year() {
+                Field::Year(n) => Field::SignedYear(n),
+                _ => Field::Invalid,
+            };
+        }
+
+        if let Some(b'C') | Some(b'c') = self.peek() {
+            self.advance(1);
+            return match self.parse_century() {
+                Field::Century => Field::SignedCentury,
+                _ => Field::Invalid,
+            };
+        }
+
         match self.pop() {
-            Some(b'S') | Some(b's') => Field::Second,
+            Some(b'S') | Some(b's') => {
+                let is_second_of_day = match self.remain() {
+                    Some(rem) => {
+                        rem.len() >= 3 && rem[..3].iter().all(|ch| ch.eq_ignore_ascii_case(&b's'))
+                    }
+                    None => false,
+                };
+                if is_second_of_day {
+                    self.advance(3);
+                    Field::SecondOfDay
+                } else {
+                    Field::Second
+                }
+            }
             _ => Field::Invalid,
         }
     }
@@ -747,7 +933,7 @@ impl<'a> FormatParser<'a> {
                 Some(ch) if ch.is_ascii_digit() => {
                     self.advance(1);
                     let p = ch - b'0';
-                    if (1..=9).contains(&p) {
+                    if p <= 9 {
                         Field::Fraction(Some(p))
                     } else {
                         Field::Invalid
@@ -945,6 +1131,8 @@ impl<'a> FormatParser<'a> {
                         self.back(1);
                         self.parse_am()
                     }
+                    b'X' | b'x' => Field::RadixChar,
+                    b'C' | b'c' => self.parse_century(),
                     b'D' | b'd' => match self.peek() {
                         Some(ch) => match ch {
                             b'D' | b'd' => {
@@ -964,12 +1152,30 @@ impl<'a> FormatParser<'a> {
                                 self.back(1);
                                 self.parse_day_name()
                             }
+                            b'L' | b'l' => {
+                                self.advance(1);
+                                Field::LongDate
+                            }
+                            b'S' | b's' => {
+                                self.advance(1);
+                                Field::ShortDate
+                            }
                             _ => Field::DayOfWeek,
                         },
                         None => Field::DayOfWeek,
                     },
-                    b'F' | b'f' => self.parse_fraction(),
+                    b'F' | b'f' => match self.peek() {
+                        Some(b'M') | Some(b'm') => {
+                            self.advance(1);
+                            Field::FillMode
+                        }
+                        _ => self.parse_fraction(),
+                    },
                     b'H' | b'h' => self.parse_hour(),
+                    b'I' | b'i' => {
+                        self.back(1);
+                        self.parse_iso()
+                    }
                     b'M' | b'm' => match self.peek() {
                         Some(ch) => match ch {
                             b'I' | b'i' => {
@@ -993,7 +1199,14 @@ impl<'a> FormatParser<'a> {
                         self.parse_pm()
                     }
                     b'S' | b's' => self.parse_second(),
-                    b'T' => Field::T,
+                    b'T' | b't' => match self.remain() {
+                        Some(rem) if rem.len() >= 2 && rem[..2].eq_ignore_ascii_case(b"ZD") => {
+                            self.advance(2);
+                            Field::ZoneAbbr
+                        }
+                        _ if char == b'T' => Field::T,
+                        _ => Field::Invalid,
+                    },
                     b'Y' | b'y' => {
                         self.back(1);
                         self.parse_year()
@@ -1026,11 +1239,39 @@ impl<'a> Iterator for FormatParser<'a> {
     }
 }
 
+/// Details of the adjustments silently made while parsing, see [`Formatter::parse_with_report`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParseReport {
+    /// Whether non-zero fractional digits beyond the precision of the target type were lost.
+    ///
+    /// The fractional seconds are rounded to microseconds for types with fractional seconds, and
+    /// dropped for types without, e.g. `OracleDate`.
+    pub truncated_fraction: bool,
+    /// The date fields absent from the input and filled with defaults, in the order of
+    /// `"year"`, `"month"` and `"day"`. Year and month default to the current date, day to 1.
+    pub defaulted_fields: Vec<&'static str>,
+}
+
+impl ParseReport {
+    #[inline]
+    fn add_defaulted_field(&mut self, field: &'static str) -> Result<()> {
+        self.defaulted_fields.try_reserve(1)?;
+        self.defaulted_fields.push(field);
+        Ok(())
+    }
+}
+
 /// Date/Time formatter.
 #[derive(Debug)]
 pub struct Formatter {
     fields: StackVec<Field, MAX_FIELDS>,
     format_exact: bool,
+    assume_am_when_missing: bool,
+    ambiguous_mdy_swap: bool,
+    empty_is_error: bool,
+    strict_fraction: bool,
+    radix_char: char,
+    zone_abbr: &'static str,
 }
 
 impl Formatter {
@@ -1060,13 +1301,192 @@ impl Formatter {
         Ok(Formatter {
             fields,
             format_exact: false,
+            assume_am_when_missing: false,
+            ambiguous_mdy_swap: false,
+            empty_is_error: false,
+            strict_fraction: false,
+            radix_char: '.',
+            zone_abbr: "",
         })
     }
 
+    /// Sets whether the input must match the format exactly, like the `FX` modifier of Oracle.
+    ///
+    /// When this is on, whitespaces are not skipped, and the hour, minute and second fields must
+    /// be parsed with exactly two digits. This resolves packed formats without separators, e.g.
+    /// `"110AM"` with `"HH12MIAM"` is parsed as `11:00` by default since the fields are read
+    /// greedily, but is rejected in exact mode, which requires `"0110AM"` for `01:10`.
+    #[inline]
+    pub fn with_format_exact(mut self, format_exact: bool) -> Self {
+        self.format_exact = format_exact;
+        self
+    }
+
+    /// Sets whether `HH12` is treated as AM when the input has no meridian indicator.
+    ///
+    /// By default, the hour parsed by `HH12` without `AM`/`PM` is kept as is, so `"12:30"` with
+    /// `"HH12:MI"` is `12:30`. When this is on, the hour is interpreted as AM, i.e. `12` maps to
+    /// `0` (midnight) and `1..=11` are kept, so `"12:30"` is `00:30`.
+    #[inline]
+    pub fn with_assume_am_when_missing(mut self, assume_am_when_missing: bool) -> Self {
+        self.assume_am_when_missing = assume_am_when_missing;
+        self
+    }
+
+    /// Sets whether the month and day are swapped when the parsed month is invalid
+    /// but the parsed day would be a valid month.
+    ///
+    /// This is a heuristic for cleaning data mixing `MM/DD/YYYY` and `DD/MM/YYYY`, e.g.
+    /// `"13/05/2021"` with `"MM/DD/YYYY"` is an error by default, but is parsed as `2021-05-13`
+    /// when this is on. Values that are valid in both orders, e.g. `"05/06/2021"`, are never swapped.
+    #[inline]
+    pub fn with_ambiguous_mdy_swap(mut self, ambiguous_mdy_swap: bool) -> Self {
+        self.ambiguous_mdy_swap = ambiguous_mdy_swap;
+        self
+    }
+
+    /// Sets whether an empty input, or an input of only whitespaces, is always a parse error
+    /// of `"empty input"`.
+    ///
+    /// By default, the missing fields are filled with the current date, so an empty input with
+    /// an empty format is parsed as the first day of the current month, which is rarely intended
+    /// when importing data.
+    #[inline]
+    pub fn with_empty_is_error(mut self, empty_is_error: bool) -> Self {
+        self.empty_is_error = empty_is_error;
+        self
+    }
+
+    /// Sets whether fractional seconds that can't be kept exactly are a parse error.
+    ///
+    /// By default, a fraction with more digits than the type keeps is rounded to microseconds,
+    /// e.g. `".1234567"` with `".FF"` is `.123457`. When this is on, such input is rejected,
+    /// and so is a fraction with more digits than the `FFn` precision, e.g. `".1234567"` with
+    /// `".FF6"`. The `oracle::Date` still drops the fraction, as it has no fractional seconds.
+    #[inline]
+    pub fn with_strict_fraction(mut self, strict_fraction: bool) -> Self {
+        self.strict_fraction = strict_fraction;
+        self
+    }
+
+    /// Sets the radix character written and expected for the `X` format element, e.g. `,` for
+    /// the locales using a decimal comma, so that `"HH24:MI:SSXFF"` is `"12:30:05,123456"`.
+    /// The default is `.`.
+    #[inline]
+    pub fn with_radix_char(mut self, radix_char: char) -> Self {
+        self.radix_char = radix_char;
+        self
+    }
+
+    /// Sets the zone abbreviation written for the `TZD` format element, e.g. `PDT`. The default
+    /// is empty.
+    ///
+    /// The date time types have no time zone, so it is a literal that is not applied to the
+    /// value, and any abbreviation is accepted and ignored on parse.
+    #[inline]
+    pub fn with_zone_abbr(mut self, zone_abbr: &'static str) -> Self {
+        self.zone_abbr = zone_abbr;
+        self
+    }
+
+    /// Creates a new `Formatter` from given format string, and checks that the format fields
+    /// do not conflict with each other, e.g. `HH24` with `AM`, or duplicate fields.
+    ///
+    /// Such conflicts are otherwise only detected when parsing.
+    #[inline]
+    pub fn try_new_validated<S: AsRef<str>>(fmt: S) -> Result<Self> {
+        let formatter = Formatter::try_new(fmt)?;
+        formatter.validate()?;
+        Ok(formatter)
+    }
+
+    #[inline]
+    fn validate(&self) -> Result<()> {
+        let mut is_year_set = false;
+        let mut is_month_set = false;
+        let mut is_day_set = false;
+        let mut is_hour24_set = false;
+        let mut is_hour12_set = false;
+        let mut is_min_set = false;
+        let mut is_sec_set = false;
+        let mut is_fraction_set = false;
+        let mut is_ampm_set = false;
+        let mut is_dow_set = false;
+        let mut is_doy_set = false;
+
+        macro_rules! check_twice {
+            ($is_set: ident, $name: expr) => {{
+                if $is_set {
+                    return Err(Error::InvalidFormat(try_format!(
+                        "format code ({}) appears twice",
+                        $name
+                    )?));
+                }
+                $is_set = true;
+            }};
+        }
+
+        for field in self.fields.iter() {
+            match field {
+                Field::Year(_) | Field::SignedYear(_) => check_twice!(is_year_set, "year"),
+                Field::Month | Field::MonthName(_) => check_twice!(is_month_set, "month"),
+                Field::Day => check_twice!(is_day_set, "day"),
+                Field::Hour24 => check_twice!(is_hour24_set, "hour"),
+                Field::Hour12 => check_twice!(is_hour12_set, "hour"),
+                Field::Minute => check_twice!(is_min_set, "minute"),
+                Field::Second => check_twice!(is_sec_set, "second"),
+                Field::SecondOfDay => {
+                    check_twice!(is_sec_set, "second");
+                    check_twice!(is_min_set, "minute");
+                    check_twice!(is_hour24_set, "hour");
+                }
+                Field::Fraction(_) => check_twice!(is_fraction_set, "fraction"),
+                Field::AmPm(_) => check_twice!(is_ampm_set, "am/pm"),
+                Field::DayOfWeek | Field::DayName(_) => check_twice!(is_dow_set, "day of week"),
+                Field::DayOfYear => check_twice!(is_doy_set, "day of year"),
+                Field::LongDate => {
+                    check_twice!(is_year_set, "year");
+                    check_twice!(is_month_set, "month");
+                    check_twice!(is_day_set, "day");
+                    check_twice!(is_dow_set, "day of week");
+                }
+                Field::ShortDate => {
+                    check_twice!(is_year_set, "year");
+                    check_twice!(is_month_set, "month");
+                    check_twice!(is_day_set, "day");
+                }
+                _ => {}
+            }
+        }
+
+        if is_hour24_set && is_hour12_set {
+            return Err(Error::InvalidFormat(
+                "'HH24' and 'HH12' cannot appear together".try_to_string()?,
+            ));
+        }
+
+        if is_hour24_set && is_ampm_set {
+            return Err(Error::InvalidFormat(
+                "'HH24' precludes use of meridian indicator".try_to_string()?,
+            ));
+        }
+
+        Ok(())
+    }
+
     /// Formats datetime types
     #[inline]
     pub fn format<W: fmt::Write, T: DateTimeFormat>(&self, datetime: T, mut w: W) -> Result<()> {
-        let dt = datetime.into();
+        let mut dt: NaiveDateTime = datetime.into();
+        if T::HAS_FRACTION {
+            let precision = self.fields.iter().find_map(|field| match field {
+                Field::Fraction(Some(p)) if (1..6).contains(p) => Some(*p),
+                _ => None,
+            });
+            if let Some(p) = precision {
+                dt.round_fraction::<T>(p);
+            }
+        }
         if dt.negative() {
             // negative interval
             w.write_char('-')?;
@@ -1075,11 +1495,31 @@ impl Formatter {
         }
 
         let mut is_iso_format = false;
+        // In fill mode, leading zeros of numeric fields are suppressed
+        let mut fill_mode = false;
+
+        macro_rules! write_number_str {
+            ($s: expr) => {{
+                let s: &str = $s;
+                if fill_mode {
+                    w.write_str(trim_leading_zeros(s))?
+                } else {
+                    w.write_str(s)?
+                }
+            }};
+        }
+
+        macro_rules! write_number {
+            ($value: expr, $width: expr) => {{
+                write_u32(&mut w, $value, if fill_mode { 1 } else { $width })?
+            }};
+        }
 
         let mut iter = self.fields.iter();
         while let Some(field) = iter.next() {
             match field {
                 Field::Invalid => unreachable!(),
+                Field::FillMode => fill_mode = !fill_mode,
                 Field::Blank(n) => {
                     for _ in 0..*n {
                         w.write_char(' ')?
@@ -1090,19 +1530,33 @@ impl Formatter {
                 Field::Slash => w.write_char('/')?,
                 Field::Backslash => w.write_char('\\')?,
                 Field::Comma => w.write_char(',')?,
+                Field::RadixChar => {
+                    // no radix character for a fraction without digits
+                    if !matches!(iter.as_slice().first(), Some(&Field::Fraction(Some(0)))) {
+                        w.write_char(self.radix_char)?
+                    }
+                }
                 Field::Dot => {
+                    let s = iter.as_slice();
                     if is_iso_format {
                         // when the next field is fraction, write the dot along with the fraction
-                        let s = iter.as_slice();
                         if !matches!(s.first(), Some(&Field::Fraction(_))) {
                             w.write_char('.')?
                         }
-                    } else {
+                    } else if !matches!(s.first(), Some(&Field::Fraction(Some(0)))) {
                         w.write_char('.')?
                     }
                 }
                 Field::Semicolon => w.write_char(';')?,
                 Field::Underline => w.write_char('_')?,
+                Field::ZoneAbbr => {
+                    if T::IS_INTERVAL_YM || T::IS_INTERVAL_DT {
+                        return Err(Error::FormatError(
+                            "date format not recognized".try_to_string()?,
+                        ));
+                    }
+                    w.write_str(self.zone_abbr)?
+                }
                 Field::T => {
                     if !T::HAS_TIME {
                         return Err(Error::FormatError(
@@ -1116,7 +1570,7 @@ impl Formatter {
                 }
                 Field::Year(n) => {
                     let year = if T::HAS_DATE {
-                        dt.year() % (YEAR_MODIFIER[*n as usize - 1] as i32)
+                        dt.year() % year_modulus(*n)
                     } else if T::IS_INTERVAL_YM {
                         dt.year()
                     } else {
@@ -1124,11 +1578,37 @@ impl Formatter {
                             "date format not recognized".try_to_string()?,
                         ));
                     };
-                    write_u32(&mut w, year as u32, *n as usize)?;
+                    write_number!(year as u32, *n as usize);
+                }
+                Field::SignedYear(n) => {
+                    if T::HAS_DATE {
+                        // All years in range are AD, which have a space in place of the sign
+                        if !fill_mode {
+                            w.write_char(' ')?;
+                        }
+                        let year = dt.year() % year_modulus(*n);
+                        write_number!(year as u32, *n as usize);
+                    } else {
+                        return Err(Error::FormatError(
+                            "date format not recognized".try_to_string()?,
+                        ));
+                    }
+                }
+                Field::Century | Field::SignedCentury => {
+                    if T::HAS_DATE {
+                        if *field == Field::SignedCentury && !fill_mode {
+                            w.write_char(' ')?;
+                        }
+                        write_number!(((dt.year() - 1) / 100 + 1) as u32, 2);
+                    } else {
+                        return Err(Error::FormatError(
+                            "date format not recognized".try_to_string()?,
+                        ));
+                    }
                 }
                 Field::Month => {
                     if T::HAS_DATE || T::IS_INTERVAL_YM {
-                        w.write_str(dt.month_str())?
+                        write_number_str!(dt.month_str())
                     } else {
                         return Err(Error::FormatError(
                             "date format not recognized".try_to_string()?,
@@ -1137,10 +1617,10 @@ impl Formatter {
                 }
                 Field::Day => {
                     if T::HAS_DATE {
-                        w.write_str(dt.day_str())?
+                        write_number_str!(dt.day_str())
                     } else if T::IS_INTERVAL_DT {
                         if dt.day() < 32 {
-                            w.write_str(dt.day_str())?
+                            write_number_str!(dt.day_str())
                         } else {
                             write!(w, "{}", dt.day())?
                         }
@@ -1152,7 +1632,7 @@ impl Formatter {
                 }
                 Field::Hour24 => {
                     if T::HAS_TIME {
-                        w.write_str(dt.hour24_str())?
+                        write_number_str!(dt.hour24_str())
                     } else {
                         return Err(Error::FormatError(
                             "date format not recognized".try_to_string()?,
@@ -1161,7 +1641,7 @@ impl Formatter {
                 }
                 Field::Hour12 => {
                     if T::HAS_TIME && !T::IS_INTERVAL_DT {
-                        w.write_str(dt.hour12_str())?
+                        write_number_str!(dt.hour12_str())
                     } else {
                         return Err(Error::FormatError(
                             "date format not recognized".try_to_string()?,
@@ -1170,7 +1650,7 @@ impl Formatter {
                 }
                 Field::Minute => {
                     if T::HAS_TIME {
-                        w.write_str(dt.minute_str())?
+                        write_number_str!(dt.minute_str())
                     } else {
                         return Err(Error::FormatError(
                             "date format not recognized".try_to_string()?,
@@ -1179,7 +1659,19 @@ impl Formatter {
                 }
                 Field::Second => {
                     if T::HAS_TIME {
-                        w.write_str(dt.second_str())?
+                        write_number_str!(dt.second_str())
+                    } else {
+                        return Err(Error::FormatError(
+                            "date format not recognized".try_to_string()?,
+                        ));
+                    }
+                }
+                Field::SecondOfDay => {
+                    if T::HAS_TIME && !T::IS_INTERVAL_DT {
+                        let secs = dt.hour24() * MINUTES_PER_HOUR * SECONDS_PER_MINUTE
+                            + dt.minute() * SECONDS_PER_MINUTE
+                            + dt.sec();
+                        write_number!(secs, 5);
                     } else {
                         return Err(Error::FormatError(
                             "date format not recognized".try_to_string()?,
@@ -1188,15 +1680,17 @@ impl Formatter {
                 }
                 Field::Fraction(p) => {
                     if T::HAS_FRACTION {
-                        if is_iso_format {
-                            if dt.usec() != 0 {
-                                w.write_char('.')?;
-                                let p = p.unwrap_or(6);
+                        let p = p.unwrap_or(6);
+                        // FF0 has no digits
+                        if p != 0 {
+                            if is_iso_format {
+                                if dt.usec() != 0 {
+                                    w.write_char('.')?;
+                                    write_u32(&mut w, dt.fraction(p), p as usize)?;
+                                }
+                            } else {
                                 write_u32(&mut w, dt.fraction(p), p as usize)?;
                             }
-                        } else {
-                            let p = p.unwrap_or(6);
-                            write_u32(&mut w, dt.fraction(p), p as usize)?;
                         }
                     } else if !is_iso_format {
                         // ignore fraction in ISO format when the datetime type has no fraction
@@ -1243,7 +1737,7 @@ impl Formatter {
                 }
                 Field::DayOfYear => {
                     if T::HAS_DATE {
-                        w.write_str(dt.day_of_year_str())?
+                        write_number_str!(dt.day_of_year_str())
                     } else {
                         return Err(Error::FormatError(
                             "date format not recognized".try_to_string()?,
@@ -1252,7 +1746,7 @@ impl Formatter {
                 }
                 Field::WeekOfMonth => {
                     if T::HAS_DATE {
-                        w.write_str(dt.week_of_month_str())?
+                        write_number_str!(dt.week_of_month_str())
                     } else {
                         return Err(Error::FormatError(
                             "date format not recognized".try_to_string()?,
@@ -1261,7 +1755,51 @@ impl Formatter {
                 }
                 Field::WeekOfYear => {
                     if T::HAS_DATE {
-                        w.write_str(dt.week_of_year_str())?
+                        write_number_str!(dt.week_of_year_str())
+                    } else {
+                        return Err(Error::FormatError(
+                            "date format not recognized".try_to_string()?,
+                        ));
+                    }
+                }
+                Field::IsoYear(n) => {
+                    if T::HAS_DATE {
+                        let year = dt.iso_year_week(datetime.date())?.0 % year_modulus(*n);
+                        write_number!(year as u32, *n as usize);
+                    } else {
+                        return Err(Error::FormatError(
+                            "date format not recognized".try_to_string()?,
+                        ));
+                    }
+                }
+                Field::IsoWeek => {
+                    if T::HAS_DATE {
+                        write_number!(dt.iso_year_week(datetime.date())?.1, 2);
+                    } else {
+                        return Err(Error::FormatError(
+                            "date format not recognized".try_to_string()?,
+                        ));
+                    }
+                }
+                Field::LongDate => {
+                    if T::HAS_DATE {
+                        write!(
+                            w,
+                            "{}, {} {}, {}",
+                            dt.week_day_name(datetime.date(), NameStyle::Capital)?,
+                            dt.month_name(NameStyle::Capital),
+                            dt.day(),
+                            dt.year()
+                        )?
+                    } else {
+                        return Err(Error::FormatError(
+                            "date format not recognized".try_to_string()?,
+                        ));
+                    }
+                }
+                Field::ShortDate => {
+                    if T::HAS_DATE {
+                        write!(w, "{}/{}/{:04}", dt.month_str(), dt.day_str(), dt.year())?
                     } else {
                         return Err(Error::FormatError(
                             "date format not recognized".try_to_string()?,
@@ -1274,12 +1812,91 @@ impl Formatter {
         Ok(())
     }
 
+    /// Formats datetime types into a new `String`.
+    #[inline]
+    pub(crate) fn format_to_string<T: DateTimeFormat>(&self, datetime: T) -> Result<String> {
+        let mut s = String::new();
+        s.try_reserve(FORMAT_STRING_CAPACITY)?;
+        self.format(datetime, &mut s)?;
+        Ok(s)
+    }
+
     /// Parses datetime types
     #[inline]
     pub fn parse<S: AsRef<str>, T: DateTimeFormat>(&self, input: S) -> Result<T> {
+        self.parse_with(input, None, None, None)
+    }
+
+    /// Parses datetime types like [`parse`], and also reports the adjustments that `parse` makes
+    /// silently, such as rounded fractional digits and missing date fields filled with defaults.
+    ///
+    /// [`parse`]: Formatter::parse
+    #[inline]
+    pub fn parse_with_report<S: AsRef<str>, T: DateTimeFormat>(
+        &self,
+        input: S,
+    ) -> Result<(T, ParseReport)> {
+        let mut report = ParseReport::default();
+        let datetime = self.parse_with(input, Some(&mut report), None, None)?;
+        Ok((datetime, report))
+    }
+
+    /// Parses datetime types like [`parse`], but takes the missing year and month, and the
+    /// century of `YY`/`YYY`/`Y` years from `reference` instead of the system clock, so that
+    /// parsing is deterministic, e.g. `15` with `DD` is the 15th of the reference month.
+    ///
+    /// [`parse`]: Formatter::parse
+    #[inline]
+    pub fn parse_with_reference<S: AsRef<str>, T: DateTimeFormat>(
+        &self,
+        input: S,
+        reference: Timestamp,
+    ) -> Result<T> {
+        self.parse_with(input, None, Some(Local::from_timestamp(reference)), None)
+    }
+
+    /// Parses datetime types like [`parse`], but every field absent from the input takes its
+    /// value from `defaults` instead of the current date or zero, e.g. `15` with `DD` and the
+    /// defaults of `2020-06-01 12:30:00` is `2020-06-15 12:30:00`. The century of
+    /// `YY`/`YYY`/`Y` years also comes from the year of `defaults`.
+    ///
+    /// This applies to the fields finer than those in the format as well, e.g. the fractional
+    /// seconds of `defaults` are kept by a format without `FF`. Use a format down to `FF`, or
+    /// `defaults` with zero fractional seconds, for the values to depend on the input only.
+    ///
+    /// Intervals don't take defaults from the calendar `defaults`, and their absent fields are
+    /// zero as in [`parse`].
+    ///
+    /// [`parse`]: Formatter::parse
+    #[inline]
+    pub fn parse_with_defaults<S: AsRef<str>, T: DateTimeFormat>(
+        &self,
+        input: S,
+        defaults: Timestamp,
+    ) -> Result<T> {
+        self.parse_with(
+            input,
+            None,
+            Some(Local::from_timestamp(defaults)),
+            Some(&NaiveDateTime::from(defaults)),
+        )
+    }
+
+    #[inline]
+    fn parse_with<S: AsRef<str>, T: DateTimeFormat>(
+        &self,
+        input: S,
+        report: Option<&mut ParseReport>,
+        reference: Option<Local>,
+        defaults: Option<&NaiveDateTime>,
+    ) -> Result<T> {
+        if self.empty_is_error && input.as_ref().trim().is_empty() {
+            return Err(Error::ParseError("empty input".try_to_string()?));
+        }
+
         let result = match self.format_exact {
-            true => self.parse_internal::<S, T, true>(input),
-            false => self.parse_internal::<S, T, false>(input),
+            true => self.parse_internal::<S, T, true>(input, report, reference, defaults),
+            false => self.parse_internal::<S, T, false>(input, report, reference, defaults),
         };
         if T::IS_INTERVAL_YM || T::IS_INTERVAL_DT {
             match result {
@@ -1300,11 +1917,23 @@ impl Formatter {
     fn parse_internal<S: AsRef<str>, T: DateTimeFormat, const FX: bool>(
         &self,
         input: S,
+        mut report: Option<&mut ParseReport>,
+        reference: Option<Local>,
+        defaults: Option<&NaiveDateTime>,
     ) -> Result<T> {
         const COMPATIBLE_SEPARATOR: [u8; 8] = [b'.', b':', b'-', b'/', b'\\', b',', b';', b'_'];
 
         let mut s = input.as_ref().as_bytes();
         let mut dt = NaiveDateTime::new();
+        // The year and month defaults are taken from the reference at the end
+        let defaults = defaults.filter(|_| !T::IS_INTERVAL_YM && !T::IS_INTERVAL_DT);
+        if let Some(defaults) = defaults {
+            dt.day = defaults.day;
+            dt.hour = defaults.hour;
+            dt.minute = defaults.minute;
+            dt.sec = defaults.sec;
+            dt.usec = defaults.usec;
+        }
         let mut need_time_fields = false;
 
         macro_rules! expect_number {
@@ -1331,6 +1960,17 @@ impl Formatter {
             }};
         }
 
+        macro_rules! check_exact_width {
+            ($len_before: expr, $width: expr) => {{
+                if FX && $len_before != s.len() && $len_before - s.len() != $width {
+                    return Err(Error::ParseError(
+                        "the numeric value does not match the length of the format item"
+                            .try_to_string()?,
+                    ));
+                }
+            }};
+        }
+
         let mut is_year_set = false;
         let mut is_month_set = false;
         let mut is_day_set = false;
@@ -1344,7 +1984,8 @@ impl Formatter {
 
         let mut dow: Option<WeekDay> = None;
         let mut doy: Option<u32> = None;
-        let mut now: Option<Local> = None;
+        // The system clock is only read if a default is needed and no reference is given
+        let mut now: Option<Local> = reference;
         let mut get_now = || {
             if now.is_none() {
                 now = Some(Local::now());
@@ -1352,7 +1993,24 @@ impl Formatter {
             now.unwrap()
         };
 
-        for field in self.fields.iter() {
+        // The trailing '.' of a month abbreviation is left to the next field if it is a separator
+        let eat_month_dot = |index: usize| {
+            !matches!(
+                self.fields.get(index + 1),
+                Some(
+                    Field::Hyphen
+                        | Field::Colon
+                        | Field::Slash
+                        | Field::Backslash
+                        | Field::Comma
+                        | Field::Dot
+                        | Field::Semicolon
+                        | Field::Underline
+                )
+            )
+        };
+
+        for (index, field) in self.fields.iter().enumerate() {
             if !FX {
                 s = eat_whitespaces(s);
             }
@@ -1372,20 +2030,67 @@ impl Formatter {
                         s = &s[1..];
                     }
                     None => continue,
+                    // the dot before FF0 is optional
+                    Some(_)
+                        if *field == Field::Dot
+                            && self.fields.get(index + 1) == Some(&Field::Fraction(Some(0))) =>
+                    {
+                        continue
+                    }
                     Some(ch) => {
-                        if need_time_fields && *ch == b'Z' {
-                            // ignore trailing 'Z' in ISO 8601 format
-                            let rem = eat_whitespaces(&s[1..]);
-                            if rem.is_empty() {
-                                continue;
-                            }
+                        // ignore trailing 'Z', 'UTC' or 'GMT' in ISO 8601 format
+                        if need_time_fields && eat_trailing_zone(s)?.is_empty() {
+                            continue;
                         }
+                        let expected = match field {
+                            Field::Hyphen => '-',
+                            Field::Colon => ':',
+                            Field::Slash => '/',
+                            Field::Backslash => '\\',
+                            Field::Comma => ',',
+                            Field::Dot => '.',
+                            Field::Semicolon => ';',
+                            Field::Underline => '_',
+                            _ => unreachable!(),
+                        };
+                        let input = input.as_ref();
+                        let offset = input.len() - s.len();
                         return Err(Error::ParseError(try_format!(
-                            "the input {} is inconsistent with the format",
-                            input.as_ref()
+                            "parse error at byte {}: expected '{}' found '{}'",
+                            offset,
+                            expected,
+                            char_at(input, offset, *ch)
                         )?));
                     }
                 },
+                Field::RadixChar => {
+                    let mut buf = [0; 4];
+                    let radix_char = self.radix_char.encode_utf8(&mut buf).as_bytes();
+                    if s.starts_with(radix_char) {
+                        s = &s[radix_char.len()..];
+                    } else if self.fields.get(index + 1) == Some(&Field::Fraction(Some(0))) {
+                        // the radix character before FF0 is optional
+                    } else if let Some(ch) = s.first() {
+                        let input = input.as_ref();
+                        let offset = input.len() - s.len();
+                        return Err(Error::ParseError(try_format!(
+                            "parse error at byte {}: expected '{}' found '{}'",
+                            offset,
+                            self.radix_char,
+                            char_at(input, offset, *ch)
+                        )?));
+                    }
+                }
+                Field::ZoneAbbr => {
+                    if T::IS_INTERVAL_YM || T::IS_INTERVAL_DT {
+                        return Err(Error::ParseError(
+                            "date format not recognized".try_to_string()?,
+                        ));
+                    }
+                    // the zone is ignored
+                    let len = s.iter().take_while(|ch| ch.is_ascii_alphabetic()).count();
+                    s = &s[len..];
+                }
                 Field::T => {
                     if !T::HAS_TIME {
                         return Err(Error::ParseError(
@@ -1431,9 +2136,36 @@ impl Formatter {
                         ));
                     }
                 }
-                Field::Month => {
-                    if T::HAS_DATE || T::IS_INTERVAL_YM {
-                        if is_month_set {
+                Field::SignedYear(n) => {
+                    if T::HAS_DATE {
+                        if is_year_set {
+                            return Err(Error::ParseError(
+                                "format code (year) appears twice".try_to_string()?,
+                            ));
+                        }
+                        // The sign of an AD year can be a space, and BC years are out of range
+                        let rem = match s.first() {
+                            Some(b' ') => &s[1..],
+                            _ => s,
+                        };
+                        let (negative, year, rem) = parse_year(rem, *n as usize, &mut get_now)?;
+                        if negative || year == 0 {
+                            return Err(Error::ParseError(
+                                "(full) year must be between 1 and 9999".try_to_string()?,
+                            ));
+                        }
+                        dt.year = year;
+                        s = rem;
+                        is_year_set = true;
+                    } else {
+                        return Err(Error::ParseError(
+                            "date format not recognized".try_to_string()?,
+                        ));
+                    }
+                }
+                Field::Month => {
+                    if T::HAS_DATE || T::IS_INTERVAL_YM {
+                        if is_month_set {
                             return Err(Error::ParseError(
                                 "format code (month) appears twice".try_to_string()?,
                             ));
@@ -1450,7 +2182,7 @@ impl Formatter {
                                 month as u32
                             }
                             Err(_) => {
-                                let (month, rem) = parse_month_name(s)?;
+                                let (month, rem) = parse_month_name(s, eat_month_dot(index))?;
                                 s = rem;
                                 month as u32
                             }
@@ -1502,14 +2234,25 @@ impl Formatter {
                         let (hour, negative) = if T::IS_INTERVAL_DT {
                             expect_number!(T::HOUR_MAX_LENGTH)
                         } else {
-                            expect_time_field_with_tolerance!(T::HOUR_MAX_LENGTH, 0)
+                            let len = s.len();
+                            let result = expect_time_field_with_tolerance!(
+                                T::HOUR_MAX_LENGTH,
+                                dt.hour as i32
+                            );
+                            check_exact_width!(len, T::HOUR_MAX_LENGTH);
+                            result
                         };
                         if negative {
-                            return Err(Error::ParseError(
-                                "hour must be between 0 and 23".try_to_string()?,
-                            ));
+                            if T::IS_INTERVAL_DT && !is_day_set {
+                                // the hour is the leading field of the interval, which carries the sign
+                                dt.negative = true;
+                            } else {
+                                return Err(Error::ParseError(
+                                    "hour must be between 0 and 23".try_to_string()?,
+                                ));
+                            }
                         }
-                        dt.hour = hour as u32;
+                        dt.hour = hour.unsigned_abs();
                         is_hour24_set = Some(true);
                     } else {
                         return Err(Error::ParseError(
@@ -1528,7 +2271,9 @@ impl Formatter {
                         let hour = if s.is_empty() {
                             0
                         } else {
+                            let len = s.len();
                             let (hour, _) = expect_number!(T::HOUR_MAX_LENGTH);
+                            check_exact_width!(len, T::HOUR_MAX_LENGTH);
                             if !(1..=12).contains(&hour) {
                                 return Err(Error::ParseError(
                                     "hour must be between 1 and 12".try_to_string()?,
@@ -1556,7 +2301,13 @@ impl Formatter {
                         let (minute, negative) = if T::IS_INTERVAL_DT {
                             expect_number!(T::MINUTE_MAX_LENGTH)
                         } else {
-                            expect_time_field_with_tolerance!(T::MINUTE_MAX_LENGTH, 0)
+                            let len = s.len();
+                            let result = expect_time_field_with_tolerance!(
+                                T::MINUTE_MAX_LENGTH,
+                                dt.minute as i32
+                            );
+                            check_exact_width!(len, T::MINUTE_MAX_LENGTH);
+                            result
                         };
                         if negative {
                             return Err(Error::ParseError(
@@ -1581,7 +2332,13 @@ impl Formatter {
                         let (sec, negative) = if T::IS_INTERVAL_DT {
                             expect_number!(T::SECOND_MAX_LENGTH)
                         } else {
-                            expect_time_field_with_tolerance!(T::SECOND_MAX_LENGTH, 0)
+                            let len = s.len();
+                            let result = expect_time_field_with_tolerance!(
+                                T::SECOND_MAX_LENGTH,
+                                dt.sec as i32
+                            );
+                            check_exact_width!(len, T::SECOND_MAX_LENGTH);
+                            result
                         };
                         if negative {
                             return Err(Error::ParseError(
@@ -1596,6 +2353,48 @@ impl Formatter {
                         ));
                     }
                 }
+                Field::SecondOfDay => {
+                    if T::HAS_TIME && !T::IS_INTERVAL_DT {
+                        if is_sec_set {
+                            return Err(Error::ParseError(
+                                "format code (second) appears twice".try_to_string()?,
+                            ));
+                        }
+                        if is_min_set {
+                            return Err(Error::ParseError(
+                                "format code (minute) appears twice".try_to_string()?,
+                            ));
+                        }
+                        if is_hour24_set.is_some() {
+                            return Err(Error::ParseError(
+                                "format code (hour) appears twice".try_to_string()?,
+                            ));
+                        }
+                        if dt.ampm.is_some() {
+                            return Err(Error::ParseError(
+                                "seconds in day precludes use of meridian indicator"
+                                    .try_to_string()?,
+                            ));
+                        }
+                        let (secs, negative) = expect_number!(5);
+                        if negative || secs >= SECONDS_PER_DAY as i32 {
+                            return Err(Error::ParseError(
+                                "seconds in day must be between 0 and 86399".try_to_string()?,
+                            ));
+                        }
+                        let secs = secs as u32;
+                        dt.hour = secs / (MINUTES_PER_HOUR * SECONDS_PER_MINUTE);
+                        dt.minute = secs / SECONDS_PER_MINUTE % MINUTES_PER_HOUR;
+                        dt.sec = secs % SECONDS_PER_MINUTE;
+                        is_hour24_set = Some(true);
+                        is_min_set = true;
+                        is_sec_set = true;
+                    } else {
+                        return Err(Error::ParseError(
+                            "date format not recognized".try_to_string()?,
+                        ));
+                    }
+                }
                 Field::Fraction(p) => {
                     if T::HAS_FRACTION || is_iso_format {
                         if is_fraction_set {
@@ -1604,7 +2403,27 @@ impl Formatter {
                             ));
                         }
                         // When parsing, if FF is given, the default precision is 9
-                        (dt.usec, s) = parse_fraction::<T>(s, p.unwrap_or(9) as usize)?;
+                        let truncated;
+                        (dt.usec, truncated, s) = parse_fraction::<T>(s, p.unwrap_or(9) as usize)?;
+                        if self.strict_fraction {
+                            if matches!(s.first(), Some(ch) if ch.is_ascii_digit()) {
+                                return Err(Error::ParseError(
+                                    "the fractional seconds have more digits than the format precision"
+                                        .try_to_string()?,
+                                ));
+                            }
+                            if truncated && T::HAS_FRACTION {
+                                return Err(Error::ParseError(
+                                    "the fractional seconds have more than 6 digits"
+                                        .try_to_string()?,
+                                ));
+                            }
+                        }
+                        if truncated {
+                            if let Some(report) = report.as_deref_mut() {
+                                report.truncated_fraction = true;
+                            }
+                        }
                         is_fraction_set = true;
                     } else {
                         return Err(Error::ParseError(
@@ -1612,7 +2431,7 @@ impl Formatter {
                         ));
                     }
                 }
-                Field::AmPm(style) => {
+                Field::AmPm(_) => {
                     if T::HAS_TIME && !T::IS_INTERVAL_DT {
                         if dt.ampm.is_some() {
                             return Err(Error::ParseError(
@@ -1624,7 +2443,7 @@ impl Formatter {
                                 "'HH24' precludes use of meridian indicator".try_to_string()?,
                             ));
                         }
-                        let (am_pm, rem) = parse_ampm(s, style)?;
+                        let (am_pm, rem) = parse_ampm(s)?;
                         s = rem;
 
                         dt.ampm = am_pm;
@@ -1644,7 +2463,7 @@ impl Formatter {
                                 "format code (month) appears twice".try_to_string()?,
                             ));
                         }
-                        let (month, rem) = parse_month_name(s)?;
+                        let (month, rem) = parse_month_name(s, eat_month_dot(index))?;
                         s = rem;
 
                         dt.month = month as u32;
@@ -1722,6 +2541,37 @@ impl Formatter {
                             .try_to_string()?,
                     ))
                 }
+                Field::IsoYear(_) => {
+                    return Err(Error::ParseError(
+                        "format code (ISO year) cannot appear in date input format"
+                            .try_to_string()?,
+                    ))
+                }
+                Field::IsoWeek => {
+                    return Err(Error::ParseError(
+                        "format code (ISO week) cannot appear in date input format"
+                            .try_to_string()?,
+                    ))
+                }
+                Field::Century | Field::SignedCentury => {
+                    return Err(Error::ParseError(
+                        "format code (century) cannot appear in date input format"
+                            .try_to_string()?,
+                    ))
+                }
+                Field::LongDate => {
+                    return Err(Error::ParseError(
+                        "format code (long date) cannot appear in date input format"
+                            .try_to_string()?,
+                    ))
+                }
+                Field::ShortDate => {
+                    return Err(Error::ParseError(
+                        "format code (short date) cannot appear in date input format"
+                            .try_to_string()?,
+                    ))
+                }
+                Field::FillMode => {}
             }
         }
 
@@ -1729,9 +2579,9 @@ impl Formatter {
             s = eat_whitespaces(s);
         }
 
-        if need_time_fields && s.first() == Some(&b'Z') {
-            // ignore trailing 'Z' in ISO 8601 format
-            s = eat_whitespaces(&s[1..]);
+        if need_time_fields {
+            // ignore trailing 'Z', 'UTC' or 'GMT' in ISO 8601 format
+            s = eat_trailing_zone(s)?;
         }
 
         if !s.is_empty() {
@@ -1759,6 +2609,38 @@ impl Formatter {
             }
         }
 
+        if let Some(report) = report {
+            if T::HAS_DATE && !T::IS_INTERVAL_YM && !T::IS_INTERVAL_DT {
+                if !is_year_set {
+                    report.add_defaulted_field("year")?;
+                }
+                if !is_month_set && doy.is_none() {
+                    report.add_defaulted_field("month")?;
+                }
+                if !is_day_set && doy.is_none() {
+                    report.add_defaulted_field("day")?;
+                }
+            }
+        }
+
+        // The leading fields of interval default to zero instead of the minimum date
+        if T::IS_INTERVAL_YM && !is_year_set {
+            dt.year = 0;
+        }
+        if T::IS_INTERVAL_DT && !is_day_set {
+            dt.day = 0;
+        }
+
+        if T::HAS_DATE
+            && self.ambiguous_mdy_swap
+            && is_month_set
+            && is_day_set
+            && dt.month > MONTHS_PER_YEAR
+            && (1..=MONTHS_PER_YEAR).contains(&dt.day)
+        {
+            std::mem::swap(&mut dt.month, &mut dt.day);
+        }
+
         if let Some(d) = doy {
             let is_leap_year = is_leap_year(dt.year);
             if d == 0 || (!is_leap_year && d > 365) || (is_leap_year && d > 366) {
@@ -1798,6 +2680,14 @@ impl Formatter {
             }
         }
 
+        if self.assume_am_when_missing
+            && is_hour24_set == Some(false)
+            && dt.ampm.is_none()
+            && dt.hour == 12
+        {
+            dt.hour = 0;
+        }
+
         // Check if parsed day of week conflicts with the date
         if let Some(d) = dow {
             let date = Date::try_from(&dt)?;
@@ -1808,10 +2698,26 @@ impl Formatter {
             }
         }
 
+        // A fraction rounded up to a whole second carries into the seconds, and is out of range
+        // if the carry exceeds the maximum value
+        if dt.usec >= USECONDS_PER_SECOND as u32 {
+            dt.carry_second::<T>();
+        }
+
         T::try_from(dt)
     }
 }
 
+/// Gets the modulus keeping the last `n` digits of a year. Widths up to 9 are used for
+/// interval years, and any wider width keeps the whole year.
+#[inline]
+const fn year_modulus(n: u8) -> i32 {
+    match 10_i32.checked_pow(n as u32) {
+        Some(modulus) => modulus,
+        None => i32::MAX,
+    }
+}
+
 fn write_u32<W: fmt::Write>(mut w: W, value: u32, width: usize) -> Result<()> {
     debug_assert!(width < 11 && width > 0);
     let mut buf: [u8; 11] = [b'0'; 11];
@@ -1838,6 +2744,26 @@ fn write_u32<W: fmt::Write>(mut w: W, value: u32, width: usize) -> Result<()> {
     Ok(())
 }
 
+#[inline]
+fn trim_leading_zeros(s: &str) -> &str {
+    let trimmed = s.trim_start_matches('0');
+    if trimmed.is_empty() {
+        &s[s.len() - 1..]
+    } else {
+        trimmed
+    }
+}
+
+/// Gets the character at the byte offset of input, falls back to the byte itself if the offset
+/// is not on a character boundary.
+#[inline]
+fn char_at(input: &str, offset: usize, byte: u8) -> char {
+    match input.get(offset..).and_then(|s| s.chars().next()) {
+        Some(ch) => ch,
+        None => byte as char,
+    }
+}
+
 #[inline]
 fn expect_char(s: &[u8], expected: u8) -> bool {
     matches!(s.first(), Some(ch) if *ch == expected)
@@ -1890,6 +2816,33 @@ fn eat_whitespaces(s: &[u8]) -> &[u8] {
     &s[i..]
 }
 
+/// Eats the trailing UTC zone designator `Z`, `UTC` or `GMT` (case-insensitive) with the
+/// surrounding whitespaces. Other zone abbreviations are rejected since their offsets can't
+/// be applied.
+#[inline]
+fn eat_trailing_zone(s: &[u8]) -> Result<&[u8]> {
+    let zone = eat_whitespaces(s);
+    let len = zone.len()
+        - zone
+            .iter()
+            .rev()
+            .take_while(|ch| ch.is_ascii_whitespace())
+            .count();
+    let zone = &zone[..len];
+
+    if zone.is_empty() || !zone.iter().all(|ch| ch.is_ascii_alphabetic()) {
+        return Ok(s);
+    }
+    if zone == b"Z" || zone.eq_ignore_ascii_case(b"UTC") || zone.eq_ignore_ascii_case(b"GMT") {
+        Ok(&s[s.len()..])
+    } else {
+        Err(Error::ParseError(try_format!(
+            "unsupported time zone abbreviation {}",
+            String::from_utf8_lossy(zone)
+        )?))
+    }
+}
+
 #[inline]
 fn parse_year<'a, T: FnMut() -> Local>(
     input: &'a [u8],
@@ -1914,47 +2867,42 @@ fn parse_year<'a, T: FnMut() -> Local>(
             let (negative, year, rem) = parse_number(input, max_len)?;
             let now = get_now();
             let current_year = now.year();
-            let result_year =
-                current_year - current_year % YEAR_MODIFIER[max_len - 1] as i32 + year;
+            let result_year = current_year - current_year % year_modulus(max_len as u8) + year;
             Ok((negative, result_year, rem))
         }
         _ => parse_number(input, max_len),
     }
 }
 
+/// Parses the meridian indicator.
+///
+/// The dotted and undotted forms are interchangeable on parse as in Oracle, so both `AM` and
+/// `A.M.` are accepted (case-insensitively) regardless of the style of the format element.
 #[inline]
-fn parse_ampm<'a>(s: &'a [u8], style: &'a AmPmStyle) -> Result<(Option<AmPm>, &'a [u8])> {
+fn parse_ampm(s: &[u8]) -> Result<(Option<AmPm>, &[u8])> {
     if s.is_empty() {
         return Ok((None, s));
     }
-    match style {
-        AmPmStyle::LowerDot | AmPmStyle::UpperDot => {
-            if CaseInsensitive::starts_with(s, b"A.M.") {
-                Ok((Some(AmPm::Am), &s[4..]))
-            } else if CaseInsensitive::starts_with(s, b"P.M.") {
-                Ok((Some(AmPm::Pm), &s[4..]))
-            } else {
-                Err(Error::ParseError(
-                    "AM/A.M. or PM/P.M. required".try_to_string()?,
-                ))
-            }
-        }
-        AmPmStyle::Upper | AmPmStyle::Lower => {
-            if CaseInsensitive::starts_with(s, b"AM") {
-                Ok((Some(AmPm::Am), &s[2..]))
-            } else if CaseInsensitive::starts_with(s, b"PM") {
-                Ok((Some(AmPm::Pm), &s[2..]))
-            } else {
-                Err(Error::ParseError(
-                    "AM/A.M. or PM/P.M. required".try_to_string()?,
-                ))
-            }
-        }
+    if CaseInsensitive::starts_with(s, b"A.M.") {
+        Ok((Some(AmPm::Am), &s[4..]))
+    } else if CaseInsensitive::starts_with(s, b"P.M.") {
+        Ok((Some(AmPm::Pm), &s[4..]))
+    } else if CaseInsensitive::starts_with(s, b"AM") {
+        Ok((Some(AmPm::Am), &s[2..]))
+    } else if CaseInsensitive::starts_with(s, b"PM") {
+        Ok((Some(AmPm::Pm), &s[2..]))
+    } else {
+        Err(Error::ParseError(
+            "AM/A.M. or PM/P.M. required".try_to_string()?,
+        ))
     }
 }
 
 #[inline]
-fn parse_fraction<T: DateTimeFormat>(s: &[u8], max_len: usize) -> Result<(u32, &[u8])> {
+fn parse_fraction<T: DateTimeFormat>(s: &[u8], max_len: usize) -> Result<(u32, bool, &[u8])> {
+    if max_len == 0 {
+        return Ok((0, false, s));
+    }
     match s.first() {
         Some(ch) => {
             if *ch == b'-' {
@@ -1964,11 +2912,15 @@ fn parse_fraction<T: DateTimeFormat>(s: &[u8], max_len: usize) -> Result<(u32, &
             }
         }
         None => {
-            return Ok((0, s));
+            return Ok((0, false, s));
         }
     }
 
     let (digits, s) = eat_digits(s, max_len);
+    // Digits beyond microseconds are rounded, and any digit is dropped when fractions are not
+    // wanted
+    let kept_len = if T::HAS_FRACTION { 6 } else { 0 };
+    let truncated = digits.iter().skip(kept_len).any(|&ch| ch != b'0');
     let int = digits
         .iter()
         .fold(0, |int, &i| int * 10 + (i - b'0') as i32);
@@ -1976,23 +2928,46 @@ fn parse_fraction<T: DateTimeFormat>(s: &[u8], max_len: usize) -> Result<(u32, &
         // Truncate when we want time, but do not want fractions.  Matches oracle::Date.
         match T::HAS_FRACTION {
             false => 0u32,
-            _ => (int as f64 * FRACTION_FACTOR[digits.len()]).round() as u32,
+            // Digits beyond microseconds are rounded half up, which may give a whole second
+            _ if digits.len() > 6 => {
+                let unit = 10_u32.pow(digits.len() as u32 - 6);
+                (int as u32 + unit / 2) / unit
+            }
+            _ => int as u32 * 10_u32.pow(6 - digits.len() as u32),
         },
+        truncated,
         s,
     ))
 }
 
+/// Parses a full or abbreviated month name. `Sept` is accepted for September, and if `eat_dot`
+/// is true, a trailing `.` after an abbreviation is consumed, e.g. `Jan.`.
 #[inline]
-fn parse_month_name(s: &[u8]) -> Result<(Month, &[u8])> {
+fn parse_month_name(s: &[u8], eat_dot: bool) -> Result<(Month, &[u8])> {
     for (index, mon) in MONTH_NAME_TABLE[Capital as usize].iter().enumerate() {
         if CaseInsensitive::starts_with(s, mon.as_bytes()) {
-            return Ok((Month::from(index + 1), &s[mon.len()..]));
+            let rem = &s[mon.len()..];
+            // The full name of May is also its abbreviation
+            let rem = if eat_dot && mon.len() == 3 && expect_char(rem, b'.') {
+                &rem[1..]
+            } else {
+                rem
+            };
+            return Ok((Month::from(index + 1), rem));
         }
     }
 
     for (index, mon) in MONTH_NAME_TABLE[AbbrCapital as usize].iter().enumerate() {
         if CaseInsensitive::starts_with(s, mon.as_bytes()) {
-            return Ok((Month::from(index + 1), &s[mon.len()..]));
+            let month = Month::from(index + 1);
+            let mut rem = &s[mon.len()..];
+            if month == Month::September && matches!(rem.first(), Some(b't') | Some(b'T')) {
+                rem = &rem[1..];
+            }
+            if eat_dot && expect_char(rem, b'.') {
+                rem = &rem[1..];
+            }
+            return Ok((month, rem));
         }
     }
 
@@ -2060,6 +3035,92 @@ impl<T: DateTimeFormat> fmt::Display for LazyFormat<T> {
     }
 }
 
+/// A thread-safe cache of `Formatter`s keyed by format string.
+///
+/// It avoids tokenizing the same format string repeatedly, e.g. when evaluating
+/// `TO_CHAR(col, 'YYYY-MM-DD')` for each row. The cache holds at most `capacity` formatters,
+/// and is cleared entirely when it is full and a new format string is requested.
+#[derive(Debug)]
+pub struct FormatterCache {
+    formatters: Mutex<HashMap<String, Arc<Formatter>>>,
+    capacity: usize,
+}
+
+impl FormatterCache {
+    /// Creates a new `FormatterCache` holding at most `capacity` formatters.
+    #[inline]
+    pub fn new(capacity: usize) -> Self {
+        FormatterCache {
+            formatters: Mutex::new(HashMap::new()),
+            capacity,
+        }
+    }
+
+    /// Gets the max number of formatters held by the cache.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Gets the number of formatters held by the cache.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Checks if the cache holds no formatter.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Removes all formatters from the cache.
+    #[inline]
+    pub fn clear(&self) {
+        self.lock().clear()
+    }
+
+    /// Gets the `Formatter` of given format string, creates and caches it if absent.
+    #[inline]
+    pub fn get(&self, fmt: &str) -> Result<Arc<Formatter>> {
+        if let Some(formatter) = self.lock().get(fmt) {
+            return Ok(formatter.clone());
+        }
+
+        // Creates the formatter without holding the lock
+        let formatter = Arc::new(Formatter::try_new(fmt)?);
+
+        let mut formatters = self.lock();
+        if formatters.len() >= self.capacity {
+            formatters.clear();
+        }
+        if self.capacity > 0 {
+            formatters.insert(fmt.try_to_string()?, formatter.clone());
+        }
+        Ok(formatter)
+    }
+
+    /// Formats datetime types by given format string.
+    #[inline]
+    pub fn format<T: DateTimeFormat>(&self, fmt: &str, datetime: T) -> Result<String> {
+        self.get(fmt)?.format_to_string(datetime)
+    }
+
+    /// Parses datetime types from given string and format string.
+    #[inline]
+    pub fn parse<S: AsRef<str>, T: DateTimeFormat>(&self, input: S, fmt: &str) -> Result<T> {
+        self.get(fmt)?.parse(input)
+    }
+
+    #[inline]
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, Arc<Formatter>>> {
+        // The map is always in a consistent state, so it is safe to ignore poisoning.
+        self.formatters
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2138,6 +3199,1466 @@ mod tests {
         assert_eq!(None, parser.next())
     }
 
+    #[test]
+    fn test_second_of_day() {
+        let mut parser = FormatParser::new(b"SSSSS.FF sssss SS SSSS");
+        assert_eq!(parser.next(), Some(Field::SecondOfDay));
+        assert_eq!(parser.next(), Some(Field::Dot));
+        assert_eq!(parser.next(), Some(Field::Fraction(None)));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::SecondOfDay));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::Second));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::Second));
+        assert_eq!(parser.next(), Some(Field::Second));
+        assert_eq!(parser.next(), None);
+
+        let time = |h, m, s, u| Time::try_from_hms(h, m, s, u).unwrap();
+
+        // Format
+        let formatter = Formatter::try_new("SSSSS.FF").unwrap();
+        let mut s = String::new();
+        formatter.format(time(12, 34, 56, 500000), &mut s).unwrap();
+        assert_eq!(s, "45296.500000");
+        let mut s = String::new();
+        formatter.format(time(0, 0, 5, 0), &mut s).unwrap();
+        assert_eq!(s, "00005.000000");
+        let mut s = String::new();
+        Formatter::try_new("FMSSSSS")
+            .unwrap()
+            .format(time(0, 0, 5, 0), &mut s)
+            .unwrap();
+        assert_eq!(s, "5");
+        let mut s = String::new();
+        Formatter::try_new("YYYY-MM-DD SSSSS")
+            .unwrap()
+            .format(
+                Timestamp::new(Date::try_from_ymd(2021, 6, 1).unwrap(), time(23, 59, 59, 0)),
+                &mut s,
+            )
+            .unwrap();
+        assert_eq!(s, "2021-06-01 86399");
+
+        // Parse, combined with fraction
+        let formatter = Formatter::try_new("sssss.ff").unwrap();
+        assert_eq!(
+            formatter.parse::<_, Time>("45296.500000").unwrap(),
+            time(12, 34, 56, 500000)
+        );
+        assert_eq!(
+            formatter.parse::<_, Time>("0.000001").unwrap(),
+            time(0, 0, 0, 1)
+        );
+        assert_eq!(
+            formatter.parse::<_, Time>("86399.999999").unwrap(),
+            time(23, 59, 59, 999999)
+        );
+        assert!(formatter.parse::<_, Time>("86400.0").is_err());
+        assert!(formatter.parse::<_, Time>("-1.0").is_err());
+        assert_eq!(
+            Formatter::try_new("YYYY-MM-DD SSSSS")
+                .unwrap()
+                .parse::<_, Timestamp>("2021-06-01 3600")
+                .unwrap(),
+            Timestamp::new(Date::try_from_ymd(2021, 6, 1).unwrap(), time(1, 0, 0, 0))
+        );
+
+        // Conflicts with other time fields
+        let parse = |fmt: &str, input: &str| {
+            Formatter::try_new(fmt)
+                .unwrap()
+                .parse::<_, Time>(input)
+                .unwrap_err()
+        };
+        assert_eq!(
+            parse("SSSSS SS", "45296 56"),
+            Error::ParseError("format code (second) appears twice".to_string())
+        );
+        assert_eq!(
+            parse("SS SSSSS", "56 45296"),
+            Error::ParseError("format code (second) appears twice".to_string())
+        );
+        assert_eq!(
+            parse("SSSSS MI", "45296 34"),
+            Error::ParseError("format code (minute) appears twice".to_string())
+        );
+        assert_eq!(
+            parse("HH24 SSSSS", "12 45296"),
+            Error::ParseError("format code (hour) appears twice".to_string())
+        );
+        assert!(Formatter::try_new_validated("SSSSS SS").is_err());
+        assert!(Formatter::try_new_validated("HH SSSSS").is_err());
+        assert!(Formatter::try_new_validated("SSSSS.FF").is_ok());
+
+        // Not for intervals or dates
+        assert!(IntervalDT::parse("100", "SSSSS").is_err());
+        assert!(Date::parse("100", "SSSSS").is_err());
+    }
+
+    #[test]
+    fn test_parse_with_report() {
+        let ymd_hms = |y, mo, d, h, mi, sec, usec| {
+            Timestamp::new(
+                Date::try_from_ymd(y, mo, d).unwrap(),
+                Time::try_from_hms(h, mi, sec, usec).unwrap(),
+            )
+        };
+
+        // Nothing adjusted
+        let formatter = Formatter::try_new("YYYY-MM-DD HH24:MI:SS.FF").unwrap();
+        let (ts, report) = formatter
+            .parse_with_report::<_, Timestamp>("2021-06-01 12:34:56.123456")
+            .unwrap();
+        assert_eq!(ts, ymd_hms(2021, 6, 1, 12, 34, 56, 123456));
+        assert_eq!(report, ParseReport::default());
+
+        // 9-digit fraction into a 6-digit type
+        let (ts, report) = formatter
+            .parse_with_report::<_, Timestamp>("2021-06-01 12:34:56.123456789")
+            .unwrap();
+        assert_eq!(
+            ts,
+            formatter
+                .parse::<_, Timestamp>("2021-06-01 12:34:56.123456789")
+                .unwrap()
+        );
+        assert_eq!(ts.time().usecs() % 1_000_000, 123457);
+        assert!(report.truncated_fraction);
+        assert!(report.defaulted_fields.is_empty());
+
+        // Trailing zeros are not significant
+        let (_, report) = formatter
+            .parse_with_report::<_, Timestamp>("2021-06-01 12:34:56.123456000")
+            .unwrap();
+        assert!(!report.truncated_fraction);
+
+        // Defaulted date fields
+        let now = Local::now();
+        let (ts, report) = Formatter::try_new("HH24:MI")
+            .unwrap()
+            .parse_with_report::<_, Timestamp>("12:34")
+            .unwrap();
+        assert_eq!(ts, ymd_hms(now.year(), now.month(), 1, 12, 34, 0, 0));
+        assert_eq!(report.defaulted_fields, vec!["year", "month", "day"]);
+        let (_, report) = Formatter::try_new("YYYY-MM")
+            .unwrap()
+            .parse_with_report::<_, Date>("2021-06")
+            .unwrap();
+        assert_eq!(report.defaulted_fields, vec!["day"]);
+        let (_, report) = Formatter::try_new("DD")
+            .unwrap()
+            .parse_with_report::<_, Date>("15")
+            .unwrap();
+        assert_eq!(report.defaulted_fields, vec!["year", "month"]);
+        let (date, report) = Formatter::try_new("YYYY DDD")
+            .unwrap()
+            .parse_with_report::<_, Date>("2021 032")
+            .unwrap();
+        assert_eq!(date, Date::try_from_ymd(2021, 2, 1).unwrap());
+        assert!(report.defaulted_fields.is_empty());
+
+        // Types without date fields report nothing defaulted
+        let (_, report) = Formatter::try_new("HH24:MI")
+            .unwrap()
+            .parse_with_report::<_, Time>("12:34")
+            .unwrap();
+        assert!(report.defaulted_fields.is_empty());
+        let (_, report) = Formatter::try_new("HH24:MI")
+            .unwrap()
+            .parse_with_report::<_, IntervalDT>("12:34")
+            .unwrap();
+        assert!(report.defaulted_fields.is_empty());
+
+        // Errors are the same as parse
+        assert_eq!(
+            formatter
+                .parse_with_report::<_, Timestamp>("2021-13-01 00:00:00")
+                .unwrap_err(),
+            formatter
+                .parse::<_, Timestamp>("2021-13-01 00:00:00")
+                .unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_error_offset() {
+        let parse = |input: &str, fmt: &str| {
+            Formatter::try_new(fmt)
+                .unwrap()
+                .parse::<_, Timestamp>(input)
+                .unwrap_err()
+        };
+
+        assert_eq!(
+            parse("12:34X56", "HH24:MI:SS"),
+            Error::ParseError("parse error at byte 5: expected ':' found 'X'".to_string())
+        );
+        assert_eq!(
+            parse("2021-06-01 12X34", "YYYY-MM-DD HH24:MI"),
+            Error::ParseError("parse error at byte 13: expected ':' found 'X'".to_string())
+        );
+        assert_eq!(
+            parse("2021a06", "YYYY/MM"),
+            Error::ParseError("parse error at byte 4: expected '/' found 'a'".to_string())
+        );
+        // Any compatible separator is accepted, the offset counts leading whitespaces
+        assert_eq!(
+            parse("  12;34|56", "HH24:MI:SS"),
+            Error::ParseError("parse error at byte 7: expected ':' found '|'".to_string())
+        );
+        assert_eq!(
+            parse("  12 34", "HH24:MI"),
+            Error::ParseError("parse error at byte 5: expected ':' found '3'".to_string())
+        );
+        // Multi-byte character
+        assert_eq!(
+            parse("12:34\u{e9}56", "HH24:MI:SS"),
+            Error::ParseError("parse error at byte 5: expected ':' found '\u{e9}'".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_month_abbreviation_with_dot() {
+        let ymd = |y, m, d| Date::try_from_ymd(y, m, d).unwrap();
+        let parse = |input: &str, fmt: &str| {
+            Formatter::try_new(fmt)
+                .unwrap()
+                .parse::<_, Date>(input)
+                .unwrap()
+        };
+
+        // The dot is taken by the separator of format
+        assert_eq!(parse("Jan. 2021", "Mon. YYYY"), ymd(2021, 1, 1));
+        assert_eq!(parse("Sept. 2021", "Mon. YYYY"), ymd(2021, 9, 1));
+        assert_eq!(parse("Jan.2021", "Mon.YYYY"), ymd(2021, 1, 1));
+
+        // The dot is consumed by month name without separator in format
+        assert_eq!(parse("Jan. 2021", "Mon YYYY"), ymd(2021, 1, 1));
+        assert_eq!(parse("sept. 15 2021", "Mon DD YYYY"), ymd(2021, 9, 15));
+        assert_eq!(parse("SEPT 2021", "MONTH YYYY"), ymd(2021, 9, 1));
+        assert_eq!(parse("Dec.2021", "MonYYYY"), ymd(2021, 12, 1));
+        assert_eq!(parse("May. 2021", "Mon YYYY"), ymd(2021, 5, 1));
+        assert_eq!(parse("Feb. 03 2021", "MM DD YYYY"), ymd(2021, 2, 3));
+
+        // Full names are not abbreviations
+        assert_eq!(parse("September 2021", "Month YYYY"), ymd(2021, 9, 1));
+        assert!(Formatter::try_new("Month YYYY")
+            .unwrap()
+            .parse::<_, Date>("March. 2021")
+            .is_err());
+        assert!(Formatter::try_new("Mon YYYY")
+            .unwrap()
+            .parse::<_, Date>("Jan.. 2021")
+            .is_err());
+    }
+
+    #[test]
+    fn test_low_precision_fraction_round_trip() {
+        let ts = |y, m, d, h, mi, sec, usec| {
+            Timestamp::new(
+                Date::try_from_ymd(y, m, d).unwrap(),
+                Time::try_from_hms(h, mi, sec, usec).unwrap(),
+            )
+        };
+
+        // Rounds half up, carrying into the next year if needed
+        for (fmt, usec, expected, rounded) in [
+            (
+                "FF1",
+                123456,
+                "2021-12-31 23:59:59.1",
+                ts(2021, 12, 31, 23, 59, 59, 100000),
+            ),
+            (
+                "FF1",
+                149999,
+                "2021-12-31 23:59:59.1",
+                ts(2021, 12, 31, 23, 59, 59, 100000),
+            ),
+            (
+                "FF1",
+                150000,
+                "2021-12-31 23:59:59.2",
+                ts(2021, 12, 31, 23, 59, 59, 200000),
+            ),
+            (
+                "FF1",
+                960000,
+                "2022-01-01 00:00:00.0",
+                ts(2022, 1, 1, 0, 0, 0, 0),
+            ),
+            (
+                "FF1",
+                999999,
+                "2022-01-01 00:00:00.0",
+                ts(2022, 1, 1, 0, 0, 0, 0),
+            ),
+            (
+                "FF2",
+                123456,
+                "2021-12-31 23:59:59.12",
+                ts(2021, 12, 31, 23, 59, 59, 120000),
+            ),
+            (
+                "FF2",
+                4999,
+                "2021-12-31 23:59:59.00",
+                ts(2021, 12, 31, 23, 59, 59, 0),
+            ),
+            (
+                "FF2",
+                5000,
+                "2021-12-31 23:59:59.01",
+                ts(2021, 12, 31, 23, 59, 59, 10000),
+            ),
+            (
+                "FF2",
+                995000,
+                "2022-01-01 00:00:00.00",
+                ts(2022, 1, 1, 0, 0, 0, 0),
+            ),
+            (
+                "FF3",
+                123456,
+                "2021-12-31 23:59:59.123",
+                ts(2021, 12, 31, 23, 59, 59, 123000),
+            ),
+            (
+                "FF3",
+                123500,
+                "2021-12-31 23:59:59.124",
+                ts(2021, 12, 31, 23, 59, 59, 124000),
+            ),
+            (
+                "FF3",
+                7000,
+                "2021-12-31 23:59:59.007",
+                ts(2021, 12, 31, 23, 59, 59, 7000),
+            ),
+            (
+                "FF3",
+                999500,
+                "2022-01-01 00:00:00.000",
+                ts(2022, 1, 1, 0, 0, 0, 0),
+            ),
+        ] {
+            let formatter = Formatter::try_new(format!("YYYY-MM-DD HH24:MI:SS.{}", fmt)).unwrap();
+            let mut s = String::new();
+            formatter
+                .format(ts(2021, 12, 31, 23, 59, 59, usec), &mut s)
+                .unwrap();
+            assert_eq!(s, expected);
+
+            // Parsing the output back gives the rounded value
+            let parsed: Timestamp = formatter.parse(&s).unwrap();
+            assert_eq!(parsed, rounded);
+
+            // Formatting the parsed value again is stable
+            let mut s2 = String::new();
+            formatter.format(parsed, &mut s2).unwrap();
+            assert_eq!(s2, s);
+
+            // The same holds for `Time` within a day
+            let time = Time::try_from_hms(12, 59, 59, usec).unwrap();
+            let formatter = Formatter::try_new(format!("HH24:MI:SS.{}", fmt)).unwrap();
+            let mut s = String::new();
+            formatter.format(time, &mut s).unwrap();
+            let parsed: Time = formatter.parse(&s).unwrap();
+            let (_, _, sec, usec) = rounded.time().extract();
+            let expected = if sec == 0 {
+                Time::try_from_hms(13, 0, 0, 0).unwrap()
+            } else {
+                Time::try_from_hms(12, 59, 59, usec).unwrap()
+            };
+            assert_eq!(parsed, expected);
+            let mut s2 = String::new();
+            formatter.format(parsed, &mut s2).unwrap();
+            assert_eq!(s2, s);
+        }
+
+        // The carry saturates at the maximum value
+        assert_eq!(
+            Time::MAX.format("HH24:MI:SS.FF1").unwrap().to_string(),
+            "23:59:59.9"
+        );
+        assert_eq!(
+            Timestamp::MAX
+                .format("YYYY-MM-DD HH24:MI:SS.FF3")
+                .unwrap()
+                .to_string(),
+            "9999-12-31 23:59:59.999"
+        );
+        let interval = IntervalDT::try_from_dhms(1, 23, 59, 59, 960000).unwrap();
+        assert_eq!(
+            interval.format("DD HH24:MI:SS.FF1").unwrap().to_string(),
+            "+02 00:00:00.0"
+        );
+        assert_eq!(
+            (-interval).format("DD HH24:MI:SS.FF1").unwrap().to_string(),
+            "-02 00:00:00.0"
+        );
+
+        // Digits beyond microseconds are rounded half up when parsing
+        let formatter = Formatter::try_new("YYYY-MM-DD HH24:MI:SS.FF9").unwrap();
+        for (input, expected) in [
+            (
+                "2021-12-31 12:00:00.123456499",
+                ts(2021, 12, 31, 12, 0, 0, 123456),
+            ),
+            (
+                "2021-12-31 12:00:00.123456500",
+                ts(2021, 12, 31, 12, 0, 0, 123457),
+            ),
+            ("2021-12-31 23:59:59.999999500", ts(2022, 1, 1, 0, 0, 0, 0)),
+        ] {
+            assert_eq!(formatter.parse::<_, Timestamp>(input).unwrap(), expected);
+        }
+        assert_eq!(
+            formatter
+                .parse::<_, Timestamp>("9999-12-31 23:59:59.999999500")
+                .unwrap_err(),
+            Error::DateOutOfRange
+        );
+        let formatter = Formatter::try_new("HH24:MI:SS.FF9").unwrap();
+        assert_eq!(
+            formatter.parse::<_, Time>("12:59:59.999999500").unwrap(),
+            Time::try_from_hms(13, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            formatter
+                .parse::<_, Time>("23:59:59.999999500")
+                .unwrap_err(),
+            Error::TimeOutOfRange
+        );
+
+        // Parsing reads at most `n` digits with `FFn`
+        let formatter = Formatter::try_new("HH24:MI:SS.FF1").unwrap();
+        assert!(formatter.parse::<_, Time>("23:59:59.96").is_err());
+    }
+
+    #[test]
+    fn test_long_short_date() {
+        let mut parser = FormatParser::new(b"DL ds DS DAY D");
+        assert_eq!(parser.next(), Some(Field::LongDate));
+        assert_eq!(parser.next(), Some(Blank(1)));
+        assert_eq!(parser.next(), Some(Field::ShortDate));
+        assert_eq!(parser.next(), Some(Blank(1)));
+        assert_eq!(parser.next(), Some(Field::ShortDate));
+        assert_eq!(parser.next(), Some(Blank(1)));
+        assert_eq!(parser.next(), Some(DayName(Upper)));
+        assert_eq!(parser.next(), Some(Blank(1)));
+        assert_eq!(parser.next(), Some(DayOfWeek));
+        assert_eq!(parser.next(), None);
+
+        let format = |fmt: &str, date: Date| {
+            let mut s = String::new();
+            Formatter::try_new(fmt)
+                .unwrap()
+                .format(date, &mut s)
+                .unwrap();
+            s
+        };
+        let date = Date::try_from_ymd(2022, 10, 24).unwrap();
+        assert_eq!(format("DL", date), "Monday, October 24, 2022");
+        assert_eq!(format("DS", date), "10/24/2022");
+        assert_eq!(format("dl", date), "Monday, October 24, 2022");
+
+        // Day and year are not padded in the long date
+        let date = Date::try_from_ymd(33, 5, 1).unwrap();
+        assert_eq!(format("DL", date), "Sunday, May 1, 33");
+        assert_eq!(format("DS", date), "05/01/0033");
+
+        // Combined with time fields
+        let ts = Timestamp::new(
+            Date::try_from_ymd(2021, 6, 1).unwrap(),
+            Time::try_from_hms(12, 30, 5, 0).unwrap(),
+        );
+        let mut s = String::new();
+        Formatter::try_new("DL HH24:MI:SS")
+            .unwrap()
+            .format(ts, &mut s)
+            .unwrap();
+        assert_eq!(s, "Tuesday, June 1, 2021 12:30:05");
+
+        // Types without date
+        let mut s = String::new();
+        assert!(Formatter::try_new("DL")
+            .unwrap()
+            .format(Time::try_from_hms(1, 2, 3, 0).unwrap(), &mut s)
+            .is_err());
+
+        // Duplicate fields
+        assert_eq!(
+            Formatter::try_new_validated("DL YYYY").unwrap_err(),
+            Error::InvalidFormat("format code (year) appears twice".to_string())
+        );
+        assert!(Formatter::try_new_validated("DS DAY").is_ok());
+        assert_eq!(
+            Formatter::try_new_validated("DL DAY").unwrap_err(),
+            Error::InvalidFormat("format code (day of week) appears twice".to_string())
+        );
+
+        // Parsing is not supported
+        assert_eq!(
+            Formatter::try_new("DL")
+                .unwrap()
+                .parse::<_, Date>("Monday, October 24, 2022")
+                .unwrap_err(),
+            Error::ParseError(
+                "format code (long date) cannot appear in date input format".to_string()
+            )
+        );
+        assert!(Formatter::try_new("DS")
+            .unwrap()
+            .parse::<_, Date>("10/24/2022")
+            .is_err());
+    }
+
+    #[test]
+    fn test_parse_ampm_cross_style() {
+        let time = |h, mi| Time::try_from_hms(h, mi, 0, 0).unwrap();
+        for fmt in [
+            "HH:MI AM",
+            "HH:MI am",
+            "HH:MI A.M.",
+            "HH:MI a.m.",
+            "HH:MI PM",
+            "HH:MI p.m.",
+        ] {
+            let formatter = Formatter::try_new(fmt).unwrap();
+            for (input, expected) in [
+                ("10:30 AM", time(10, 30)),
+                ("10:30 am", time(10, 30)),
+                ("10:30 A.M.", time(10, 30)),
+                ("10:30 a.m.", time(10, 30)),
+                ("10:30 PM", time(22, 30)),
+                ("10:30 pm", time(22, 30)),
+                ("10:30 P.M.", time(22, 30)),
+                ("10:30 p.M.", time(22, 30)),
+            ] {
+                assert_eq!(formatter.parse::<_, Time>(input).unwrap(), expected);
+            }
+            assert_eq!(
+                formatter.parse::<_, Time>("10:30 A.M").unwrap_err(),
+                Error::ParseError("AM/A.M. or PM/P.M. required".to_string())
+            );
+            assert_eq!(
+                formatter.parse::<_, Time>("10:30 XM").unwrap_err(),
+                Error::ParseError("AM/A.M. or PM/P.M. required".to_string())
+            );
+        }
+
+        // The style of the format element still decides the output
+        let formatter = Formatter::try_new("HH:MI a.m.").unwrap();
+        let time = formatter.parse::<_, Time>("10:30 PM").unwrap();
+        let mut output = String::new();
+        formatter.format(time, &mut output).unwrap();
+        assert_eq!(output, "10:30 p.m.");
+    }
+
+    #[test]
+    fn test_parse_with_defaults() {
+        let date = |y, m, d| Date::try_from_ymd(y, m, d).unwrap();
+        let ts = |y, m, d, h, mi, s, us| {
+            Timestamp::new(date(y, m, d), Time::try_from_hms(h, mi, s, us).unwrap())
+        };
+        let defaults = ts(2020, 6, 1, 0, 0, 0, 0);
+
+        // Day only
+        let formatter = Formatter::try_new("DD").unwrap();
+        assert_eq!(
+            formatter
+                .parse_with_defaults::<_, Date>("15", defaults)
+                .unwrap(),
+            date(2020, 6, 15)
+        );
+        assert_eq!(
+            formatter
+                .parse_with_defaults::<_, Date>("31", defaults)
+                .unwrap_err(),
+            Error::InvalidDate
+        );
+
+        // The day and time fields are defaulted as well
+        let defaults = ts(2020, 6, 7, 12, 30, 5, 123456);
+        assert_eq!(
+            formatter
+                .parse_with_defaults::<_, Timestamp>("15", defaults)
+                .unwrap(),
+            ts(2020, 6, 15, 12, 30, 5, 123456)
+        );
+        assert_eq!(
+            Formatter::try_new("HH24")
+                .unwrap()
+                .parse_with_defaults::<_, Timestamp>("10", defaults)
+                .unwrap(),
+            ts(2020, 6, 7, 10, 30, 5, 123456)
+        );
+        assert_eq!(
+            Formatter::try_new("HH24")
+                .unwrap()
+                .parse_with_defaults::<_, Time>("10", defaults)
+                .unwrap(),
+            Time::try_from_hms(10, 30, 5, 123456).unwrap()
+        );
+        assert_eq!(
+            Formatter::try_new("YYYY-MM-DD")
+                .unwrap()
+                .parse_with_defaults::<_, Timestamp>("2021-01-02", defaults)
+                .unwrap(),
+            ts(2021, 1, 2, 12, 30, 5, 123456)
+        );
+        assert_eq!(
+            Formatter::try_new("")
+                .unwrap()
+                .parse_with_defaults::<_, Timestamp>("", defaults)
+                .unwrap(),
+            defaults
+        );
+
+        // Time fields missing at the end of input
+        let formatter = Formatter::try_new("YYYY-MM-DD HH24:MI:SS").unwrap();
+        assert_eq!(
+            formatter
+                .parse_with_defaults::<_, Timestamp>("2021-01-02 10", defaults)
+                .unwrap(),
+            ts(2021, 1, 2, 10, 30, 5, 123456)
+        );
+        assert_eq!(
+            formatter
+                .parse_with_defaults::<_, Timestamp>("2021-01-02 10:00", defaults)
+                .unwrap(),
+            ts(2021, 1, 2, 10, 0, 5, 123456)
+        );
+
+        // The century of short years comes from the defaults
+        assert_eq!(
+            Formatter::try_new("YY-MM-DD")
+                .unwrap()
+                .parse_with_defaults::<_, Date>("99-01-02", ts(1950, 1, 1, 0, 0, 0, 0))
+                .unwrap(),
+            date(1999, 1, 2)
+        );
+
+        // Complete input does not depend on the defaults
+        let formatter = Formatter::try_new("YYYY-MM-DD HH24:MI:SS.FF").unwrap();
+        assert_eq!(
+            formatter
+                .parse_with_defaults::<_, Timestamp>("2000-01-02 03:04:05.000006", defaults)
+                .unwrap(),
+            ts(2000, 1, 2, 3, 4, 5, 6)
+        );
+        // But the fields finer than the format still take the defaults
+        let formatter = Formatter::try_new("YYYY-MM-DD HH24:MI:SS").unwrap();
+        assert_eq!(
+            formatter
+                .parse_with_defaults::<_, Timestamp>("2000-01-02 03:04:05", defaults)
+                .unwrap(),
+            ts(2000, 1, 2, 3, 4, 5, 123456)
+        );
+        assert_eq!(
+            formatter
+                .parse_with_defaults::<_, Timestamp>(
+                    "2000-01-02 03:04:05",
+                    ts(2020, 6, 7, 12, 30, 5, 0)
+                )
+                .unwrap(),
+            ts(2000, 1, 2, 3, 4, 5, 0)
+        );
+
+        // Intervals don't take the calendar defaults
+        let formatter = Formatter::try_new("MM").unwrap();
+        assert_eq!(
+            formatter
+                .parse_with_defaults::<_, IntervalYM>("3", defaults)
+                .unwrap(),
+            IntervalYM::try_from_ym(0, 3).unwrap()
+        );
+        let formatter = Formatter::try_new("HH24:MI").unwrap();
+        assert_eq!(
+            formatter
+                .parse_with_defaults::<_, IntervalDT>("01:02", defaults)
+                .unwrap(),
+            IntervalDT::try_from_dhms(0, 1, 2, 0, 0).unwrap()
+        );
+        assert_eq!(
+            Formatter::try_new("DD")
+                .unwrap()
+                .parse_with_defaults::<_, IntervalDT>("3", defaults)
+                .unwrap(),
+            IntervalDT::try_from_dhms(3, 0, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_with_reference() {
+        let reference = Timestamp::new(
+            Date::try_from_ymd(2021, 6, 20).unwrap(),
+            Time::try_from_hms(12, 30, 5, 0).unwrap(),
+        );
+        let date = |y, m, d| Date::try_from_ymd(y, m, d).unwrap();
+
+        // Day only
+        let formatter = Formatter::try_new("DD").unwrap();
+        assert_eq!(
+            formatter
+                .parse_with_reference::<_, Date>("15", reference)
+                .unwrap(),
+            date(2021, 6, 15)
+        );
+        assert_eq!(
+            formatter
+                .parse_with_reference::<_, Timestamp>("15", reference)
+                .unwrap(),
+            date(2021, 6, 15).and_zero_time()
+        );
+        // Invalid for the reference month
+        assert_eq!(
+            formatter
+                .parse_with_reference::<_, Date>("31", reference)
+                .unwrap_err(),
+            Error::InvalidDate
+        );
+
+        // Month and day
+        assert_eq!(
+            Formatter::try_new("MM-DD")
+                .unwrap()
+                .parse_with_reference::<_, Date>(
+                    "02-29",
+                    Date::try_from_ymd(2020, 1, 1).unwrap().and_zero_time()
+                )
+                .unwrap(),
+            date(2020, 2, 29)
+        );
+
+        // Year only, the month comes from the reference
+        assert_eq!(
+            Formatter::try_new("YYYY")
+                .unwrap()
+                .parse_with_reference::<_, Date>("1999", reference)
+                .unwrap(),
+            date(1999, 6, 1)
+        );
+
+        // The century of short years comes from the reference
+        let formatter = Formatter::try_new("YY-MM-DD").unwrap();
+        assert_eq!(
+            formatter
+                .parse_with_reference::<_, Date>("99-01-02", reference)
+                .unwrap(),
+            date(2099, 1, 2)
+        );
+        let old = Date::try_from_ymd(1950, 3, 4).unwrap().and_zero_time();
+        assert_eq!(
+            formatter
+                .parse_with_reference::<_, Date>("99-01-02", old)
+                .unwrap(),
+            date(1999, 1, 2)
+        );
+        assert_eq!(
+            Formatter::try_new("Y-MM-DD")
+                .unwrap()
+                .parse_with_reference::<_, Date>("7-01-02", old)
+                .unwrap(),
+            date(1957, 1, 2)
+        );
+
+        // Complete input does not depend on the reference
+        assert_eq!(
+            Formatter::try_new("YYYY-MM-DD")
+                .unwrap()
+                .parse_with_reference::<_, Date>("2000-01-02", reference)
+                .unwrap(),
+            date(2000, 1, 2)
+        );
+    }
+
+    #[test]
+    fn test_zone_abbr() {
+        let mut parser = FormatParser::new(b"TZD tzd Tzd T");
+        assert_eq!(parser.next(), Some(Field::ZoneAbbr));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::ZoneAbbr));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::ZoneAbbr));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::T));
+        assert_eq!(parser.next(), None);
+        assert!(Formatter::try_new("t").is_err());
+        assert!(Formatter::try_new("TZ").is_err());
+
+        let ts = Timestamp::new(
+            Date::try_from_ymd(2022, 6, 18).unwrap(),
+            Time::try_from_hms(3, 4, 5, 0).unwrap(),
+        );
+        const FMT: &str = "YYYY-MM-DD HH24:MI:SS TZD";
+
+        // Parse with and without the abbreviation, the zone is ignored
+        for input in [
+            "2022-06-18 03:04:05 PDT",
+            "2022-06-18 03:04:05 pdt",
+            "2022-06-18 03:04:05 CEST",
+            "2022-06-18 03:04:05",
+            "2022-06-18 03:04:05 ",
+        ] {
+            assert_eq!(Timestamp::parse(input, FMT).unwrap(), ts, "{}", input);
+        }
+        assert_eq!(
+            Timestamp::parse("03:04:05.5 PDT 2022-06-18", "HH24:MI:SS.FF tzd YYYY-MM-DD").unwrap(),
+            ts.add_interval_dt(IntervalDT::try_from_dhms(0, 0, 0, 0, 500000).unwrap())
+                .unwrap()
+        );
+        assert_eq!(
+            Date::parse("2022-06-18 PDT", "YYYY-MM-DD TZD").unwrap(),
+            Date::try_from_ymd(2022, 6, 18).unwrap()
+        );
+        assert!(Timestamp::parse("2022-06-18 03:04:05 PDT1", FMT).is_err());
+        // The abbreviation is rejected without TZD
+        assert!(Timestamp::parse("2022-06-18 03:04:05 PDT", "YYYY-MM-DD HH24:MI:SS").is_err());
+
+        // Format
+        assert_eq!(ts.format(FMT).unwrap().to_string(), "2022-06-18 03:04:05 ");
+        let formatter = Formatter::try_new(FMT).unwrap().with_zone_abbr("PDT");
+        let mut output = String::new();
+        formatter.format(ts, &mut output).unwrap();
+        assert_eq!(output, "2022-06-18 03:04:05 PDT");
+        assert_eq!(formatter.parse::<_, Timestamp>(&output).unwrap(), ts);
+
+        // Intervals have no zone
+        let interval = IntervalDT::try_from_dhms(1, 0, 0, 0, 0).unwrap();
+        let mut output = String::new();
+        assert!(Formatter::try_new("DD TZD")
+            .unwrap()
+            .format(interval, &mut output)
+            .is_err());
+        assert!(IntervalDT::parse("1 PDT", "DD TZD").is_err());
+    }
+
+    #[test]
+    fn test_radix_char() {
+        let mut parser = FormatParser::new(b"SSXFF ssxff");
+        assert_eq!(parser.next(), Some(Field::Second));
+        assert_eq!(parser.next(), Some(Field::RadixChar));
+        assert_eq!(parser.next(), Some(Field::Fraction(None)));
+        assert_eq!(parser.next(), Some(Blank(1)));
+        assert_eq!(parser.next(), Some(Field::Second));
+        assert_eq!(parser.next(), Some(Field::RadixChar));
+        assert_eq!(parser.next(), Some(Field::Fraction(None)));
+        assert_eq!(parser.next(), None);
+
+        let ts = Timestamp::new(
+            Date::try_from_ymd(2021, 6, 1).unwrap(),
+            Time::try_from_hms(12, 30, 5, 123456).unwrap(),
+        );
+
+        // Default is '.'
+        let formatter = Formatter::try_new("YYYY-MM-DD HH24:MI:SSXFF").unwrap();
+        let mut s = String::new();
+        formatter.format(ts, &mut s).unwrap();
+        assert_eq!(s, "2021-06-01 12:30:05.123456");
+        assert_eq!(formatter.parse::<_, Timestamp>(&s).unwrap(), ts);
+        assert_eq!(
+            formatter
+                .parse::<_, Timestamp>("2021-06-01 12:30:05,123456")
+                .unwrap_err(),
+            Error::ParseError("parse error at byte 19: expected '.' found ','".to_string())
+        );
+
+        // Comma locale
+        let formatter = Formatter::try_new("YYYY-MM-DD HH24:MI:SSXFF")
+            .unwrap()
+            .with_radix_char(',');
+        let mut s = String::new();
+        formatter.format(ts, &mut s).unwrap();
+        assert_eq!(s, "2021-06-01 12:30:05,123456");
+        assert_eq!(formatter.parse::<_, Timestamp>(&s).unwrap(), ts);
+        assert_eq!(
+            formatter
+                .parse::<_, Timestamp>("2021-06-01 12:30:05.123456")
+                .unwrap_err(),
+            Error::ParseError("parse error at byte 19: expected ',' found '.'".to_string())
+        );
+        let mut s = String::new();
+        Formatter::try_new("HH24:MI:SS X FF3")
+            .unwrap()
+            .with_radix_char(',')
+            .format(ts.time(), &mut s)
+            .unwrap();
+        assert_eq!(s, "12:30:05 , 123");
+
+        // Missing at the end of input
+        assert_eq!(
+            formatter
+                .parse::<_, Timestamp>("2021-06-01 12:30:05")
+                .unwrap(),
+            Timestamp::new(ts.date(), Time::try_from_hms(12, 30, 5, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_century() {
+        let mut parser = FormatParser::new(b"CC scc");
+        assert_eq!(parser.next(), Some(Field::Century));
+        assert_eq!(parser.next(), Some(Blank(1)));
+        assert_eq!(parser.next(), Some(Field::SignedCentury));
+        assert_eq!(parser.next(), None);
+        assert!(Formatter::try_new("C").is_err());
+        assert!(Formatter::try_new("SC").is_err());
+
+        let format = |fmt: &str, date: Date| {
+            let mut s = String::new();
+            Formatter::try_new(fmt)
+                .unwrap()
+                .format(date, &mut s)
+                .unwrap();
+            s
+        };
+        let date = Date::try_from_ymd(2021, 6, 1).unwrap();
+        assert_eq!(format("CC", date), "21");
+        assert_eq!(format("SCC", date), " 21");
+        assert_eq!(format("FMSCC", date), "21");
+        assert_eq!(format("CC YYYY", date), "21 2021");
+        assert_eq!(
+            format("CC", Date::try_from_ymd(2000, 12, 31).unwrap()),
+            "20"
+        );
+        assert_eq!(format("CC", Date::try_from_ymd(2001, 1, 1).unwrap()), "21");
+        assert_eq!(format("CC", Date::MIN), "01");
+        assert_eq!(format("SCC", Date::MIN), " 01");
+        assert_eq!(format("CC", Date::MAX), "100");
+
+        let ts = Timestamp::new(date, Time::try_from_hms(12, 30, 5, 0).unwrap());
+        assert_eq!(ts.format("CC").unwrap().to_string(), "21");
+        assert!(Formatter::try_new("CC")
+            .unwrap()
+            .format(Time::ZERO, &mut String::new())
+            .is_err());
+
+        // Parsing is not supported
+        let err = Error::ParseError(
+            "format code (century) cannot appear in date input format".to_string(),
+        );
+        assert_eq!(
+            Date::parse("21 2021-06-01", "CC YYYY-MM-DD").unwrap_err(),
+            err
+        );
+        assert_eq!(Timestamp::parse(" 21", "SCC").unwrap_err(), err);
+    }
+
+    #[test]
+    fn test_signed_year() {
+        let mut parser = FormatParser::new(b"SYYYY syy SS");
+        assert_eq!(parser.next(), Some(Field::SignedYear(4)));
+        assert_eq!(parser.next(), Some(Blank(1)));
+        assert_eq!(parser.next(), Some(Field::SignedYear(2)));
+        assert_eq!(parser.next(), Some(Blank(1)));
+        assert_eq!(parser.next(), Some(Field::Second));
+        assert_eq!(parser.next(), None);
+        assert!(Formatter::try_new("S").is_err());
+        assert!(Formatter::try_new("SX").is_err());
+
+        let format = |fmt: &str, date: Date| {
+            let mut s = String::new();
+            Formatter::try_new(fmt)
+                .unwrap()
+                .format(date, &mut s)
+                .unwrap();
+            s
+        };
+        let date = Date::try_from_ymd(2021, 6, 1).unwrap();
+        assert_eq!(format("SYYYY", date), " 2021");
+        assert_eq!(format("SYYYY-MM-DD", date), " 2021-06-01");
+        assert_eq!(format("FMSYYYY", date), "2021");
+        assert_eq!(format("SYYYY", Date::MIN), " 0001");
+        assert_eq!(format("SYY", date), " 21");
+
+        // Parse with an optional leading space or plus sign
+        let formatter = Formatter::try_new("SYYYY-MM-DD").unwrap();
+        for input in [" 2021-06-01", "2021-06-01", "+2021-06-01"] {
+            assert_eq!(formatter.parse::<_, Date>(input).unwrap(), date);
+        }
+        assert_eq!(
+            formatter.parse::<_, Date>(" 0001-01-01").unwrap(),
+            Date::MIN
+        );
+        // BC years are out of range
+        assert_eq!(
+            formatter.parse::<_, Date>("-2021-06-01").unwrap_err(),
+            Error::ParseError("(full) year must be between 1 and 9999".to_string())
+        );
+        assert_eq!(
+            Formatter::try_new_validated("SYYYY YYYY").unwrap_err(),
+            Error::InvalidFormat("format code (year) appears twice".to_string())
+        );
+
+        // Round trip
+        let ts = Timestamp::new(date, Time::try_from_hms(12, 30, 5, 0).unwrap());
+        let formatter = Formatter::try_new("SYYYY-MM-DD HH24:MI:SS").unwrap();
+        let mut s = String::new();
+        formatter.format(ts, &mut s).unwrap();
+        assert_eq!(s, " 2021-06-01 12:30:05");
+        assert_eq!(formatter.parse::<_, Timestamp>(&s).unwrap(), ts);
+
+        // Types without date
+        let mut s = String::new();
+        assert!(Formatter::try_new("SYYYY")
+            .unwrap()
+            .format(IntervalYM::try_from_ym(1, 2).unwrap(), &mut s)
+            .is_err());
+    }
+
+    #[test]
+    fn test_wide_year_field() {
+        assert_eq!(year_modulus(1), 10);
+        assert_eq!(year_modulus(4), 10000);
+        assert_eq!(year_modulus(9), 1_000_000_000);
+        assert_eq!(year_modulus(10), i32::MAX);
+
+        let wide = |n: u8| {
+            let mut formatter = Formatter::try_new("YYYY").unwrap();
+            formatter.fields[0] = Field::Year(n);
+            formatter
+        };
+        let format = |formatter: &Formatter, interval: IntervalYM| {
+            let mut s = String::new();
+            formatter.format(interval, &mut s).unwrap();
+            s
+        };
+
+        // Interval years up to 9 digits
+        let formatter = wide(9);
+        assert_eq!(format(&formatter, IntervalYM::MAX), "+178000000");
+        assert_eq!(format(&formatter, IntervalYM::MIN), "-178000000");
+        assert_eq!(
+            format(&formatter, IntervalYM::try_from_ym(12, 0).unwrap()),
+            "+000000012"
+        );
+        assert_eq!(format(&wide(6), IntervalYM::MAX), "+178000000");
+
+        // Date years are zero padded to the width
+        let date = Date::try_from_ymd(2021, 6, 1).unwrap();
+        for (n, expected) in [(5, "02021"), (9, "000002021"), (10, "0000002021")] {
+            let mut s = String::new();
+            wide(n).format(date, &mut s).unwrap();
+            assert_eq!(s, expected);
+        }
+    }
+
+    #[test]
+    fn test_long_format() {
+        let date = Date::try_from_ymd(2021, 6, 1).unwrap();
+
+        // 50 fields
+        let fmt = format!("YYYY{} ", "-MM".repeat(24));
+        let formatter = Formatter::try_new(&fmt).unwrap();
+        assert_eq!(formatter.fields.len(), 50);
+        let mut s = String::new();
+        formatter.format(date, &mut s).unwrap();
+        assert_eq!(s, format!("2021{} ", "-06".repeat(24)));
+
+        // Separators between distinct fields can be parsed
+        let fmt = format!("YYYY{}MM{}DD", "-".repeat(30), "/".repeat(16));
+        let formatter = Formatter::try_new(&fmt).unwrap();
+        assert_eq!(formatter.fields.len(), 49);
+        let input = format!("2021{}06{}01", "-".repeat(30), "/".repeat(16));
+        assert_eq!(formatter.parse::<_, Date>(&input).unwrap(), date);
+
+        // Up to the limit
+        let fmt = "-".repeat(MAX_FIELDS);
+        assert_eq!(Formatter::try_new(&fmt).unwrap().fields.len(), MAX_FIELDS);
+        let fmt = "-".repeat(MAX_FIELDS + 1);
+        assert_eq!(
+            Formatter::try_new(&fmt).unwrap_err(),
+            Error::InvalidFormat("date format is too long for internal buffer".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_empty_is_error() {
+        let now = Date::now().unwrap();
+        let (year, month, _) = now.extract();
+
+        // Default fills the current date
+        let date: Date = Formatter::try_new("").unwrap().parse("").unwrap();
+        assert_eq!(date, Date::try_from_ymd(year, month, 1).unwrap());
+        assert!(Formatter::try_new("YYYY-MM-DD")
+            .unwrap()
+            .parse::<_, Date>("")
+            .is_err());
+
+        let empty_input = Error::ParseError("empty input".to_string());
+        for fmt in ["", "YYYY-MM-DD", "YYYY-MM-DD HH24:MI:SS"] {
+            let formatter = Formatter::try_new(fmt).unwrap().with_empty_is_error(true);
+            assert_eq!(formatter.parse::<_, Date>("").unwrap_err(), empty_input);
+            assert_eq!(
+                formatter.parse::<_, Timestamp>("").unwrap_err(),
+                empty_input
+            );
+            assert_eq!(formatter.parse::<_, Time>("  ").unwrap_err(), empty_input);
+        }
+        let formatter = Formatter::try_new("DD HH24:MI:SS")
+            .unwrap()
+            .with_empty_is_error(true);
+        assert_eq!(
+            formatter.parse::<_, IntervalDT>("").unwrap_err(),
+            empty_input
+        );
+
+        // Non-empty input is not affected
+        let formatter = Formatter::try_new("YYYY-MM-DD")
+            .unwrap()
+            .with_empty_is_error(true);
+        assert_eq!(
+            formatter.parse::<_, Date>("2021-06-01").unwrap(),
+            Date::try_from_ymd(2021, 6, 1).unwrap()
+        );
+        assert!(Formatter::try_new("")
+            .unwrap()
+            .with_empty_is_error(true)
+            .with_empty_is_error(false)
+            .parse::<_, Date>("")
+            .is_ok());
+    }
+
+    #[test]
+    fn test_fraction_precision_zero() {
+        let ts = Timestamp::new(
+            Date::try_from_ymd(2021, 10, 24).unwrap(),
+            Time::try_from_hms(12, 30, 5, 999999).unwrap(),
+        );
+
+        // Format
+        assert_eq!(ts.format("ss.ff0").unwrap().to_string(), "05");
+        assert_eq!(ts.format("SSXFF0").unwrap().to_string(), "05");
+        assert_eq!(
+            ts.format("YYYY-MM-DD HH24:MI:SS.FF0").unwrap().to_string(),
+            "2021-10-24 12:30:05"
+        );
+        assert_eq!(
+            ts.format("YYYY-MM-DDTHH24:MI:SS.FF0").unwrap().to_string(),
+            "2021-10-24T12:30:05"
+        );
+        assert_eq!(ts.format("SS.FF1").unwrap().to_string(), "06.0");
+        assert_eq!(ts.format("FF0.SS").unwrap().to_string(), ".05");
+        assert_eq!(
+            Time::try_from_hms(1, 2, 3, 456789)
+                .unwrap()
+                .format("HH24:MI:SS.FF0")
+                .unwrap()
+                .to_string(),
+            "01:02:03"
+        );
+
+        // Parse, the dot before FF0 is optional
+        let expected = Timestamp::new(
+            Date::try_from_ymd(2021, 10, 24).unwrap(),
+            Time::try_from_hms(12, 30, 5, 0).unwrap(),
+        );
+        let fmt = "YYYY-MM-DD HH24:MI:SS.FF0";
+        assert_eq!(
+            Timestamp::parse("2021-10-24 12:30:05", fmt).unwrap(),
+            expected
+        );
+        assert_eq!(
+            Timestamp::parse("2021-10-24 12:30:05.", fmt).unwrap(),
+            expected
+        );
+        assert_eq!(
+            Timestamp::parse("12:30:05 2021-10-24", "HH24:MI:SS.FF0 YYYY-MM-DD").unwrap(),
+            expected
+        );
+        assert_eq!(
+            Timestamp::parse("12:30:05 2021-10-24", "HH24:MI:SSXFF0 YYYY-MM-DD").unwrap(),
+            expected
+        );
+        assert!(Timestamp::parse("2021-10-24 12:30:05.1", fmt).is_err());
+        assert_eq!(
+            Formatter::try_new(fmt)
+                .unwrap()
+                .with_strict_fraction(true)
+                .parse::<_, Timestamp>("2021-10-24 12:30:05.1")
+                .unwrap_err(),
+            Error::ParseError(
+                "the fractional seconds have more digits than the format precision".to_string()
+            )
+        );
+
+        // Round trip
+        let formatted = ts.format(fmt).unwrap().to_string();
+        assert_eq!(Timestamp::parse(formatted, fmt).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_parse_strict_fraction() {
+        let ts = Timestamp::new(
+            Date::try_from_ymd(2021, 1, 1).unwrap(),
+            Time::try_from_hms(0, 0, 0, 123456).unwrap(),
+        );
+        let more_than_precision = Error::ParseError(
+            "the fractional seconds have more digits than the format precision".to_string(),
+        );
+        let more_than_micros =
+            Error::ParseError("the fractional seconds have more than 6 digits".to_string());
+
+        // Lenient by default: extra digits are rounded
+        let lenient = Formatter::try_new("YYYY-MM-DD HH24:MI:SS.FF").unwrap();
+        assert_eq!(
+            lenient
+                .parse::<_, Timestamp>("2021-01-01 00:00:00.1234564")
+                .unwrap(),
+            ts
+        );
+        assert_eq!(
+            lenient
+                .parse::<_, Timestamp>("2021-01-01 00:00:00.1234567")
+                .unwrap(),
+            ts.add_interval_dt(IntervalDT::try_from_dhms(0, 0, 0, 0, 1).unwrap())
+                .unwrap()
+        );
+
+        // Strict rejects the digits beyond the `FFn` precision
+        let strict = Formatter::try_new("YYYY-MM-DD HH24:MI:SS.FF6")
+            .unwrap()
+            .with_strict_fraction(true);
+        assert_eq!(
+            strict
+                .parse::<_, Timestamp>("2021-01-01 00:00:00.1234567")
+                .unwrap_err(),
+            more_than_precision
+        );
+        assert_eq!(
+            strict
+                .parse::<_, Timestamp>("2021-01-01 00:00:00.123456")
+                .unwrap(),
+            ts
+        );
+        let strict = Formatter::try_new("HH12:MI:SS.FF3 AM")
+            .unwrap()
+            .with_strict_fraction(true);
+        assert_eq!(
+            strict.parse::<_, Time>("10:00:00.1234 PM").unwrap_err(),
+            more_than_precision
+        );
+        assert_eq!(
+            strict.parse::<_, Time>("10:00:00.123 PM").unwrap(),
+            Time::try_from_hms(22, 0, 0, 123000).unwrap()
+        );
+
+        // Strict rejects the digits that would be rounded to microseconds
+        let strict = Formatter::try_new("YYYY-MM-DD HH24:MI:SS.FF")
+            .unwrap()
+            .with_strict_fraction(true);
+        assert_eq!(
+            strict
+                .parse::<_, Timestamp>("2021-01-01 00:00:00.1234567")
+                .unwrap_err(),
+            more_than_micros
+        );
+        assert_eq!(
+            strict
+                .parse::<_, Timestamp>("2021-01-01 00:00:00.123456000")
+                .unwrap(),
+            ts
+        );
+        let strict = Formatter::try_new("DD HH24:MI:SS.FF")
+            .unwrap()
+            .with_strict_fraction(true);
+        assert_eq!(
+            strict
+                .parse::<_, IntervalDT>("1 00:00:00.1234567")
+                .unwrap_err(),
+            Error::ParseError("the interval is invalid".to_string())
+        );
+
+        // Can be turned off again
+        assert!(Formatter::try_new("YYYY-MM-DD HH24:MI:SS.FF")
+            .unwrap()
+            .with_strict_fraction(true)
+            .with_strict_fraction(false)
+            .parse::<_, Timestamp>("2021-01-01 00:00:00.1234567")
+            .is_ok());
+    }
+
+    #[test]
+    fn test_parse_ambiguous_mdy_swap() {
+        let strict = Formatter::try_new("MM/DD/YYYY").unwrap();
+        let lenient = Formatter::try_new("MM/DD/YYYY")
+            .unwrap()
+            .with_ambiguous_mdy_swap(true);
+        let date = |y, m, d| Date::try_from_ymd(y, m, d).unwrap();
+
+        // Default is strict
+        assert!(strict.parse::<_, Date>("13/05/2021").is_err());
+        assert_eq!(
+            lenient.parse::<_, Date>("13/05/2021").unwrap(),
+            date(2021, 5, 13)
+        );
+        assert_eq!(
+            lenient.parse::<_, Date>("31/12/2021").unwrap(),
+            date(2021, 12, 31)
+        );
+
+        // Valid in both orders, never swapped
+        assert_eq!(
+            lenient.parse::<_, Date>("05/06/2021").unwrap(),
+            date(2021, 5, 6)
+        );
+        assert_eq!(
+            lenient.parse::<_, Date>("12/13/2021").unwrap(),
+            date(2021, 12, 13)
+        );
+
+        // Not swappable
+        assert!(lenient.parse::<_, Date>("13/13/2021").is_err());
+        assert!(lenient.parse::<_, Date>("13/00/2021").is_err());
+        assert!(lenient.parse::<_, Date>("00/05/2021").is_err());
+        // Swapped but still invalid
+        assert!(lenient.parse::<_, Date>("30/02/2021").is_err());
+
+        // Also for DD/MM/YYYY and timestamps
+        let lenient = Formatter::try_new("DD/MM/YYYY HH24:MI")
+            .unwrap()
+            .with_ambiguous_mdy_swap(true);
+        assert_eq!(
+            lenient.parse::<_, Timestamp>("05/13/2021 10:30").unwrap(),
+            Timestamp::new(date(2021, 5, 13), Time::try_from_hms(10, 30, 0, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_parse_assume_am_when_missing() {
+        let default = Formatter::try_new("HH12:MI").unwrap();
+        let assume_am = Formatter::try_new("HH12:MI")
+            .unwrap()
+            .with_assume_am_when_missing(true);
+        let time = |h, m| Time::try_from_hms(h, m, 0, 0).unwrap();
+
+        // Default is unchanged
+        assert_eq!(default.parse::<_, Time>("12:30").unwrap(), time(12, 30));
+        assert_eq!(default.parse::<_, Time>("07:30").unwrap(), time(7, 30));
+
+        assert_eq!(assume_am.parse::<_, Time>("12:30").unwrap(), time(0, 30));
+        assert_eq!(assume_am.parse::<_, Time>("07:30").unwrap(), time(7, 30));
+        assert_eq!(assume_am.parse::<_, Time>("11:59").unwrap(), time(11, 59));
+        assert!(assume_am.parse::<_, Time>("13:00").is_err());
+
+        // Explicit meridian takes precedence
+        let assume_am = Formatter::try_new("HH12:MI AM")
+            .unwrap()
+            .with_assume_am_when_missing(true);
+        assert_eq!(
+            assume_am.parse::<_, Time>("12:30 PM").unwrap(),
+            time(12, 30)
+        );
+        assert_eq!(assume_am.parse::<_, Time>("12:30 AM").unwrap(), time(0, 30));
+        assert_eq!(
+            assume_am.parse::<_, Time>("07:30 PM").unwrap(),
+            time(19, 30)
+        );
+
+        // HH24 is not affected
+        let hour24 = Formatter::try_new("HH24:MI")
+            .unwrap()
+            .with_assume_am_when_missing(true);
+        assert_eq!(hour24.parse::<_, Time>("12:30").unwrap(), time(12, 30));
+
+        let ts: Timestamp = Formatter::try_new("YYYY-MM-DD HH:MI:SS")
+            .unwrap()
+            .with_assume_am_when_missing(true)
+            .parse("2021-06-01 12:00:05")
+            .unwrap();
+        assert_eq!(
+            ts,
+            Timestamp::new(
+                Date::try_from_ymd(2021, 6, 1).unwrap(),
+                Time::try_from_hms(0, 0, 5, 0).unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn test_format_fill_mode() {
+        let mut parser = FormatParser::new(b"FMMM/fmDD FF3");
+        assert_eq!(parser.next(), Some(Field::FillMode));
+        assert_eq!(parser.next(), Some(Field::Month));
+        assert_eq!(parser.next(), Some(Field::Slash));
+        assert_eq!(parser.next(), Some(Field::FillMode));
+        assert_eq!(parser.next(), Some(Field::Day));
+        assert_eq!(parser.next(), Some(Field::Blank(1)));
+        assert_eq!(parser.next(), Some(Field::Fraction(Some(3))));
+        assert_eq!(parser.next(), None);
+
+        let format = |fmt: &str, ts: Timestamp| {
+            let mut s = String::new();
+            Formatter::try_new(fmt).unwrap().format(ts, &mut s).unwrap();
+            s
+        };
+
+        let ts = Timestamp::new(
+            Date::try_from_ymd(2021, 6, 5).unwrap(),
+            Time::try_from_hms(8, 5, 0, 7000).unwrap(),
+        );
+        assert_eq!(format("FMMM/DD/YYYY", ts), "6/5/2021");
+        // 'FM' toggles, so the second one turns fill mode off again
+        assert_eq!(format("FMMM/FMDD/YYYY", ts), "6/05/2021");
+        assert_eq!(format("MM/DD/YYYY", ts), "06/05/2021");
+        assert_eq!(
+            format("FMHH24:MI:SS HH12 DDD WW W IW", ts),
+            "8:5:0 8 156 23 1 22"
+        );
+        // Fraction digits are significant, so they are kept
+        assert_eq!(format("FMSS.FF3", ts), "0.007");
+        assert_eq!(format("FMMonth DD, YYYY", ts), "June 5, 2021");
+        assert_eq!(format("FMDay", ts), "Saturday");
+
+        let ts = Timestamp::new(Date::try_from_ymd(5, 10, 20).unwrap(), Time::ZERO);
+        assert_eq!(format("FMYYYY-MM-DD", ts), "5-10-20");
+        assert_eq!(format("FMYY IYYY", ts), "5 5");
+
+        let interval = IntervalDT::try_from_dhms(3, 4, 5, 6, 0).unwrap();
+        let mut s = String::new();
+        Formatter::try_new("FMDD HH24:MI:SS")
+            .unwrap()
+            .format(interval, &mut s)
+            .unwrap();
+        assert_eq!(s, "+3 4:5:6");
+
+        // Fill mode is ignored when parsing
+        let date: Date = Formatter::try_new("FMMM/FMDD/YYYY")
+            .unwrap()
+            .parse("6/5/2021")
+            .unwrap();
+        assert_eq!(date, Date::try_from_ymd(2021, 6, 5).unwrap());
+    }
+
+    #[test]
+    fn test_format_parser_iso() {
+        let mut parser = FormatParser::new(b"IYYY-IW iyy iy i IYYYY Iw");
+
+        let expect = [
+            Field::IsoYear(4),
+            Field::Hyphen,
+            Field::IsoWeek,
+            Blank(1),
+            Field::IsoYear(3),
+            Blank(1),
+            Field::IsoYear(2),
+            Blank(1),
+            Field::IsoYear(1),
+            Blank(1),
+            Field::IsoYear(4),
+            Field::Year(1),
+            Blank(1),
+            Field::IsoWeek,
+        ];
+        for e in expect.iter() {
+            assert_eq!(e, &parser.next().unwrap())
+        }
+        assert_eq!(None, parser.next())
+    }
+
     #[test]
     fn test_formatter() {
         assert!(Formatter::try_new(
@@ -2163,6 +4684,196 @@ mod tests {
         println!("{}", res);
     }
 
+    #[test]
+    fn test_formatter_validated() {
+        fn assert_invalid(fmt: &str, msg: &str) {
+            assert!(Formatter::try_new(fmt).is_ok());
+            match Formatter::try_new_validated(fmt) {
+                Err(Error::InvalidFormat(m)) => assert_eq!(m, msg),
+                _ => panic!("format {} should be invalid", fmt),
+            }
+        }
+
+        assert!(Formatter::try_new_validated("YYYY-MM-DD HH24:MI:SS.FF").is_ok());
+        assert!(Formatter::try_new_validated("YYYY-MON-DD HH12:MI:SS AM").is_ok());
+        assert!(Formatter::try_new_validated("DAY, DD MONTH YYYY HH:MI PM").is_ok());
+        assert!(Formatter::try_new_validated("YYYY-MM-DDTHH24:MI:SS.FF").is_ok());
+        assert!(Formatter::try_new_validated("YYYY-DDD").is_ok());
+        assert!(Formatter::try_new_validated("").is_ok());
+
+        assert_invalid("HH24:MI AM", "'HH24' precludes use of meridian indicator");
+        assert_invalid("A.M. HH24:MI", "'HH24' precludes use of meridian indicator");
+        assert_invalid("HH24 HH12", "'HH24' and 'HH12' cannot appear together");
+        assert_invalid("HH12 HH24 AM", "'HH24' and 'HH12' cannot appear together");
+        assert_invalid("YYYY-MM-DD YYYY", "format code (year) appears twice");
+        assert_invalid("YYYY-MM-DD YY", "format code (year) appears twice");
+        assert_invalid("YYYY-MM-MON", "format code (month) appears twice");
+        assert_invalid("YYYY-MM-DD DD", "format code (day) appears twice");
+        assert_invalid("HH24 HH24", "format code (hour) appears twice");
+        assert_invalid("HH HH12", "format code (hour) appears twice");
+        assert_invalid("MI:MI", "format code (minute) appears twice");
+        assert_invalid("SS:SS", "format code (second) appears twice");
+        assert_invalid("FF FF3", "format code (fraction) appears twice");
+        assert_invalid("HH AM PM", "format code (am/pm) appears twice");
+        assert_invalid("DY D", "format code (day of week) appears twice");
+        assert_invalid("DDD DDD", "format code (day of year) appears twice");
+
+        // Tokenizing errors are still reported
+        assert!(matches!(
+            Formatter::try_new_validated("YYYY-ZZ"),
+            Err(Error::InvalidFormat(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_format_exact() {
+        fn parse_time(input: &str, fmt: &str, format_exact: bool) -> Result<Time> {
+            Formatter::try_new(fmt)
+                .unwrap()
+                .with_format_exact(format_exact)
+                .parse(input)
+        }
+
+        let time = |h, m, s| Time::try_from_hms(h, m, s, 0).unwrap();
+
+        // Greedy reading mis-parses packed formats
+        assert_eq!(
+            parse_time("110AM", "HH12MIAM", false).unwrap(),
+            time(11, 0, 0)
+        );
+        assert_eq!(
+            parse_time("1005", "HH24MISS", false).unwrap(),
+            time(10, 5, 0)
+        );
+
+        assert!(parse_time("110AM", "HH12MIAM", true).is_err());
+        assert!(parse_time("930AM", "HH12MIAM", true).is_err());
+        assert_eq!(
+            parse_time("0110AM", "HH12MIAM", true).unwrap(),
+            time(1, 10, 0)
+        );
+        assert_eq!(
+            parse_time("0930AM", "HH12MIAM", true).unwrap(),
+            time(9, 30, 0)
+        );
+        assert_eq!(
+            parse_time("0930PM", "HH12MIPM", true).unwrap(),
+            time(21, 30, 0)
+        );
+        assert_eq!(
+            parse_time("1230AM", "HH12MIAM", true).unwrap(),
+            time(0, 30, 0)
+        );
+        assert_eq!(
+            parse_time("093005PM", "HHMISSAM", true).unwrap(),
+            time(21, 30, 5)
+        );
+        assert!(parse_time("09305PM", "HHMISSAM", true).is_err());
+        assert_eq!(
+            parse_time("093000", "HH24MISS", true).unwrap(),
+            time(9, 30, 0)
+        );
+        assert!(parse_time("93000", "HH24MISS", true).is_err());
+        assert!(parse_time("10530", "HH24MISS", true).is_err());
+        assert_eq!(
+            parse_time("100530", "HH24MISS", true).unwrap(),
+            time(10, 5, 30)
+        );
+
+        // Whitespaces are not skipped
+        assert_eq!(
+            parse_time("09 30", "HH24MI", false).unwrap(),
+            time(9, 30, 0)
+        );
+        assert!(parse_time("09 30", "HH24MI", true).is_err());
+
+        // Missing trailing time fields are still tolerated
+        assert_eq!(parse_time("09", "HH24:MI:SS", true).unwrap(), time(9, 0, 0));
+        assert!(parse_time("9:30:00", "HH24:MI:SS", true).is_err());
+
+        let ts = Formatter::try_new("YYYYMMDDHH12MIAM")
+            .unwrap()
+            .with_format_exact(true)
+            .parse::<_, Timestamp>("202106010110PM")
+            .unwrap();
+        assert_eq!(
+            ts,
+            Timestamp::new(Date::try_from_ymd(2021, 6, 1).unwrap(), time(13, 10, 0))
+        );
+    }
+
+    #[test]
+    fn test_formatter_cache() {
+        let cache = FormatterCache::new(2);
+        assert_eq!(cache.capacity(), 2);
+        assert!(cache.is_empty());
+
+        let date = Date::try_from_ymd(2021, 6, 1).unwrap();
+        let ts = date.and_time(Time::try_from_hms(12, 34, 56, 0).unwrap());
+
+        assert_eq!(cache.format("YYYY-MM-DD", date).unwrap(), "2021-06-01");
+        assert_eq!(cache.format("YYYY-MM-DD", ts).unwrap(), "2021-06-01");
+        assert_eq!(cache.len(), 1);
+
+        let first = cache.get("YYYY-MM-DD").unwrap();
+        let second = cache.get("YYYY-MM-DD").unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+
+        assert_eq!(
+            cache.format("YYYY-MM-DD HH24:MI:SS", ts).unwrap(),
+            "2021-06-01 12:34:56"
+        );
+        assert_eq!(
+            cache
+                .parse::<_, Timestamp>("2021-06-01 12:34:56", "YYYY-MM-DD HH24:MI:SS")
+                .unwrap(),
+            ts
+        );
+        assert_eq!(cache.len(), 2);
+
+        // Clears the cache when it is full
+        assert_eq!(cache.format("DD/MM/YYYY", date).unwrap(), "01/06/2021");
+        assert_eq!(cache.len(), 1);
+        assert!(!Arc::ptr_eq(&first, &cache.get("YYYY-MM-DD").unwrap()));
+        assert_eq!(cache.len(), 2);
+
+        // Invalid format is not cached
+        assert!(cache.format("YYYY-ZZ", date).is_err());
+        assert_eq!(cache.len(), 2);
+
+        // Format error of the datetime type
+        assert!(cache.format("HH24", date).is_err());
+
+        cache.clear();
+        assert!(cache.is_empty());
+
+        // Zero capacity caches nothing
+        let cache = FormatterCache::new(0);
+        assert_eq!(cache.format("YYYY", date).unwrap(), "2021");
+        assert!(cache.is_empty());
+
+        // Shared between threads
+        let cache = Arc::new(FormatterCache::new(16));
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                let cache = cache.clone();
+                std::thread::spawn(move || {
+                    let date = Date::try_from_ymd(2021, 6, i + 1).unwrap();
+                    for _ in 0..100 {
+                        assert_eq!(
+                            cache.format("YYYY-MM-DD", date).unwrap(),
+                            format!("2021-06-0{}", i + 1)
+                        );
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(cache.len(), 1);
+    }
+
     #[test]
     fn test_write_u32() {
         fn assert(val: u32, expected: &str, width: usize) {
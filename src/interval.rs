@@ -1,16 +1,19 @@
 //! Interval implementation.
 
 use crate::common::{
-    HOURS_PER_DAY, MINUTES_PER_HOUR, MONTHS_PER_YEAR, SECONDS_PER_MINUTE, USECONDS_MAX,
-    USECONDS_PER_DAY, USECONDS_PER_HOUR, USECONDS_PER_MINUTE, USECONDS_PER_SECOND,
+    HOURS_PER_DAY, MINUTES_PER_HOUR, MONTHS_PER_YEAR, SECONDS_PER_DAY, SECONDS_PER_MINUTE,
+    TIMESTAMP_MAX, TIMESTAMP_MIN, USECONDS_MAX, USECONDS_PER_DAY, USECONDS_PER_HOUR,
+    USECONDS_PER_MINUTE, USECONDS_PER_SECOND,
 };
 use crate::error::{Error, Result};
 use crate::format::{LazyFormat, NaiveDateTime};
 use crate::interval::Sign::{Negative, Positive};
-use crate::{Date, Time};
+use crate::util::StrExt;
+use crate::{Date, Time, Timestamp};
 use crate::{DateTime, Formatter};
 use std::cmp::Ordering;
 use std::convert::TryFrom;
+use std::fmt;
 use std::fmt::Display;
 use std::ops::Neg;
 
@@ -29,7 +32,7 @@ pub enum Sign {
 /// `Year-Month Interval` represents the duration of a period of time,
 /// has an interval precision that includes a YEAR field or a MONTH field, or both.
 #[allow(clippy::upper_case_acronyms)]
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
 #[repr(transparent)]
 pub struct IntervalYM(i32);
 
@@ -132,6 +135,12 @@ impl IntervalYM {
         Ok(LazyFormat::new(fmt, self))
     }
 
+    /// Formats `IntervalYM` into a new `String` by given formatter, which can be reused across calls.
+    #[inline]
+    pub fn to_string_with(self, fmt: &Formatter) -> Result<String> {
+        fmt.format_to_string(self)
+    }
+
     /// Parses `IntervalYM` from given string and format.
     #[inline]
     pub fn parse<S1: AsRef<str>, S2: AsRef<str>>(input: S1, fmt: S2) -> Result<Self> {
@@ -160,6 +169,27 @@ impl IntervalYM {
         self.add_interval_ym(interval.negate())
     }
 
+    /// `IntervalYM` adds `IntervalYM`, saturating at `IntervalYM::MIN` and `IntervalYM::MAX`
+    /// instead of returning an error.
+    #[inline]
+    pub const fn saturating_add_interval_ym(self, interval: IntervalYM) -> IntervalYM {
+        let months = self.months().saturating_add(interval.months());
+        if months < IntervalYM::MIN.months() {
+            IntervalYM::MIN
+        } else if months > IntervalYM::MAX.months() {
+            IntervalYM::MAX
+        } else {
+            unsafe { IntervalYM::from_months_unchecked(months) }
+        }
+    }
+
+    /// `IntervalYM` subtracts `IntervalYM`, saturating at `IntervalYM::MIN` and
+    /// `IntervalYM::MAX` instead of returning an error.
+    #[inline]
+    pub const fn saturating_sub_interval_ym(self, interval: IntervalYM) -> IntervalYM {
+        self.saturating_add_interval_ym(interval.negate())
+    }
+
     /// `IntervalYM` multiplies `f64`
     #[inline]
     pub fn mul_f64(self, number: f64) -> Result<IntervalYM> {
@@ -192,6 +222,88 @@ impl IntervalYM {
             IntervalYM::try_from_months(result as i32)
         }
     }
+
+    /// Gets the interval as a fractional number of months.
+    ///
+    /// Every `IntervalYM` is exactly representable.
+    #[inline]
+    pub fn as_months_f64(self) -> f64 {
+        self.months() as f64
+    }
+
+    /// Gets the interval as a fractional number of years, e.g. `1.5` for 18 months.
+    #[inline]
+    pub fn as_years_f64(self) -> f64 {
+        self.months() as f64 / MONTHS_PER_YEAR as f64
+    }
+
+    /// `IntervalYM` divides `IntervalYM`, returns the integer quotient and the remaining interval.
+    ///
+    /// The quotient is truncated toward zero, so the remainder has the same sign as `self`.
+    #[inline]
+    pub const fn div_rem(self, other: IntervalYM) -> Result<(i32, IntervalYM)> {
+        if other.months() == 0 {
+            return Err(Error::DivideByZero);
+        }
+        let quotient = self.months() / other.months();
+        let remainder = self.months() % other.months();
+        Ok((quotient, unsafe {
+            IntervalYM::from_months_unchecked(remainder)
+        }))
+    }
+
+    /// Formats `IntervalYM` as a compact human-readable string, e.g. `1y 6mo`.
+    ///
+    /// Zero-valued components are omitted, a negative interval is prefixed with `-`,
+    /// and the zero interval is formatted as `0mo`.
+    #[inline]
+    pub fn to_compact_string(self) -> Result<String> {
+        try_format!("{}", CompactIntervalYM(self))
+    }
+
+    /// Parses `IntervalYM` from a humanized string of unit-suffixed components, e.g. `18mo` or
+    /// `1y6mo`, as formatted by [`to_compact_string`].
+    ///
+    /// The units are `y` and `mo`, components are added up and can be separated by whitespaces,
+    /// and a leading `-` negates the whole interval. Units of `IntervalDT` are rejected.
+    ///
+    /// [`to_compact_string`]: IntervalYM::to_compact_string
+    #[inline]
+    pub fn parse_humanized<S: AsRef<str>>(input: S) -> Result<Self> {
+        let mut months = 0i64;
+        let negative = parse_humanized(input.as_ref(), |number, fraction, unit| {
+            let factor = match unit {
+                "y" => MONTHS_PER_YEAR as i64,
+                "mo" => 1,
+                "d" | "h" | "m" | "s" => {
+                    return Err(Error::ParseError(try_format!(
+                        "the unit {} cannot be used in IntervalYM",
+                        unit
+                    )?))
+                }
+                _ => {
+                    return Err(Error::ParseError(try_format!(
+                        "unknown interval unit {}",
+                        unit
+                    )?))
+                }
+            };
+            if !fraction.is_empty() {
+                return Err(Error::ParseError(
+                    "the fraction is not allowed in IntervalYM".try_to_string()?,
+                ));
+            }
+            months = i64::try_from(number)
+                .ok()
+                .and_then(|n| n.checked_mul(factor))
+                .and_then(|n| n.checked_add(months))
+                .ok_or(Error::IntervalOutOfRange)?;
+            Ok(())
+        })?;
+
+        let months = i32::try_from(months).map_err(|_| Error::IntervalOutOfRange)?;
+        IntervalYM::try_from_months(if negative { -months } else { months })
+    }
 }
 
 impl From<IntervalYM> for NaiveDateTime {
@@ -221,6 +333,19 @@ impl TryFrom<NaiveDateTime> for IntervalYM {
     }
 }
 
+impl fmt::Debug for IntervalYM {
+    /// Shows the decomposed fields, e.g. `IntervalYM { years: 1, months: 6 }`,
+    /// with a leading `negative: true` field for negative intervals.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (sign, year, month) = self.extract();
+        let mut debug = f.debug_struct("IntervalYM");
+        if sign == Negative {
+            debug.field("negative", &true);
+        }
+        debug.field("years", &year).field("months", &month).finish()
+    }
+}
+
 impl Neg for IntervalYM {
     type Output = IntervalYM;
 
@@ -261,6 +386,21 @@ impl DateTime for IntervalYM {
         None
     }
 
+    #[inline(always)]
+    fn microsecond(&self) -> Option<u32> {
+        None
+    }
+
+    #[inline]
+    fn epoch_seconds(&self) -> Option<f64> {
+        const DAYS_PER_YEAR: f64 = 365.25;
+        const DAYS_PER_MONTH: f64 = 30.0;
+
+        let years = (self.months() / MONTHS_PER_YEAR as i32) as f64;
+        let months = (self.months() % MONTHS_PER_YEAR as i32) as f64;
+        Some((years * DAYS_PER_YEAR + months * DAYS_PER_MONTH) * SECONDS_PER_DAY as f64)
+    }
+
     #[inline(always)]
     fn date(&self) -> Option<Date> {
         None
@@ -270,7 +410,7 @@ impl DateTime for IntervalYM {
 /// `Day-Time Interval` represents the duration of a period of time,
 /// has an interval precision that includes DAY, HOUR, MINUTE, SECOND, MICROSECOND.
 #[allow(clippy::upper_case_acronyms)]
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, PartialOrd, Ord)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
 #[repr(transparent)]
 pub struct IntervalDT(i64);
 
@@ -436,6 +576,12 @@ impl IntervalDT {
         Ok(LazyFormat::new(fmt, self))
     }
 
+    /// Formats `IntervalDT` into a new `String` by given formatter, which can be reused across calls.
+    #[inline]
+    pub fn to_string_with(self, fmt: &Formatter) -> Result<String> {
+        fmt.format_to_string(self)
+    }
+
     /// Parses `IntervalDT` from given string and format.
     #[inline]
     pub fn parse<S1: AsRef<str>, S2: AsRef<str>>(input: S1, fmt: S2) -> Result<Self> {
@@ -464,6 +610,90 @@ impl IntervalDT {
         self.add_interval_dt(interval.negate())
     }
 
+    /// `IntervalDT` adds `IntervalDT`, saturating at `IntervalDT::MIN` and `IntervalDT::MAX`
+    /// instead of returning an error.
+    #[inline]
+    pub const fn saturating_add_interval_dt(self, interval: IntervalDT) -> IntervalDT {
+        let usecs = self.usecs().saturating_add(interval.usecs());
+        if usecs < IntervalDT::MIN.usecs() {
+            IntervalDT::MIN
+        } else if usecs > IntervalDT::MAX.usecs() {
+            IntervalDT::MAX
+        } else {
+            unsafe { IntervalDT::from_usecs_unchecked(usecs) }
+        }
+    }
+
+    /// `IntervalDT` subtracts `IntervalDT`, saturating at `IntervalDT::MIN` and
+    /// `IntervalDT::MAX` instead of returning an error.
+    #[inline]
+    pub const fn saturating_sub_interval_dt(self, interval: IntervalDT) -> IntervalDT {
+        self.saturating_add_interval_dt(interval.negate())
+    }
+
+    /// Shifts every timestamp in the slice by `IntervalDT` in place, e.g. to move a whole series
+    /// by one hour.
+    ///
+    /// The valid range is computed once instead of checking each result. All timestamps are
+    /// checked before any is shifted, so on error the slice is unchanged, and the index of the
+    /// first timestamp out of range is returned with `DateUnderflow` or `DateOverflow` as in
+    /// [`Timestamp::add_interval_dt`].
+    #[inline]
+    pub fn apply_to_slice(
+        self,
+        timestamps: &mut [Timestamp],
+    ) -> std::result::Result<(), (usize, Error)> {
+        let shift = self.usecs();
+        let lower = TIMESTAMP_MIN.saturating_sub(shift);
+        let upper = TIMESTAMP_MAX.saturating_sub(shift);
+
+        for (index, ts) in timestamps.iter().enumerate() {
+            if ts.usecs() < lower {
+                return Err((index, Error::DateUnderflow));
+            }
+            if ts.usecs() > upper {
+                return Err((index, Error::DateOverflow));
+            }
+        }
+
+        for ts in timestamps.iter_mut() {
+            *ts = unsafe { Timestamp::from_usecs_unchecked(ts.usecs() + shift) };
+        }
+        Ok(())
+    }
+
+    /// Returns a new vector with every timestamp in the slice shifted by `IntervalDT`, leaving
+    /// the input untouched. It is the non-mutating variant of [`IntervalDT::apply_to_slice`].
+    ///
+    /// The result vector is reserved up front, and the index of the first timestamp out of range
+    /// is returned with `DateUnderflow` or `DateOverflow` as in [`Timestamp::add_interval_dt`].
+    /// A failed reservation is returned as `TryReserveError` with index 0.
+    #[inline]
+    pub fn shift_all(
+        self,
+        timestamps: &[Timestamp],
+    ) -> std::result::Result<Vec<Timestamp>, (usize, Error)> {
+        let shift = self.usecs();
+        let lower = TIMESTAMP_MIN.saturating_sub(shift);
+        let upper = TIMESTAMP_MAX.saturating_sub(shift);
+
+        let mut result = Vec::new();
+        result
+            .try_reserve_exact(timestamps.len())
+            .map_err(|e| (0, e.into()))?;
+
+        for (index, ts) in timestamps.iter().enumerate() {
+            if ts.usecs() < lower {
+                return Err((index, Error::DateUnderflow));
+            }
+            if ts.usecs() > upper {
+                return Err((index, Error::DateOverflow));
+            }
+            result.push(unsafe { Timestamp::from_usecs_unchecked(ts.usecs() + shift) });
+        }
+        Ok(result)
+    }
+
     /// `IntervalDT` multiplies `f64`
     #[inline]
     pub fn mul_f64(self, number: f64) -> Result<IntervalDT> {
@@ -497,11 +727,123 @@ impl IntervalDT {
         }
     }
 
+    /// Gets the interval as a fractional number of days, e.g. `1.5` for 36 hours.
+    ///
+    /// The result is the nearest `f64`, which keeps microsecond precision for intervals of
+    /// up to about 104 days.
+    #[inline]
+    pub fn as_days_f64(self) -> f64 {
+        self.usecs() as f64 / USECONDS_PER_DAY as f64
+    }
+
+    /// Gets the interval as a fractional number of hours, see [`IntervalDT::as_days_f64`] for
+    /// precision.
+    #[inline]
+    pub fn as_hours_f64(self) -> f64 {
+        self.usecs() as f64 / USECONDS_PER_HOUR as f64
+    }
+
+    /// Gets the interval as a fractional number of minutes, see [`IntervalDT::as_days_f64`] for
+    /// precision.
+    #[inline]
+    pub fn as_minutes_f64(self) -> f64 {
+        self.usecs() as f64 / USECONDS_PER_MINUTE as f64
+    }
+
+    /// Gets the interval as a fractional number of seconds, see [`IntervalDT::as_days_f64`] for
+    /// precision.
+    #[inline]
+    pub fn as_seconds_f64(self) -> f64 {
+        self.usecs() as f64 / USECONDS_PER_SECOND as f64
+    }
+
+    /// `IntervalDT` divides `IntervalDT`, returns the integer quotient and the remaining interval.
+    ///
+    /// The quotient is truncated toward zero, so the remainder has the same sign as `self`.
+    #[inline]
+    pub const fn div_rem(self, other: IntervalDT) -> Result<(i64, IntervalDT)> {
+        if other.usecs() == 0 {
+            return Err(Error::DivideByZero);
+        }
+        let quotient = self.usecs() / other.usecs();
+        let remainder = self.usecs() % other.usecs();
+        Ok((quotient, unsafe {
+            IntervalDT::from_usecs_unchecked(remainder)
+        }))
+    }
+
     /// `IntervalDT` subtracts `Time`
     #[inline]
     pub const fn sub_time(self, time: Time) -> Result<IntervalDT> {
         IntervalDT::try_from_usecs(self.usecs() - time.usecs())
     }
+
+    /// Formats `IntervalDT` as a compact human-readable string, e.g. `2d 3h`.
+    ///
+    /// Zero-valued components are omitted, microseconds are shown as the fractional part of seconds,
+    /// a negative interval is prefixed with `-`, and the zero interval is formatted as `0s`.
+    #[inline]
+    pub fn to_compact_string(self) -> Result<String> {
+        try_format!("{}", CompactIntervalDT(self))
+    }
+
+    /// Parses `IntervalDT` from a humanized string of unit-suffixed components, e.g. `90s`,
+    /// `2h30m` or `1d 12h`, as formatted by [`to_compact_string`].
+    ///
+    /// The units are `d`, `h`, `m` and `s`, each may have a fraction, e.g. `1.5h`, which is
+    /// truncated to microseconds. Components are added up and can be separated by whitespaces,
+    /// and a leading `-` negates the whole interval. Units of `IntervalYM` are rejected.
+    ///
+    /// [`to_compact_string`]: IntervalDT::to_compact_string
+    #[inline]
+    pub fn parse_humanized<S: AsRef<str>>(input: S) -> Result<Self> {
+        let mut usecs = 0i128;
+        let negative = parse_humanized(input.as_ref(), |number, fraction, unit| {
+            let factor = match unit {
+                "d" => USECONDS_PER_DAY,
+                "h" => USECONDS_PER_HOUR,
+                "m" => USECONDS_PER_MINUTE,
+                "s" => USECONDS_PER_SECOND,
+                "y" | "mo" => {
+                    return Err(Error::ParseError(try_format!(
+                        "the unit {} cannot be used in IntervalDT",
+                        unit
+                    )?))
+                }
+                _ => {
+                    return Err(Error::ParseError(try_format!(
+                        "unknown interval unit {}",
+                        unit
+                    )?))
+                }
+            } as i128;
+            // The digits beyond 12 are less than a microsecond even for days, and are dropped
+            let fraction = &fraction[..fraction.len().min(12)];
+            let fraction_usecs = fraction
+                .iter()
+                .fold(0i128, |n, &ch| n * 10 + (ch - b'0') as i128)
+                * factor
+                / 10i128.pow(fraction.len() as u32);
+            usecs = (number as i128)
+                .checked_mul(factor)
+                .and_then(|n| n.checked_add(fraction_usecs))
+                .and_then(|n| n.checked_add(usecs))
+                .ok_or(Error::IntervalOutOfRange)?;
+            Ok(())
+        })?;
+
+        let usecs = i64::try_from(usecs).map_err(|_| Error::IntervalOutOfRange)?;
+        IntervalDT::try_from_usecs(if negative { -usecs } else { usecs })
+    }
+
+    /// Formats `IntervalDT` as total hours, minutes and seconds, e.g. `51:00:00`.
+    ///
+    /// Unlike the day-based formats, days are counted into hours, like the `[h]:mm:ss` format of
+    /// spreadsheets. The fraction of second is only written if it is not zero.
+    #[inline]
+    pub fn to_hms_total_string(self) -> Result<String> {
+        try_format!("{}", TotalHoursIntervalDT(self))
+    }
 }
 
 impl From<IntervalDT> for NaiveDateTime {
@@ -555,6 +897,26 @@ impl PartialOrd<Time> for IntervalDT {
     }
 }
 
+impl fmt::Debug for IntervalDT {
+    /// Shows the decomposed fields, e.g.
+    /// `IntervalDT { days: 1, hours: 0, minutes: 0, seconds: 0, micros: 0 }`,
+    /// with a leading `negative: true` field for negative intervals.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (sign, day, hour, minute, sec, usec) = self.extract();
+        let mut debug = f.debug_struct("IntervalDT");
+        if sign == Negative {
+            debug.field("negative", &true);
+        }
+        debug
+            .field("days", &day)
+            .field("hours", &hour)
+            .field("minutes", &minute)
+            .field("seconds", &sec)
+            .field("micros", &usec)
+            .finish()
+    }
+}
+
 impl Neg for IntervalDT {
     type Output = IntervalDT;
 
@@ -598,12 +960,152 @@ impl DateTime for IntervalDT {
         Some(remain_time as f64 / USECONDS_PER_SECOND as f64)
     }
 
+    #[inline]
+    fn microsecond(&self) -> Option<u32> {
+        Some((self.usecs() % USECONDS_PER_SECOND).unsigned_abs() as u32)
+    }
+
+    #[inline]
+    fn epoch_seconds(&self) -> Option<f64> {
+        Some(self.usecs() as f64 / USECONDS_PER_SECOND as f64)
+    }
+
     #[inline(always)]
     fn date(&self) -> Option<Date> {
         None
     }
 }
 
+/// Splits a humanized interval, e.g. `-1d 12h`, into components of a whole number, the digits
+/// of an optional fraction, and the unit, which are passed to `component`. Returns whether the
+/// interval is negative.
+fn parse_humanized<'a, F>(input: &'a str, mut component: F) -> Result<bool>
+where
+    F: FnMut(u64, &'a [u8], &'a str) -> Result<()>,
+{
+    let invalid = || -> Result<bool> {
+        Err(Error::ParseError(try_format!(
+            "the input {} is not a valid humanized interval",
+            input
+        )?))
+    };
+
+    let s = input.trim();
+    let (negative, mut s) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    if s.is_empty() {
+        return invalid();
+    }
+
+    while !s.is_empty() {
+        let len = s.bytes().take_while(|ch| ch.is_ascii_digit()).count();
+        if len == 0 {
+            return invalid();
+        }
+        let mut number = 0u64;
+        for ch in s[..len].bytes() {
+            number = number
+                .checked_mul(10)
+                .and_then(|n| n.checked_add((ch - b'0') as u64))
+                .ok_or(Error::IntervalOutOfRange)?;
+        }
+        s = &s[len..];
+
+        let mut fraction: &[u8] = &[];
+        if let Some(rem) = s.strip_prefix('.') {
+            let len = rem.bytes().take_while(|ch| ch.is_ascii_digit()).count();
+            if len == 0 {
+                return invalid();
+            }
+            fraction = &rem.as_bytes()[..len];
+            s = &rem[len..];
+        }
+
+        let len = s.bytes().take_while(|ch| ch.is_ascii_alphabetic()).count();
+        if len == 0 {
+            return invalid();
+        }
+        component(number, fraction, &s[..len])?;
+        s = s[len..].trim_start();
+    }
+    Ok(negative)
+}
+
+struct CompactIntervalYM(IntervalYM);
+
+impl fmt::Display for CompactIntervalYM {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (sign, year, month) = self.0.extract();
+        if sign == Negative {
+            f.write_str("-")?;
+        }
+
+        let mut sep = "";
+        if year != 0 {
+            write!(f, "{}y", year)?;
+            sep = " ";
+        }
+        if month != 0 || year == 0 {
+            write!(f, "{}{}mo", sep, month)?;
+        }
+        Ok(())
+    }
+}
+
+struct CompactIntervalDT(IntervalDT);
+
+impl fmt::Display for CompactIntervalDT {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (sign, day, hour, minute, sec, usec) = self.0.extract();
+        if sign == Negative {
+            f.write_str("-")?;
+        }
+
+        let mut sep = "";
+        for (value, unit) in [(day, "d"), (hour, "h"), (minute, "m")] {
+            if value != 0 {
+                write!(f, "{}{}{}", sep, value, unit)?;
+                sep = " ";
+            }
+        }
+
+        if usec != 0 {
+            // Trims the trailing zeros of fraction
+            let mut fraction = usec;
+            let mut width = 6;
+            while fraction % 10 == 0 {
+                fraction /= 10;
+                width -= 1;
+            }
+            write!(f, "{}{}.{:0width$}s", sep, sec, fraction, width = width)?;
+        } else if sec != 0 || sep.is_empty() {
+            write!(f, "{}{}s", sep, sec)?;
+        }
+        Ok(())
+    }
+}
+
+struct TotalHoursIntervalDT(IntervalDT);
+
+impl fmt::Display for TotalHoursIntervalDT {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (sign, day, hour, minute, sec, usec) = self.0.extract();
+        if sign == Negative {
+            f.write_str("-")?;
+        }
+
+        let hours = day as u64 * HOURS_PER_DAY as u64 + hour as u64;
+        write!(f, "{:02}:{:02}:{:02}", hours, minute, sec)?;
+        if usec != 0 {
+            write!(f, ".{:06}", usec)?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -816,6 +1318,108 @@ mod tests {
         assert!(IntervalDT::parse("19", "mm").is_err());
     }
 
+    #[test]
+    fn test_interval_parse_partial_fields() {
+        let dhms = |d, h, m, s, u| IntervalDT::try_from_dhms(d, h, m, s, u).unwrap();
+
+        // Missing leading day defaults to zero
+        assert_eq!(
+            IntervalDT::parse("03:04", "hh24:mi").unwrap(),
+            dhms(0, 3, 4, 0, 0)
+        );
+        assert_eq!(
+            IntervalDT::parse("04:05:06", "HH24:MI:SS").unwrap(),
+            dhms(0, 4, 5, 6, 0)
+        );
+        assert_eq!(IntervalDT::parse("59", "MI").unwrap(), dhms(0, 0, 59, 0, 0));
+        assert_eq!(
+            IntervalDT::parse("05.123", "SS.FF").unwrap(),
+            dhms(0, 0, 0, 5, 123000)
+        );
+        assert_eq!(
+            IntervalDT::parse("3 04:05", "dd hh24:mi").unwrap(),
+            dhms(3, 4, 5, 0, 0)
+        );
+        assert_eq!(IntervalDT::parse("3", "DD").unwrap(), dhms(3, 0, 0, 0, 0));
+
+        // The leading hour carries the sign
+        assert_eq!(
+            IntervalDT::parse("-03:04", "hh24:mi").unwrap(),
+            -dhms(0, 3, 4, 0, 0)
+        );
+        assert_eq!(
+            IntervalDT::parse("-00:05", "HH24:MI").unwrap(),
+            -dhms(0, 0, 5, 0, 0)
+        );
+        assert_eq!(
+            IntervalDT::parse("+03:04", "HH24:MI").unwrap(),
+            dhms(0, 3, 4, 0, 0)
+        );
+        assert!(IntervalDT::parse("3 -04:05", "DD HH24:MI").is_err());
+        assert!(IntervalDT::parse("03:-04", "HH24:MI").is_err());
+        assert!(IntervalDT::parse("24:00", "HH24:MI").is_err());
+        assert!(IntervalDT::parse("03:60", "HH24:MI").is_err());
+
+        // Missing leading year defaults to zero
+        assert_eq!(
+            IntervalYM::parse("05", "MM").unwrap(),
+            IntervalYM::try_from_ym(0, 5).unwrap()
+        );
+        assert_eq!(
+            IntervalYM::parse("3", "YYYY").unwrap(),
+            IntervalYM::try_from_ym(3, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_interval_dt_hash_consistent_with_eq() {
+        use crate::util::testing::hash_of;
+
+        for time in [
+            Time::ZERO,
+            Time::try_from_hms(12, 30, 5, 1).unwrap(),
+            Time::MAX,
+        ] {
+            let interval = IntervalDT::try_from_usecs(time.usecs()).unwrap();
+            assert_eq!(interval, time);
+            assert_eq!(time, interval);
+            assert_eq!(hash_of(&interval), hash_of(&time));
+        }
+    }
+
+    #[test]
+    fn test_interval_debug() {
+        assert_eq!(
+            format!("{:?}", IntervalYM::try_from_ym(1, 6).unwrap()),
+            "IntervalYM { years: 1, months: 6 }"
+        );
+        assert_eq!(
+            format!("{:?}", -IntervalYM::try_from_ym(0, 3).unwrap()),
+            "IntervalYM { negative: true, years: 0, months: 3 }"
+        );
+        assert_eq!(
+            format!("{:?}", IntervalYM::ZERO),
+            "IntervalYM { years: 0, months: 0 }"
+        );
+
+        assert_eq!(
+            format!("{:?}", IntervalDT::try_from_dhms(1, 0, 0, 0, 0).unwrap()),
+            "IntervalDT { days: 1, hours: 0, minutes: 0, seconds: 0, micros: 0 }"
+        );
+        assert_eq!(
+            format!("{:?}", -IntervalDT::try_from_dhms(0, 5, 4, 3, 2).unwrap()),
+            "IntervalDT { negative: true, days: 0, hours: 5, minutes: 4, seconds: 3, micros: 2 }"
+        );
+        assert_eq!(
+            format!("{:#?}", IntervalDT::try_from_dhms(2, 3, 0, 0, 0).unwrap()),
+            "IntervalDT {\n    days: 2,\n    hours: 3,\n    minutes: 0,\n    seconds: 0,\n    micros: 0,\n}"
+        );
+        assert_eq!(
+            format!("{:?}", Some(IntervalDT::ZERO)),
+            "Some(IntervalDT { days: 0, hours: 0, minutes: 0, seconds: 0, micros: 0 })"
+        );
+    }
+
     #[test]
     fn test_interval_negate() {
         assert_eq!(
@@ -1162,6 +1766,45 @@ mod tests {
             .is_err());
     }
 
+    #[test]
+    fn test_interval_div_rem() {
+        let ym = |months| IntervalYM::try_from_months(months).unwrap();
+        assert_eq!(ym(30).div_rem(ym(12)).unwrap(), (2, ym(6)));
+        assert_eq!(ym(24).div_rem(ym(12)).unwrap(), (2, ym(0)));
+        assert_eq!(ym(5).div_rem(ym(12)).unwrap(), (0, ym(5)));
+        assert_eq!(ym(-30).div_rem(ym(12)).unwrap(), (-2, ym(-6)));
+        assert_eq!(ym(30).div_rem(ym(-12)).unwrap(), (-2, ym(6)));
+        assert_eq!(ym(0).div_rem(ym(1)).unwrap(), (0, ym(0)));
+        assert_eq!(
+            IntervalYM::MAX.div_rem(ym(1)).unwrap(),
+            (IntervalYM::MAX.months(), ym(0))
+        );
+        assert_eq!(ym(30).div_rem(ym(0)).unwrap_err(), Error::DivideByZero);
+
+        let days = |d| IntervalDT::try_from_dhms(d, 0, 0, 0, 0).unwrap();
+        assert_eq!(days(10).div_rem(days(3)).unwrap(), (3, days(1)));
+        assert_eq!(days(21).div_rem(days(7)).unwrap(), (3, days(0)));
+        assert_eq!(
+            days(10).negate().div_rem(days(3)).unwrap(),
+            (-3, days(1).negate())
+        );
+        assert_eq!(days(10).div_rem(days(3).negate()).unwrap(), (-3, days(1)));
+        assert_eq!(
+            IntervalDT::try_from_dhms(1, 2, 30, 0, 0)
+                .unwrap()
+                .div_rem(IntervalDT::try_from_dhms(0, 1, 0, 0, 0).unwrap())
+                .unwrap(),
+            (26, IntervalDT::try_from_dhms(0, 0, 30, 0, 0).unwrap())
+        );
+        assert_eq!(
+            IntervalDT::MIN
+                .div_rem(IntervalDT::try_from_usecs(1).unwrap())
+                .unwrap(),
+            (IntervalDT::MIN.usecs(), days(0))
+        );
+        assert_eq!(days(10).div_rem(days(0)).unwrap_err(), Error::DivideByZero);
+    }
+
     #[test]
     fn test_interval_dt_sub_time() {
         // Out of range
@@ -1199,6 +1842,12 @@ mod tests {
         assert!(interval.day().is_none());
         assert!(interval.minute().is_none());
         assert!(interval.second().is_none());
+        assert!(interval.millisecond().is_none());
+        assert!(interval.microsecond().is_none());
+        assert_eq!(
+            modifier as f64 * (year as f64 * 365.25 + month as f64 * 30.0) * 86400.0,
+            interval.epoch_seconds().unwrap()
+        );
     }
 
     #[test]
@@ -1233,6 +1882,12 @@ mod tests {
             modifier as f64 * (sec as f64 + (usec as f64) / 1_000_000f64),
             interval.second().unwrap()
         );
+        assert_eq!(usec / 1000, interval.millisecond().unwrap());
+        assert_eq!(usec, interval.microsecond().unwrap());
+        assert_eq!(
+            interval.usecs() as f64 / 1_000_000f64,
+            interval.epoch_seconds().unwrap()
+        );
         assert!(interval.year().is_none());
         assert!(interval.month().is_none());
     }
@@ -1248,6 +1903,596 @@ mod tests {
         test_extract_dt(true, 1, 0, 0, 0, 1);
         test_extract_dt(true, 9999, 23, 59, 59, 999999);
         test_extract_dt(true, 9999, 23, 59, 59, 375473);
+        test_extract_dt(true, 0, 0, 0, 1, 123456);
         test_extract_dt(true, 100000000, 0, 0, 0, 0);
     }
+
+    #[test]
+    fn test_interval_to_string_with() {
+        let interval = -IntervalYM::try_from_ym(1, 6).unwrap();
+        let formatter = Formatter::try_new("YYYY-MM").unwrap();
+        assert_eq!(
+            interval.to_string_with(&formatter).unwrap(),
+            interval.format("YYYY-MM").unwrap().to_string()
+        );
+        assert_eq!(interval.to_string_with(&formatter).unwrap(), "-0001-06");
+
+        let interval = IntervalDT::try_from_dhms(3, 4, 5, 6, 789).unwrap();
+        let formatter = Formatter::try_new("DD HH24:MI:SS.FF").unwrap();
+        assert_eq!(
+            interval.to_string_with(&formatter).unwrap(),
+            interval.format("DD HH24:MI:SS.FF").unwrap().to_string()
+        );
+        assert_eq!(
+            interval.to_string_with(&formatter).unwrap(),
+            "+03 04:05:06.000789"
+        );
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_interval_as_f64() {
+        let interval = IntervalYM::try_from_ym(1, 6).unwrap();
+        assert_eq!(interval.as_months_f64(), 18.0);
+        assert_eq!(interval.as_years_f64(), 1.5);
+        assert_eq!((-interval).as_years_f64(), -1.5);
+        assert_eq!(IntervalYM::ZERO.as_years_f64(), 0.0);
+        assert_eq!(
+            IntervalYM::try_from_ym(0, 1).unwrap().as_years_f64(),
+            1.0 / 12.0
+        );
+        assert_eq!(IntervalYM::MAX.as_years_f64(), 178000000.0);
+        assert_eq!(IntervalYM::MIN.as_months_f64(), -2136000000.0);
+
+        let interval = IntervalDT::try_from_dhms(1, 12, 0, 0, 0).unwrap();
+        assert_eq!(interval.as_days_f64(), 1.5);
+        assert_eq!(interval.as_hours_f64(), 36.0);
+        assert_eq!(interval.as_minutes_f64(), 2160.0);
+        assert_eq!(interval.as_seconds_f64(), 129600.0);
+        assert_eq!((-interval).as_days_f64(), -1.5);
+
+        let interval = IntervalDT::try_from_dhms(0, 0, 1, 30, 500000).unwrap();
+        assert_eq!(interval.as_minutes_f64(), 1.5083333333333333);
+        assert_eq!(interval.as_seconds_f64(), 90.5);
+        assert_eq!(
+            IntervalDT::try_from_dhms(0, 0, 0, 0, 1)
+                .unwrap()
+                .as_seconds_f64(),
+            0.000001
+        );
+        assert_eq!(IntervalDT::ZERO.as_days_f64(), 0.0);
+        assert_eq!(IntervalDT::MAX.as_days_f64(), 100000000.0);
+        assert_eq!(IntervalDT::MIN.as_hours_f64(), -2400000000.0);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_interval_epoch_seconds() {
+        assert_eq!(IntervalYM::ZERO.epoch_seconds().unwrap(), 0.0);
+        assert_eq!(
+            IntervalYM::try_from_ym(1, 0)
+                .unwrap()
+                .epoch_seconds()
+                .unwrap(),
+            31557600.0
+        );
+        assert_eq!(
+            IntervalYM::try_from_ym(0, 1)
+                .unwrap()
+                .epoch_seconds()
+                .unwrap(),
+            2592000.0
+        );
+        assert_eq!(
+            (-IntervalYM::try_from_ym(1, 1).unwrap())
+                .epoch_seconds()
+                .unwrap(),
+            -34149600.0
+        );
+
+        assert_eq!(IntervalDT::ZERO.epoch_seconds().unwrap(), 0.0);
+        assert_eq!(
+            IntervalDT::try_from_dhms(1, 0, 0, 1, 500000)
+                .unwrap()
+                .epoch_seconds()
+                .unwrap(),
+            86401.5
+        );
+        assert_eq!(
+            IntervalDT::try_from_usecs(-1)
+                .unwrap()
+                .epoch_seconds()
+                .unwrap(),
+            -0.000001
+        );
+    }
+
+    #[test]
+    fn test_interval_bounds() {
+        // IntervalYM
+        assert_eq!(
+            IntervalYM::MAX,
+            IntervalYM::try_from_ym(178000000, 0).unwrap()
+        );
+        assert_eq!(IntervalYM::MIN, -IntervalYM::MAX);
+        assert_eq!(IntervalYM::ZERO, IntervalYM::try_from_months(0).unwrap());
+        assert_eq!(IntervalYM::MAX.months(), INTERVAL_MAX_MONTH);
+        assert_eq!(IntervalYM::MIN.months(), -INTERVAL_MAX_MONTH);
+
+        let one_month = IntervalYM::try_from_months(1).unwrap();
+        assert_eq!(
+            IntervalYM::MAX.add_interval_ym(one_month).unwrap_err(),
+            Error::IntervalOutOfRange
+        );
+        assert_eq!(
+            IntervalYM::MIN.sub_interval_ym(one_month).unwrap_err(),
+            Error::IntervalOutOfRange
+        );
+        assert_eq!(
+            IntervalYM::MAX.add_interval_ym(-one_month).unwrap(),
+            IntervalYM::try_from_ym(177999999, 11).unwrap()
+        );
+        assert_eq!(
+            IntervalYM::ZERO.add_interval_ym(IntervalYM::MAX).unwrap(),
+            IntervalYM::MAX
+        );
+        assert!(IntervalYM::try_from_months(INTERVAL_MAX_MONTH + 1).is_err());
+        assert!(IntervalYM::try_from_months(-INTERVAL_MAX_MONTH - 1).is_err());
+
+        // IntervalDT
+        assert_eq!(
+            IntervalDT::MAX,
+            IntervalDT::try_from_dhms(100000000, 0, 0, 0, 0).unwrap()
+        );
+        assert_eq!(IntervalDT::MIN, -IntervalDT::MAX);
+        assert_eq!(IntervalDT::ZERO, IntervalDT::try_from_usecs(0).unwrap());
+        assert_eq!(IntervalDT::MAX.usecs(), INTERVAL_MAX_USECONDS);
+        assert_eq!(IntervalDT::MIN.usecs(), -INTERVAL_MAX_USECONDS);
+
+        let one_usec = IntervalDT::try_from_usecs(1).unwrap();
+        assert_eq!(
+            IntervalDT::MAX.add_interval_dt(one_usec).unwrap_err(),
+            Error::IntervalOutOfRange
+        );
+        assert_eq!(
+            IntervalDT::MIN.sub_interval_dt(one_usec).unwrap_err(),
+            Error::IntervalOutOfRange
+        );
+        assert_eq!(
+            IntervalDT::MAX.sub_interval_dt(one_usec).unwrap(),
+            IntervalDT::try_from_dhms(99999999, 23, 59, 59, 999999).unwrap()
+        );
+        assert_eq!(
+            IntervalDT::ZERO.add_interval_dt(IntervalDT::MIN).unwrap(),
+            IntervalDT::MIN
+        );
+        assert!(IntervalDT::try_from_usecs(INTERVAL_MAX_USECONDS + 1).is_err());
+        assert!(IntervalDT::try_from_usecs(-INTERVAL_MAX_USECONDS - 1).is_err());
+    }
+
+    #[test]
+    fn test_interval_to_hms_total_string() {
+        let dhms = |d, h, m, s, u| IntervalDT::try_from_dhms(d, h, m, s, u).unwrap();
+
+        assert_eq!(IntervalDT::ZERO.to_hms_total_string().unwrap(), "00:00:00");
+        assert_eq!(
+            dhms(2, 3, 0, 0, 0).to_hms_total_string().unwrap(),
+            "51:00:00"
+        );
+        assert_eq!(
+            dhms(2, 2, 30, 0, 0).to_hms_total_string().unwrap(),
+            "50:30:00"
+        );
+        assert_eq!(
+            dhms(0, 1, 2, 3, 0).to_hms_total_string().unwrap(),
+            "01:02:03"
+        );
+        assert_eq!(
+            dhms(0, 0, 0, 5, 1000).to_hms_total_string().unwrap(),
+            "00:00:05.001000"
+        );
+        assert_eq!(
+            (-dhms(1, 0, 0, 1, 0)).to_hms_total_string().unwrap(),
+            "-24:00:01"
+        );
+        assert_eq!(
+            IntervalDT::MAX.to_hms_total_string().unwrap(),
+            "2400000000:00:00"
+        );
+        assert_eq!(
+            IntervalDT::MIN.to_hms_total_string().unwrap(),
+            "-2400000000:00:00"
+        );
+    }
+
+    #[test]
+    fn test_interval_saturating_and_clamp() {
+        // IntervalYM
+        let one_year = IntervalYM::try_from_ym(1, 0).unwrap();
+        assert_eq!(
+            IntervalYM::MAX.saturating_add_interval_ym(one_year),
+            IntervalYM::MAX
+        );
+        assert_eq!(
+            IntervalYM::MIN.saturating_sub_interval_ym(one_year),
+            IntervalYM::MIN
+        );
+        assert_eq!(
+            IntervalYM::MAX.saturating_add_interval_ym(IntervalYM::MAX),
+            IntervalYM::MAX
+        );
+        assert_eq!(
+            IntervalYM::MIN.saturating_add_interval_ym(IntervalYM::MAX),
+            IntervalYM::ZERO
+        );
+        assert_eq!(
+            one_year.saturating_add_interval_ym(one_year),
+            IntervalYM::try_from_ym(2, 0).unwrap()
+        );
+        let mut total = IntervalYM::ZERO;
+        for _ in 0..3 {
+            total =
+                total.saturating_add_interval_ym(IntervalYM::try_from_ym(100000000, 0).unwrap());
+        }
+        assert_eq!(total, IntervalYM::MAX);
+
+        // Clamped by `Ord::clamp`
+        let min = IntervalYM::try_from_ym(1, 0).unwrap();
+        let max = IntervalYM::try_from_ym(2, 0).unwrap();
+        assert_eq!(IntervalYM::ZERO.clamp(min, max), min);
+        assert_eq!(IntervalYM::MAX.clamp(min, max), max);
+        let mid = IntervalYM::try_from_ym(1, 6).unwrap();
+        assert_eq!(mid.clamp(min, max), mid);
+        assert_eq!(mid.clamp(mid, mid), mid);
+
+        // IntervalDT
+        let one_hour = IntervalDT::try_from_dhms(0, 1, 0, 0, 0).unwrap();
+        assert_eq!(
+            IntervalDT::MAX.saturating_add_interval_dt(one_hour),
+            IntervalDT::MAX
+        );
+        assert_eq!(
+            IntervalDT::MIN.saturating_sub_interval_dt(one_hour),
+            IntervalDT::MIN
+        );
+        assert_eq!(
+            IntervalDT::MIN.saturating_add_interval_dt(IntervalDT::MIN),
+            IntervalDT::MIN
+        );
+        assert_eq!(
+            one_hour.saturating_sub_interval_dt(one_hour),
+            IntervalDT::ZERO
+        );
+
+        // Cap total overtime at 999 hours
+        let cap = IntervalDT::try_from_dhms(41, 15, 0, 0, 0).unwrap();
+        let mut overtime = IntervalDT::ZERO;
+        for _ in 0..1000 {
+            overtime = overtime
+                .saturating_add_interval_dt(one_hour)
+                .clamp(IntervalDT::ZERO, cap);
+        }
+        assert_eq!(overtime, cap);
+        let mut total = IntervalDT::ZERO;
+        for _ in 0..3 {
+            total = total.saturating_add_interval_dt(
+                IntervalDT::try_from_dhms(50000000, 0, 0, 0, 0).unwrap(),
+            );
+        }
+        assert_eq!(total, IntervalDT::MAX);
+        assert_eq!(
+            IntervalDT::MIN.clamp(IntervalDT::ZERO, cap),
+            IntervalDT::ZERO
+        );
+    }
+
+    #[test]
+    fn test_interval_dt_apply_to_slice() {
+        let ts = |y, m, d, h| {
+            Timestamp::new(
+                Date::try_from_ymd(y, m, d).unwrap(),
+                Time::try_from_hms(h, 0, 0, 0).unwrap(),
+            )
+        };
+        let one_hour = IntervalDT::try_from_dhms(0, 1, 0, 0, 0).unwrap();
+
+        let mut timestamps = [ts(2021, 6, 1, 0), ts(2021, 6, 1, 23), ts(1969, 12, 31, 23)];
+        one_hour.apply_to_slice(&mut timestamps).unwrap();
+        assert_eq!(
+            timestamps,
+            [ts(2021, 6, 1, 1), ts(2021, 6, 2, 0), ts(1970, 1, 1, 0)]
+        );
+        (-one_hour).apply_to_slice(&mut timestamps).unwrap();
+        assert_eq!(
+            timestamps,
+            [ts(2021, 6, 1, 0), ts(2021, 6, 1, 23), ts(1969, 12, 31, 23)]
+        );
+
+        // Same as shifting each timestamp
+        let original = timestamps;
+        let interval = IntervalDT::try_from_dhms(1234, 5, 6, 7, 890123).unwrap();
+        interval.apply_to_slice(&mut timestamps).unwrap();
+        for (shifted, ts) in timestamps.iter().zip(original.iter()) {
+            assert_eq!(*shifted, ts.add_interval_dt(interval).unwrap());
+        }
+
+        // Empty slice and zero interval
+        IntervalDT::MAX.apply_to_slice(&mut []).unwrap();
+        let mut timestamps = [Timestamp::MIN, Timestamp::MAX];
+        IntervalDT::ZERO.apply_to_slice(&mut timestamps).unwrap();
+        assert_eq!(timestamps, [Timestamp::MIN, Timestamp::MAX]);
+
+        // The first timestamp out of range is reported and nothing is shifted
+        let mut timestamps = [ts(2021, 6, 1, 0), Timestamp::MAX, Timestamp::MAX];
+        assert_eq!(
+            one_hour.apply_to_slice(&mut timestamps).unwrap_err(),
+            (1, Error::DateOverflow)
+        );
+        assert_eq!(
+            timestamps,
+            [ts(2021, 6, 1, 0), Timestamp::MAX, Timestamp::MAX]
+        );
+        let mut timestamps = [ts(2021, 6, 1, 0), Timestamp::MIN];
+        assert_eq!(
+            (-one_hour).apply_to_slice(&mut timestamps).unwrap_err(),
+            (1, Error::DateUnderflow)
+        );
+        assert_eq!(timestamps, [ts(2021, 6, 1, 0), Timestamp::MIN]);
+        let mut timestamps = [Timestamp::MIN, Timestamp::MAX];
+        assert_eq!(
+            IntervalDT::MAX.apply_to_slice(&mut timestamps).unwrap_err(),
+            (0, Error::DateOverflow)
+        );
+        assert_eq!(
+            IntervalDT::MIN.apply_to_slice(&mut timestamps).unwrap_err(),
+            (0, Error::DateUnderflow)
+        );
+    }
+
+    #[test]
+    fn test_interval_dt_shift_all() {
+        let ts = |y, m, d, h| {
+            Timestamp::new(
+                Date::try_from_ymd(y, m, d).unwrap(),
+                Time::try_from_hms(h, 0, 0, 0).unwrap(),
+            )
+        };
+        let one_hour = IntervalDT::try_from_dhms(0, 1, 0, 0, 0).unwrap();
+
+        let timestamps = [ts(2021, 6, 1, 0), ts(2021, 6, 1, 23), ts(1969, 12, 31, 23)];
+        let shifted = one_hour.shift_all(&timestamps).unwrap();
+        assert_eq!(
+            shifted,
+            vec![ts(2021, 6, 1, 1), ts(2021, 6, 2, 0), ts(1970, 1, 1, 0)]
+        );
+        assert_eq!(shifted.capacity(), timestamps.len());
+        assert_eq!(
+            (-one_hour).shift_all(&shifted).unwrap(),
+            timestamps.to_vec()
+        );
+
+        // Same as the in-place shift
+        let interval = IntervalDT::try_from_dhms(1234, 5, 6, 7, 890123).unwrap();
+        let mut in_place = timestamps;
+        interval.apply_to_slice(&mut in_place).unwrap();
+        assert_eq!(interval.shift_all(&timestamps).unwrap(), in_place.to_vec());
+
+        // Empty slice and zero interval
+        assert!(IntervalDT::MAX.shift_all(&[]).unwrap().is_empty());
+        assert_eq!(
+            IntervalDT::ZERO
+                .shift_all(&[Timestamp::MIN, Timestamp::MAX])
+                .unwrap(),
+            vec![Timestamp::MIN, Timestamp::MAX]
+        );
+
+        // The first timestamp out of range is reported
+        assert_eq!(
+            one_hour
+                .shift_all(&[ts(2021, 6, 1, 0), Timestamp::MAX, Timestamp::MAX])
+                .unwrap_err(),
+            (1, Error::DateOverflow)
+        );
+        assert_eq!(
+            (-one_hour)
+                .shift_all(&[ts(2021, 6, 1, 0), Timestamp::MIN])
+                .unwrap_err(),
+            (1, Error::DateUnderflow)
+        );
+        assert_eq!(
+            IntervalDT::MIN
+                .shift_all(&[Timestamp::MIN, Timestamp::MAX])
+                .unwrap_err(),
+            (0, Error::DateUnderflow)
+        );
+    }
+
+    #[test]
+    fn test_interval_parse_humanized() {
+        // IntervalYM
+        let ym = |year, month| IntervalYM::try_from_ym(year, month).unwrap();
+        assert_eq!(IntervalYM::parse_humanized("18mo").unwrap(), ym(1, 6));
+        assert_eq!(IntervalYM::parse_humanized("1y6mo").unwrap(), ym(1, 6));
+        assert_eq!(IntervalYM::parse_humanized(" 1y 6mo ").unwrap(), ym(1, 6));
+        assert_eq!(IntervalYM::parse_humanized("6mo1y").unwrap(), ym(1, 6));
+        assert_eq!(IntervalYM::parse_humanized("1y1y").unwrap(), ym(2, 0));
+        assert_eq!(IntervalYM::parse_humanized("-1y 6mo").unwrap(), -ym(1, 6));
+        assert_eq!(
+            IntervalYM::parse_humanized("+0mo").unwrap(),
+            IntervalYM::ZERO
+        );
+        assert_eq!(
+            IntervalYM::parse_humanized("178000000y").unwrap(),
+            IntervalYM::MAX
+        );
+        assert_eq!(
+            IntervalYM::parse_humanized("178000000y1mo").unwrap_err(),
+            Error::IntervalOutOfRange
+        );
+        assert_eq!(
+            IntervalYM::parse_humanized("99999999999999999999y").unwrap_err(),
+            Error::IntervalOutOfRange
+        );
+        for interval in [ym(0, 0), ym(0, 5), ym(2, 0), -ym(1, 6), IntervalYM::MIN] {
+            assert_eq!(
+                IntervalYM::parse_humanized(interval.to_compact_string().unwrap()).unwrap(),
+                interval
+            );
+        }
+
+        // IntervalDT
+        let dt = |day, hour, minute, sec, usec| {
+            IntervalDT::try_from_dhms(day, hour, minute, sec, usec).unwrap()
+        };
+        assert_eq!(
+            IntervalDT::parse_humanized("90s").unwrap(),
+            dt(0, 0, 1, 30, 0)
+        );
+        assert_eq!(
+            IntervalDT::parse_humanized("90m").unwrap(),
+            dt(0, 1, 30, 0, 0)
+        );
+        assert_eq!(
+            IntervalDT::parse_humanized("2h30m").unwrap(),
+            dt(0, 2, 30, 0, 0)
+        );
+        assert_eq!(
+            IntervalDT::parse_humanized("1d12h").unwrap(),
+            dt(1, 12, 0, 0, 0)
+        );
+        assert_eq!(
+            IntervalDT::parse_humanized("1d 2h 3m 4.5s").unwrap(),
+            dt(1, 2, 3, 4, 500000)
+        );
+        assert_eq!(
+            IntervalDT::parse_humanized("1.5h").unwrap(),
+            dt(0, 1, 30, 0, 0)
+        );
+        assert_eq!(
+            IntervalDT::parse_humanized("0.0000001s").unwrap(),
+            IntervalDT::ZERO
+        );
+        assert_eq!(
+            IntervalDT::parse_humanized("-2h30m").unwrap(),
+            -dt(0, 2, 30, 0, 0)
+        );
+        assert_eq!(
+            IntervalDT::parse_humanized("100000000d").unwrap(),
+            IntervalDT::MAX
+        );
+        assert_eq!(
+            IntervalDT::parse_humanized("100000000d1s").unwrap_err(),
+            Error::IntervalOutOfRange
+        );
+        for interval in [
+            IntervalDT::ZERO,
+            dt(2, 3, 0, 0, 0),
+            dt(0, 0, 4, 5, 0),
+            -dt(1, 0, 0, 1, 500),
+            IntervalDT::MAX,
+        ] {
+            assert_eq!(
+                IntervalDT::parse_humanized(interval.to_compact_string().unwrap()).unwrap(),
+                interval
+            );
+        }
+
+        // Errors
+        for input in ["", "-", "5", "y", "1-6", "1.y", "1 y", "--1", "1_2"] {
+            let expected = Error::ParseError(format!(
+                "the input {} is not a valid humanized interval",
+                input
+            ));
+            assert_eq!(IntervalYM::parse_humanized(input).unwrap_err(), expected);
+            assert_eq!(IntervalDT::parse_humanized(input).unwrap_err(), expected);
+        }
+        assert_eq!(
+            IntervalDT::parse_humanized("1w").unwrap_err(),
+            Error::ParseError("unknown interval unit w".to_string())
+        );
+        assert_eq!(
+            IntervalYM::parse_humanized("1M").unwrap_err(),
+            Error::ParseError("unknown interval unit M".to_string())
+        );
+        assert_eq!(
+            IntervalDT::parse_humanized("1d6mo").unwrap_err(),
+            Error::ParseError("the unit mo cannot be used in IntervalDT".to_string())
+        );
+        assert_eq!(
+            IntervalYM::parse_humanized("1y2m").unwrap_err(),
+            Error::ParseError("the unit m cannot be used in IntervalYM".to_string())
+        );
+        assert_eq!(
+            IntervalYM::parse_humanized("1.5y").unwrap_err(),
+            Error::ParseError("the fraction is not allowed in IntervalYM".to_string())
+        );
+    }
+
+    #[test]
+    fn test_interval_to_compact_string() {
+        assert_eq!(IntervalYM::ZERO.to_compact_string().unwrap(), "0mo");
+        assert_eq!(
+            IntervalYM::try_from_ym(0, 5)
+                .unwrap()
+                .to_compact_string()
+                .unwrap(),
+            "5mo"
+        );
+        assert_eq!(
+            IntervalYM::try_from_ym(2, 0)
+                .unwrap()
+                .to_compact_string()
+                .unwrap(),
+            "2y"
+        );
+        assert_eq!(
+            (-IntervalYM::try_from_ym(1, 6).unwrap())
+                .to_compact_string()
+                .unwrap(),
+            "-1y 6mo"
+        );
+
+        assert_eq!(IntervalDT::ZERO.to_compact_string().unwrap(), "0s");
+        assert_eq!(
+            IntervalDT::try_from_dhms(3, 0, 0, 0, 0)
+                .unwrap()
+                .to_compact_string()
+                .unwrap(),
+            "3d"
+        );
+        assert_eq!(
+            IntervalDT::try_from_dhms(2, 3, 0, 0, 0)
+                .unwrap()
+                .to_compact_string()
+                .unwrap(),
+            "2d 3h"
+        );
+        assert_eq!(
+            IntervalDT::try_from_dhms(0, 0, 4, 5, 0)
+                .unwrap()
+                .to_compact_string()
+                .unwrap(),
+            "4m 5s"
+        );
+        assert_eq!(
+            IntervalDT::try_from_dhms(1, 0, 0, 2, 500000)
+                .unwrap()
+                .to_compact_string()
+                .unwrap(),
+            "1d 2.5s"
+        );
+        assert_eq!(
+            IntervalDT::try_from_dhms(0, 0, 0, 0, 1)
+                .unwrap()
+                .to_compact_string()
+                .unwrap(),
+            "0.000001s"
+        );
+        assert_eq!(
+            (-IntervalDT::try_from_dhms(0, 1, 30, 0, 0).unwrap())
+                .to_compact_string()
+                .unwrap(),
+            "-1h 30m"
+        );
+    }
 }
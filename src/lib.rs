@@ -4,17 +4,21 @@
 //!
 //!- `serde`: Enable `serde`-based serialization and deserialization. Not enabled by default.
 //!- `oracle`: Enable Oracle oriented datetime type: `OracleDate`. Not enabled by default.
+//!- `time`: Enable conversions between the datetime types and the `time` crate types. Not enabled by default.
 
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 #[macro_use]
 mod util;
 
+mod any;
+mod builder;
 mod common;
 mod date;
 mod error;
 mod format;
 mod interval;
+mod saturating;
 mod time;
 mod timestamp;
 
@@ -23,21 +27,52 @@ mod local;
 mod oracle;
 #[cfg(feature = "serde")]
 mod serialize;
+#[cfg(feature = "time")]
+mod time_compat;
 
-pub use crate::date::{Date, Month, WeekDay};
+pub use crate::any::AnyDateTime;
+pub use crate::builder::{DateBuilder, TimestampBuilder};
+pub use crate::date::{
+    date_range_inclusive, Date, DateRangeInclusive, Half, Month, MonthOverflow, Quarter, WeekDay,
+};
 pub use crate::error::Error;
-pub use crate::format::Formatter;
+pub use crate::format::{Formatter, FormatterCache, ParseReport};
 pub use crate::interval::{IntervalDT, IntervalYM, Sign};
+pub use crate::saturating::Saturating;
 pub use crate::time::Time;
-pub use crate::timestamp::Timestamp;
+pub use crate::timestamp::{RelativeTime, Timestamp};
 
 #[cfg(feature = "oracle")]
 pub use crate::oracle::Date as OracleDate;
 
+/// Serde helper modules for alternative representations, used with `#[serde(with = "...")]`.
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod serde {
+    pub use crate::serialize::{interval_dt_days, interval_ym_months, timestamp_array};
+}
+
 /// General trait for all date time types.
 pub trait DateTime {
     /// Extracts year from date time.
     fn year(&self) -> Option<i32>;
+    /// Extracts century from date time, e.g. 21 for the years 2001..=2100.
+    ///
+    /// The default implementation computes it from [`date`](DateTime::date), so it is `None`
+    /// for the types without a date.
+    #[inline]
+    fn century(&self) -> Option<i32> {
+        self.date().map(|date| (date.extract().0 - 1) / 100 + 1)
+    }
+    /// Extracts half of year from date time, i.e. 1 for January..=June and 2 for
+    /// July..=December.
+    ///
+    /// The default implementation computes it from [`date`](DateTime::date), so it is `None`
+    /// for the types without a date.
+    #[inline]
+    fn half(&self) -> Option<i32> {
+        self.date().map(|date| date.half_enum() as i32)
+    }
     /// Extracts month from date time.
     fn month(&self) -> Option<i32>;
     /// Extracts day from date time.
@@ -48,6 +83,36 @@ pub trait DateTime {
     fn minute(&self) -> Option<i32>;
     /// Extracts second from date time.
     fn second(&self) -> Option<f64>;
+    /// Extracts millisecond (0..=999) within the second from date time.
+    ///
+    /// The default implementation computes it from [`microsecond`](DateTime::microsecond).
+    #[inline]
+    fn millisecond(&self) -> Option<u32> {
+        self.microsecond().map(|usec| usec / 1000)
+    }
+    /// Extracts microsecond (0..=999999) within the second from date time.
+    fn microsecond(&self) -> Option<u32>;
+    /// Extracts epoch, i.e. the fractional seconds since Unix Epoch `1970-01-01 00:00:00`.
+    ///
+    /// For `Time`, it is the seconds since midnight. For intervals, it is the total seconds,
+    /// where a year is 365.25 days and a month is 30 days as in PostgreSQL.
+    ///
+    /// The default implementation adds up [`date`](DateTime::date) and the time fields, and
+    /// returns `None` if the type has neither.
+    #[inline]
+    fn epoch_seconds(&self) -> Option<f64> {
+        let days = self.date().map(|date| date.days());
+        let second = self.second();
+        if days.is_none() && second.is_none() {
+            return None;
+        }
+        let minutes = self.hour().unwrap_or(0) * 60 + self.minute().unwrap_or(0);
+        Some(
+            days.unwrap_or(0) as f64 * crate::common::SECONDS_PER_DAY as f64
+                + minutes as f64 * crate::common::SECONDS_PER_MINUTE as f64
+                + second.unwrap_or(0.0),
+        )
+    }
     /// Extracts second from date time.
     fn date(&self) -> Option<Date>;
 }
@@ -209,6 +274,41 @@ pub trait Trunc: Sized {
     /// assert_eq!(timestamp.trunc_minute().unwrap(), result);
     /// ```
     fn trunc_minute(self) -> Result<Self, Error>;
+
+    /// Truncates to the given unit, so that it composes in a fluent chain, e.g.
+    /// `ts.trunc(DateTimeUnit::Month)?.add_months(1)?`.
+    ///
+    /// `Week` truncates to the ISO week starting on Monday, as
+    /// [`trunc_iso_week`](Trunc::trunc_iso_week). Units smaller than a minute are not supported
+    /// and return `UnsupportedUnit` error.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use sqldatetime::{Timestamp, Date, Time, Trunc, DateTimeUnit};
+    ///
+    /// let timestamp = Date::try_from_ymd(2021, 10, 15).unwrap().and_time(Time::try_from_hms(8, 30, 0, 0).unwrap());
+    /// let result = Date::try_from_ymd(2021, 10, 1).unwrap().and_time(Time::ZERO);
+    /// assert_eq!(timestamp.trunc(DateTimeUnit::Month).unwrap(), result);
+    /// ```
+    #[inline]
+    fn trunc(self, unit: DateTimeUnit) -> Result<Self, Error> {
+        match unit {
+            DateTimeUnit::Year => self.trunc_year(),
+            DateTimeUnit::Quarter => self.trunc_quarter(),
+            DateTimeUnit::Month => self.trunc_month(),
+            DateTimeUnit::Week => self.trunc_iso_week(),
+            DateTimeUnit::Day => self.trunc_day(),
+            DateTimeUnit::Hour => self.trunc_hour(),
+            DateTimeUnit::Minute => self.trunc_minute(),
+            DateTimeUnit::Second | DateTimeUnit::Millisecond | DateTimeUnit::Microsecond => {
+                Err(Error::UnsupportedUnit(try_format!(
+                    "the unit {:?} is not supported by trunc",
+                    unit
+                )?))
+            }
+        }
+    }
 }
 
 /// Round trait for Timestamp/Date/OracleDate
@@ -368,4 +468,64 @@ pub trait Round: Sized {
     /// assert_eq!(timestamp.round_minute().unwrap(), result);
     /// ```
     fn round_minute(self) -> Result<Self, Error>;
+
+    /// Rounds to the given unit, so that it composes in a fluent chain, e.g.
+    /// `ts.round(DateTimeUnit::Day)?.add_calendar_days(1)?`.
+    ///
+    /// `Week` rounds to the ISO week starting on Monday, as
+    /// [`round_iso_week`](Round::round_iso_week). Units smaller than a minute are not supported
+    /// and return `UnsupportedUnit` error.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use sqldatetime::{Timestamp, Date, Time, Round, DateTimeUnit};
+    ///
+    /// let timestamp = Date::try_from_ymd(2021, 10, 15).unwrap().and_time(Time::try_from_hms(18, 30, 0, 0).unwrap());
+    /// let result = Date::try_from_ymd(2021, 10, 16).unwrap().and_time(Time::ZERO);
+    /// assert_eq!(timestamp.round(DateTimeUnit::Day).unwrap(), result);
+    /// ```
+    #[inline]
+    fn round(self, unit: DateTimeUnit) -> Result<Self, Error> {
+        match unit {
+            DateTimeUnit::Year => self.round_year(),
+            DateTimeUnit::Quarter => self.round_quarter(),
+            DateTimeUnit::Month => self.round_month(),
+            DateTimeUnit::Week => self.round_iso_week(),
+            DateTimeUnit::Day => self.round_day(),
+            DateTimeUnit::Hour => self.round_hour(),
+            DateTimeUnit::Minute => self.round_minute(),
+            DateTimeUnit::Second | DateTimeUnit::Millisecond | DateTimeUnit::Microsecond => {
+                Err(Error::UnsupportedUnit(try_format!(
+                    "the unit {:?} is not supported by round",
+                    unit
+                )?))
+            }
+        }
+    }
+}
+
+/// Units of date time, e.g. for computing the difference between two date times.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum DateTimeUnit {
+    /// Year
+    Year,
+    /// Quarter
+    Quarter,
+    /// Month
+    Month,
+    /// Week
+    Week,
+    /// Day
+    Day,
+    /// Hour
+    Hour,
+    /// Minute
+    Minute,
+    /// Second
+    Second,
+    /// Millisecond
+    Millisecond,
+    /// Microsecond
+    Microsecond,
 }
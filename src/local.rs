@@ -1,3 +1,4 @@
+use crate::Timestamp;
 use libc::{time_t, tm};
 use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -31,6 +32,23 @@ impl Local {
         }
     }
 
+    /// Creates a `Local` from the given timestamp, used in place of the system clock.
+    #[inline]
+    pub fn from_timestamp(timestamp: Timestamp) -> Self {
+        let (date, time) = timestamp.extract();
+        let (year, month, day) = date.extract();
+        let (hour, minute, second, usec) = time.extract();
+        Local {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            usec,
+        }
+    }
+
     #[inline]
     pub fn year(&self) -> i32 {
         self.year
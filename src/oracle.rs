@@ -81,6 +81,19 @@ impl Date {
         }
     }
 
+    /// Creates a `Date` from the given `Timestamp`, returns `PrecisionLoss` error instead of
+    /// truncating like `From<Timestamp>` if the timestamp has fractional seconds.
+    ///
+    /// This is not a `TryFrom` implementation, as the lossy `From` already provides an
+    /// infallible `TryFrom<Timestamp>`.
+    #[inline]
+    pub const fn try_from_timestamp_exact(timestamp: Timestamp) -> Result<Self> {
+        if timestamp.usecs() % USECONDS_PER_SECOND != 0 {
+            return Err(Error::PrecisionLoss);
+        }
+        Ok(Date(timestamp))
+    }
+
     #[inline]
     const fn is_valid_date(usecs: i64) -> bool {
         is_valid_timestamp(usecs) && usecs % USECONDS_PER_SECOND == 0
@@ -93,6 +106,12 @@ impl Date {
         Ok(LazyFormat::new(fmt, self))
     }
 
+    /// Formats `Date` into a new `String` by given formatter, which can be reused across calls.
+    #[inline]
+    pub fn to_string_with(self, fmt: &Formatter) -> Result<String> {
+        fmt.format_to_string(self)
+    }
+
     /// Parses `Date` from given string and format.
     #[inline]
     pub fn parse<S1: AsRef<str>, S2: AsRef<str>>(input: S1, fmt: S2) -> Result<Self> {
@@ -101,6 +120,9 @@ impl Date {
     }
 
     /// `Date` adds `IntervalDT`
+    ///
+    /// Returns `DateUnderflow` error if the result is before the minimum value, or
+    /// `DateOverflow` error if it is after the maximum value.
     #[inline]
     pub fn add_interval_dt(self, interval: IntervalDT) -> Result<Date> {
         Ok(Date::from(self.0.add_interval_dt(interval)?))
@@ -141,6 +163,9 @@ impl Date {
     }
 
     /// `Date` subtracts `IntervalDT`
+    ///
+    /// Returns `DateUnderflow` error if the result is before the minimum value, or
+    /// `DateOverflow` error if it is after the maximum value.
     #[inline]
     pub fn sub_interval_dt(self, interval: IntervalDT) -> Result<Date> {
         self.add_interval_dt(-interval)
@@ -180,6 +205,31 @@ impl Date {
         self.0.last_day_of_month().into()
     }
 
+    /// Makes a new `Date` with the year replaced, returns error if the result is invalid.
+    #[inline]
+    pub fn with_year(self, year: i32) -> Result<Date> {
+        Ok(Date(self.0.with_year(year)?))
+    }
+
+    /// Makes a new `Date` with the month replaced, returns error if the result is invalid.
+    #[inline]
+    pub fn with_month(self, month: u32) -> Result<Date> {
+        Ok(Date(self.0.with_month(month)?))
+    }
+
+    /// Makes a new `Date` with the month replaced, and the day clamped to the last day
+    /// of that month.
+    #[inline]
+    pub fn with_month_clamped(self, month: u32) -> Result<Date> {
+        Ok(Date(self.0.with_month_clamped(month)?))
+    }
+
+    /// Makes a new `Date` with the day of month replaced, returns error if the result is invalid.
+    #[inline]
+    pub fn with_day(self, day: u32) -> Result<Date> {
+        Ok(Date(self.0.with_day(day)?))
+    }
+
     /// Gets months and microseconds of datetime between two `Date`.
     #[inline]
     pub fn months_between(self, date: Date) -> (i32, i64) {
@@ -379,12 +429,23 @@ impl DateTime for Date {
         self.time().second()
     }
 
+    #[inline]
+    fn microsecond(&self) -> Option<u32> {
+        self.time().microsecond()
+    }
+
+    #[inline]
+    fn epoch_seconds(&self) -> Option<f64> {
+        Some(self.usecs() as f64 / USECONDS_PER_SECOND as f64)
+    }
+
     #[inline]
     fn date(&self) -> Option<SqlDate> {
         Some(Date::date(*self))
     }
 }
 
+/// Truncates the fractional seconds, see [`Date::try_from_timestamp_exact`] for a checked conversion.
 impl From<Timestamp> for Date {
     #[inline]
     fn from(timestamp: Timestamp) -> Self {
@@ -525,6 +586,7 @@ impl PartialOrd<SqlDate> for Date {
 mod tests {
     use super::*;
     use crate::common::USECONDS_PER_HOUR;
+    use crate::util::testing::TestRng;
 
     fn generate_date(year: i32, month: u32, day: u32, hour: u32, min: u32, sec: u32) -> Date {
         Date::new(
@@ -1088,10 +1150,16 @@ mod tests {
         assert_eq!(date.sub_interval_dt(interval).unwrap(), expect);
 
         let interval = IntervalDT::try_from_dhms(0, 0, 0, 1, 0).unwrap();
-        assert!(date.add_interval_dt(interval).is_err());
+        assert_eq!(
+            date.add_interval_dt(interval).unwrap_err(),
+            Error::DateOverflow
+        );
 
         let interval = IntervalDT::try_from_dhms(12345, 12, 3, 5, 6).unwrap();
-        assert!(date.add_interval_dt(interval).is_err());
+        assert_eq!(
+            date.add_interval_dt(interval).unwrap_err(),
+            Error::DateOverflow
+        );
 
         let date = generate_date(1, 1, 1, 0, 0, 0);
         let interval = IntervalDT::try_from_dhms(5, 4, 3, 2, 1).unwrap();
@@ -1099,10 +1167,92 @@ mod tests {
         assert_eq!(date.add_interval_dt(interval).unwrap(), expect);
 
         let interval = IntervalDT::try_from_dhms(0, 0, 0, 0, 1).unwrap();
-        assert!(date.sub_interval_dt(interval).is_err());
+        assert_eq!(
+            date.sub_interval_dt(interval).unwrap_err(),
+            Error::DateUnderflow
+        );
 
         let interval = IntervalDT::try_from_dhms(12345, 12, 3, 5, 6).unwrap();
-        assert!(date.sub_interval_dt(interval).is_err());
+        assert_eq!(
+            date.sub_interval_dt(interval).unwrap_err(),
+            Error::DateUnderflow
+        );
+
+        // The direction of out of range results
+        assert_eq!(
+            date.add_interval_dt(-interval).unwrap_err(),
+            Error::DateUnderflow
+        );
+        assert_eq!(
+            date.add_interval_dt(IntervalDT::MIN).unwrap_err(),
+            Error::DateUnderflow
+        );
+        assert_eq!(
+            date.sub_interval_dt(IntervalDT::MAX).unwrap_err(),
+            Error::DateUnderflow
+        );
+        assert_eq!(
+            date.add_interval_dt(IntervalDT::MAX).unwrap_err(),
+            Error::DateOverflow
+        );
+    }
+
+    #[test]
+    fn test_date_add_sub_interval_ym_whole_seconds() {
+        fn assert_whole_seconds(date: Date) {
+            assert_eq!(date.usecs() % USECONDS_PER_SECOND, 0, "{:?}", date);
+            assert_eq!(date.microsecond(), Some(0));
+        }
+
+        let intervals = [
+            IntervalYM::try_from_ym(0, 1).unwrap(),
+            IntervalYM::try_from_ym(0, 11).unwrap(),
+            IntervalYM::try_from_ym(1, 0).unwrap(),
+            IntervalYM::try_from_ym(100, 7).unwrap(),
+            IntervalYM::try_from_ym(1969, 0).unwrap(),
+        ];
+
+        // Pseudo random dates across the whole range, including before Unix Epoch
+        let mut rng = TestRng::new();
+        for _ in 0..1000 {
+            let secs = (rng.next_u64() >> 11) as i64
+                % ((Date::MAX.usecs() - Date::MIN.usecs()) / USECONDS_PER_SECOND);
+            let date =
+                Date::try_from_usecs(Date::MIN.usecs() + secs * USECONDS_PER_SECOND).unwrap();
+            assert_whole_seconds(date);
+
+            for interval in intervals {
+                for result in [
+                    date.add_interval_ym(interval),
+                    date.add_interval_ym(-interval),
+                    date.sub_interval_ym(interval),
+                    date.sub_interval_ym(-interval),
+                ]
+                .iter()
+                .flatten()
+                {
+                    assert_whole_seconds(*result);
+                    // The time of day is kept as is
+                    assert_eq!(result.extract().1, date.extract().1);
+                }
+            }
+        }
+
+        for date in [
+            Date::MIN,
+            Date::MAX,
+            generate_date(1969, 12, 31, 23, 59, 59),
+            generate_date(1970, 1, 1, 0, 0, 1),
+        ] {
+            for interval in intervals {
+                if let Ok(result) = date.add_interval_ym(interval) {
+                    assert_whole_seconds(result);
+                }
+                if let Ok(result) = date.sub_interval_ym(interval) {
+                    assert_whole_seconds(result);
+                }
+            }
+        }
     }
 
     #[test]
@@ -1244,6 +1394,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_date_to_string_with() {
+        let date = generate_date(2021, 6, 1, 12, 30, 5);
+        let formatter = Formatter::try_new("YYYY-MM-DD HH24:MI:SS").unwrap();
+        assert_eq!(
+            date.to_string_with(&formatter).unwrap(),
+            date.format("YYYY-MM-DD HH24:MI:SS").unwrap().to_string()
+        );
+        assert_eq!(
+            date.to_string_with(&formatter).unwrap(),
+            "2021-06-01 12:30:05"
+        );
+    }
+
+    #[test]
+    fn test_date_parse_with_report() {
+        let formatter = Formatter::try_new("YYYY-MM-DDTHH24:MI:SS.FF").unwrap();
+
+        // Any non-zero fraction is dropped
+        let (date, report) = formatter
+            .parse_with_report::<_, Date>("2021-06-01T12:30:05.123456789")
+            .unwrap();
+        assert_eq!(date, generate_date(2021, 6, 1, 12, 30, 5));
+        assert!(report.truncated_fraction);
+        assert!(report.defaulted_fields.is_empty());
+
+        let (date, report) = formatter
+            .parse_with_report::<_, Date>("2021-06-01T12:30:05.000")
+            .unwrap();
+        assert_eq!(date, generate_date(2021, 6, 1, 12, 30, 5));
+        assert!(!report.truncated_fraction);
+    }
+
     #[test]
     fn test_date_add_sub_days() {
         let upper_ts = generate_date(9999, 12, 31, 23, 59, 59);
@@ -1301,6 +1484,31 @@ mod tests {
         assert_eq!(date.sub_days(-1.12).unwrap(), date.add_days(1.12).unwrap());
     }
 
+    #[test]
+    fn test_date_hash_consistent_with_eq() {
+        use crate::util::testing::hash_of;
+
+        for date in [
+            Date::MIN,
+            generate_date(1969, 12, 31, 0, 0, 0),
+            generate_date(1969, 12, 31, 23, 59, 59),
+            generate_date(2021, 6, 1, 12, 30, 5),
+            Date::MAX,
+        ] {
+            let ts = Timestamp::from(date);
+            assert_eq!(date, ts);
+            assert_eq!(ts, date);
+            assert_eq!(hash_of(&date), hash_of(&ts));
+
+            let (sql_date, time) = date.extract();
+            if time == Time::ZERO {
+                assert_eq!(date, sql_date);
+                assert_eq!(sql_date, date);
+                assert_eq!(hash_of(&date), hash_of(&sql_date));
+            }
+        }
+    }
+
     #[test]
     fn test_date_cmp_timestamp() {
         let date = generate_date(1970, 1, 1, 1, 1, 1);
@@ -1319,6 +1527,9 @@ mod tests {
         assert_eq!(hour as i32, date.hour().unwrap());
         assert_eq!(min as i32, date.minute().unwrap());
         assert_eq!(sec as f64, date.second().unwrap());
+        assert_eq!(0, date.millisecond().unwrap());
+        assert_eq!(0, date.microsecond().unwrap());
+        assert_eq!(Timestamp::from(date).epoch_seconds(), date.epoch_seconds());
     }
 
     #[test]
@@ -1374,6 +1585,38 @@ mod tests {
         assert_eq!(Date::from(timestamp), generate_date(2000, 1, 1, 0, 0, 0));
     }
 
+    #[test]
+    fn test_try_from_timestamp_exact() {
+        let timestamp = generate_ts(2021, 6, 1, 12, 30, 45, 0);
+        assert_eq!(
+            Date::try_from_timestamp_exact(timestamp).unwrap(),
+            generate_date(2021, 6, 1, 12, 30, 45)
+        );
+        assert_eq!(
+            Date::try_from_timestamp_exact(Timestamp::MIN).unwrap(),
+            Date::MIN
+        );
+        assert_eq!(
+            Date::try_from_timestamp_exact(generate_ts(1969, 12, 31, 23, 59, 59, 0)).unwrap(),
+            generate_date(1969, 12, 31, 23, 59, 59)
+        );
+
+        // Sub-second precision is not silently dropped
+        for timestamp in [
+            generate_ts(2021, 6, 1, 12, 30, 45, 1),
+            generate_ts(2021, 6, 1, 12, 30, 45, 999999),
+            generate_ts(1969, 12, 31, 23, 59, 59, 500000),
+            Timestamp::MAX,
+        ] {
+            assert_eq!(
+                Date::try_from_timestamp_exact(timestamp).unwrap_err(),
+                Error::PrecisionLoss
+            );
+            // The lossy conversion still truncates
+            assert_ne!(Timestamp::from(Date::from(timestamp)), timestamp);
+        }
+    }
+
     #[test]
     fn test_timestamp_sub_date() {
         let upper_ts = generate_ts(9999, 12, 31, 23, 59, 59, 999999);
@@ -1649,6 +1892,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_date_with_fields() {
+        let date = generate_date(2021, 1, 31, 14, 15, 16);
+
+        assert_eq!(
+            date.with_year(2020).unwrap(),
+            generate_date(2020, 1, 31, 14, 15, 16)
+        );
+        assert_eq!(date.with_year(10000).unwrap_err(), Error::DateOutOfRange);
+        assert_eq!(
+            date.with_month(12).unwrap(),
+            generate_date(2021, 12, 31, 14, 15, 16)
+        );
+        assert_eq!(date.with_month(2).unwrap_err(), Error::InvalidDate);
+        assert_eq!(
+            date.with_month_clamped(2).unwrap(),
+            generate_date(2021, 2, 28, 14, 15, 16)
+        );
+        assert_eq!(
+            date.with_day(15).unwrap(),
+            generate_date(2021, 1, 15, 14, 15, 16)
+        );
+        assert_eq!(date.with_day(0).unwrap_err(), Error::InvalidDay);
+    }
+
     #[test]
     fn test_last_day_of_month() {
         assert_eq!(
@@ -0,0 +1,187 @@
+//! Saturating arithmetic wrapper implementation.
+
+use crate::common::{TIMESTAMP_MAX, TIMESTAMP_MIN};
+use crate::{Date, IntervalDT, Timestamp};
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+/// Saturating provides saturating arithmetic on date time values with the operators, like
+/// `std::num::Saturating`.
+///
+/// The results are clamped at the minimum and maximum values of the type instead of returning
+/// an error, so the operations can be chained freely:
+/// - `Saturating<Timestamp>` adds and subtracts `IntervalDT`.
+/// - `Saturating<Date>` adds and subtracts days as `i32`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Saturating<T>(pub T);
+
+impl Add<IntervalDT> for Saturating<Timestamp> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: IntervalDT) -> Self {
+        let usecs = self
+            .0
+            .usecs()
+            .saturating_add(rhs.usecs())
+            .clamp(TIMESTAMP_MIN, TIMESTAMP_MAX);
+        Saturating(unsafe { Timestamp::from_usecs_unchecked(usecs) })
+    }
+}
+
+impl Sub<IntervalDT> for Saturating<Timestamp> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: IntervalDT) -> Self {
+        self + -rhs
+    }
+}
+
+impl AddAssign<IntervalDT> for Saturating<Timestamp> {
+    #[inline]
+    fn add_assign(&mut self, rhs: IntervalDT) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign<IntervalDT> for Saturating<Timestamp> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: IntervalDT) {
+        *self = *self - rhs;
+    }
+}
+
+impl Add<i32> for Saturating<Date> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: i32) -> Self {
+        let days = self
+            .0
+            .days()
+            .saturating_add(rhs)
+            .clamp(Date::MIN.days(), Date::MAX.days());
+        Saturating(unsafe { Date::from_days_unchecked(days) })
+    }
+}
+
+impl Sub<i32> for Saturating<Date> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: i32) -> Self {
+        let days = self
+            .0
+            .days()
+            .saturating_sub(rhs)
+            .clamp(Date::MIN.days(), Date::MAX.days());
+        Saturating(unsafe { Date::from_days_unchecked(days) })
+    }
+}
+
+impl AddAssign<i32> for Saturating<Date> {
+    #[inline]
+    fn add_assign(&mut self, rhs: i32) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign<i32> for Saturating<Date> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: i32) {
+        *self = *self - rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Time;
+
+    fn generate_ts(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        min: u32,
+        sec: u32,
+        usec: u32,
+    ) -> Timestamp {
+        Timestamp::new(
+            Date::try_from_ymd(year, month, day).unwrap(),
+            Time::try_from_hms(hour, min, sec, usec).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_saturating_timestamp() {
+        let ts = generate_ts(2021, 6, 1, 12, 30, 5, 0);
+        let one_day = IntervalDT::try_from_dhms(1, 0, 0, 0, 0).unwrap();
+
+        // In range, same as the checked arithmetic
+        assert_eq!(
+            (Saturating(ts) + one_day).0,
+            ts.add_interval_dt(one_day).unwrap()
+        );
+        assert_eq!(
+            (Saturating(ts) - one_day).0,
+            ts.sub_interval_dt(one_day).unwrap()
+        );
+
+        // Chained additions past the bounds stay at the bounds
+        let mut saturating = Saturating(generate_ts(9999, 12, 29, 0, 0, 0, 0));
+        for _ in 0..5 {
+            saturating += one_day;
+        }
+        assert_eq!(saturating, Saturating(Timestamp::MAX));
+        assert_eq!(
+            Saturating(Timestamp::MAX) + IntervalDT::MAX + IntervalDT::MAX,
+            Saturating(Timestamp::MAX)
+        );
+        assert_eq!(
+            Saturating(Timestamp::MAX) - IntervalDT::MIN,
+            Saturating(Timestamp::MAX)
+        );
+
+        let mut saturating = Saturating(generate_ts(1, 1, 3, 0, 0, 0, 0));
+        for _ in 0..5 {
+            saturating -= one_day;
+        }
+        assert_eq!(saturating, Saturating(Timestamp::MIN));
+        assert_eq!(
+            Saturating(Timestamp::MIN) + IntervalDT::MIN,
+            Saturating(Timestamp::MIN)
+        );
+
+        // Back from the bound
+        assert_eq!(
+            (Saturating(ts) + IntervalDT::MAX - one_day).0,
+            generate_ts(9999, 12, 30, 23, 59, 59, 999999)
+        );
+    }
+
+    #[test]
+    fn test_saturating_date() {
+        let date = Date::try_from_ymd(2021, 6, 1).unwrap();
+        assert_eq!((Saturating(date) + 30).0, date.add_days(30).unwrap());
+        assert_eq!((Saturating(date) - 30).0, date.sub_days(30).unwrap());
+        assert_eq!((Saturating(date) + -30).0, date.sub_days(30).unwrap());
+
+        let mut saturating = Saturating(Date::try_from_ymd(9999, 12, 30).unwrap());
+        for _ in 0..5 {
+            saturating += 1;
+        }
+        assert_eq!(saturating, Saturating(Date::MAX));
+        assert_eq!(Saturating(date) + i32::MAX, Saturating(Date::MAX));
+        assert_eq!(Saturating(date) - i32::MIN, Saturating(Date::MAX));
+
+        let mut saturating = Saturating(Date::try_from_ymd(1, 1, 2).unwrap());
+        for _ in 0..5 {
+            saturating -= 1;
+        }
+        assert_eq!(saturating, Saturating(Date::MIN));
+        assert_eq!(Saturating(date) + i32::MIN, Saturating(Date::MIN));
+        assert_eq!(Saturating(date) - i32::MAX, Saturating(Date::MIN));
+    }
+}
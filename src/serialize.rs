@@ -1,5 +1,7 @@
 //! Impl the `serde::Serialize` and `serde::Deserialize` traits.
 
+use crate::date::{Month, WeekDay};
+use crate::format::NameStyle;
 use crate::{Date, Formatter, IntervalDT, IntervalYM, Time, Timestamp};
 use once_cell::sync::Lazy;
 use serde_crate::de::Visitor;
@@ -391,6 +393,257 @@ impl<'de> Deserialize<'de> for crate::oracle::Date {
     }
 }
 
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Serialize for WeekDay {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(self.name(NameStyle::Capital))
+        } else {
+            serializer.serialize_u8(*self as u8)
+        }
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for WeekDay {
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct WeekDayVisitor;
+
+        impl<'de> Visitor<'de> for WeekDayVisitor {
+            type Value = WeekDay;
+
+            #[inline]
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(formatter, "a WeekDay name or a number in 1..=7")
+            }
+
+            #[inline]
+            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                if (1..=7).contains(&v) {
+                    Ok(WeekDay::from(v as usize))
+                } else {
+                    Err(de::Error::invalid_value(de::Unexpected::Unsigned(v), &self))
+                }
+            }
+
+            #[inline]
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                WeekDay::all()
+                    .iter()
+                    .find(|week_day| week_day.name(NameStyle::Capital).eq_ignore_ascii_case(v))
+                    .copied()
+                    .ok_or_else(|| de::Error::invalid_value(de::Unexpected::Str(v), &self))
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(WeekDayVisitor)
+        } else {
+            deserializer.deserialize_u8(WeekDayVisitor)
+        }
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Serialize for Month {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(self.name(NameStyle::Capital))
+        } else {
+            serializer.serialize_u8(*self as u8)
+        }
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Month {
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct MonthVisitor;
+
+        impl<'de> Visitor<'de> for MonthVisitor {
+            type Value = Month;
+
+            #[inline]
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(formatter, "a Month name or a number in 1..=12")
+            }
+
+            #[inline]
+            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                if (1..=12).contains(&v) {
+                    Ok(Month::from(v as usize))
+                } else {
+                    Err(de::Error::invalid_value(de::Unexpected::Unsigned(v), &self))
+                }
+            }
+
+            #[inline]
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Month::all()
+                    .iter()
+                    .find(|month| month.name(NameStyle::Capital).eq_ignore_ascii_case(v))
+                    .copied()
+                    .ok_or_else(|| de::Error::invalid_value(de::Unexpected::Str(v), &self))
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(MonthVisitor)
+        } else {
+            deserializer.deserialize_u8(MonthVisitor)
+        }
+    }
+}
+
+/// (De)serializes `IntervalDT` as a `f64` number of days, e.g. `1.5` for `1 12:00:00`.
+///
+/// Use it with `#[serde(with = "sqldatetime::serde::interval_dt_days")]`.
+///
+/// Note that `f64` cannot represent every microsecond exactly, so the value is rounded to the
+/// nearest microsecond when deserializing and may differ slightly from the original interval.
+/// Use the default integer representation if a lossless round trip is required.
+pub mod interval_dt_days {
+    use crate::common::USECONDS_PER_DAY;
+    use crate::{Error, IntervalDT};
+    use serde_crate::{de, Deserialize, Deserializer, Serializer};
+
+    /// Serializes `IntervalDT` as a `f64` number of days.
+    #[inline]
+    pub fn serialize<S>(interval: &IntervalDT, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_f64(interval.usecs() as f64 / USECONDS_PER_DAY as f64)
+    }
+
+    /// Deserializes `IntervalDT` from a `f64` number of days.
+    #[inline]
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<IntervalDT, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let days = f64::deserialize(deserializer)?;
+        let usecs = (days * USECONDS_PER_DAY as f64).round();
+        if usecs.is_infinite() {
+            Err(de::Error::custom(Error::NumericOverflow))
+        } else if usecs.is_nan() {
+            Err(de::Error::custom(Error::InvalidNumber))
+        } else {
+            IntervalDT::try_from_usecs(usecs as i64).map_err(de::Error::custom)
+        }
+    }
+}
+
+/// (De)serializes `IntervalYM` as a `f64` number of months, e.g. `18.0` for `1-6`.
+///
+/// Use it with `#[serde(with = "sqldatetime::serde::interval_ym_months")]`.
+///
+/// The value is rounded to the nearest month when deserializing.
+pub mod interval_ym_months {
+    use crate::{Error, IntervalYM};
+    use serde_crate::{de, Deserialize, Deserializer, Serializer};
+
+    /// Serializes `IntervalYM` as a `f64` number of months.
+    #[inline]
+    pub fn serialize<S>(interval: &IntervalYM, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_f64(interval.months() as f64)
+    }
+
+    /// Deserializes `IntervalYM` from a `f64` number of months.
+    #[inline]
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<IntervalYM, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let months = f64::deserialize(deserializer)?.round();
+        if months.is_infinite() {
+            Err(de::Error::custom(Error::NumericOverflow))
+        } else if months.is_nan() {
+            Err(de::Error::custom(Error::InvalidNumber))
+        } else if months < i32::MIN as f64 || months > i32::MAX as f64 {
+            Err(de::Error::custom(Error::IntervalOutOfRange))
+        } else {
+            IntervalYM::try_from_months(months as i32).map_err(de::Error::custom)
+        }
+    }
+}
+
+/// (De)serializes `Timestamp` as an array of its fields
+/// `[year, month, day, hour, minute, second, microsecond]`, e.g. `[2021,6,1,12,30,5,0]`.
+///
+/// Use it with `#[serde(with = "sqldatetime::serde::timestamp_array")]`.
+///
+/// The fields are validated when deserializing, so e.g. month 13 is rejected.
+pub mod timestamp_array {
+    use crate::{Date, Timestamp};
+    use serde_crate::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializes `Timestamp` as a `[i32, u8, u8, u8, u8, u8, u32]` array.
+    #[inline]
+    pub fn serialize<S>(timestamp: &Timestamp, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (date, time) = timestamp.extract();
+        let (year, month, day) = date.extract();
+        let (hour, minute, sec, usec) = time.extract();
+        (
+            year,
+            month as u8,
+            day as u8,
+            hour as u8,
+            minute as u8,
+            sec as u8,
+            usec,
+        )
+            .serialize(serializer)
+    }
+
+    /// Deserializes `Timestamp` from a `[i32, u8, u8, u8, u8, u8, u32]` array.
+    #[inline]
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Timestamp, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (year, month, day, hour, minute, sec, usec) =
+            <(i32, u8, u8, u8, u8, u8, u32)>::deserialize(deserializer)?;
+        Date::try_from_ymd(year, month as u32, day as u32)
+            .and_then(|date| date.and_hms(hour as u32, minute as u32, sec as u32, usec))
+            .map_err(de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -566,4 +819,174 @@ mod tests {
         test_oracle_date(1970, 10, 1, 23, 30, 0);
         test_oracle_date(9999, 12, 31, 23, 59, 59);
     }
+
+    #[test]
+    fn test_serde_timestamp_array() {
+        use serde_crate::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        #[serde(crate = "serde_crate")]
+        struct Row {
+            #[serde(with = "crate::serde::timestamp_array")]
+            ts: Timestamp,
+        }
+
+        let row = Row {
+            ts: Date::try_from_ymd(2021, 6, 1)
+                .unwrap()
+                .and_hms(12, 30, 5, 123456)
+                .unwrap(),
+        };
+        let json = serde_json::to_string(&row).unwrap();
+        assert_eq!(json, r#"{"ts":[2021,6,1,12,30,5,123456]}"#);
+        assert_eq!(serde_json::from_str::<Row>(&json).unwrap(), row);
+
+        for ts in [Timestamp::MIN, Timestamp::MAX] {
+            let row = Row { ts };
+            let json = serde_json::to_string(&row).unwrap();
+            assert_eq!(serde_json::from_str::<Row>(&json).unwrap(), row);
+        }
+        assert_eq!(
+            serde_json::to_string(&Row { ts: Timestamp::MAX }).unwrap(),
+            r#"{"ts":[9999,12,31,23,59,59,999999]}"#
+        );
+
+        // Invalid fields
+        for json in [
+            r#"{"ts":[2021,13,1,0,0,0,0]}"#,
+            r#"{"ts":[2021,2,29,0,0,0,0]}"#,
+            r#"{"ts":[0,1,1,0,0,0,0]}"#,
+            r#"{"ts":[2021,6,1,24,0,0,0]}"#,
+            r#"{"ts":[2021,6,1,0,60,0,0]}"#,
+            r#"{"ts":[2021,6,1,0,0,0,1000000]}"#,
+            r#"{"ts":[2021,6,1,0,0,256,0]}"#,
+            r#"{"ts":[2021,6,1,0,0,0]}"#,
+            r#"{"ts":"2021-06-01 00:00:00"}"#,
+        ] {
+            assert!(serde_json::from_str::<Row>(json).is_err(), "{}", json);
+        }
+        let err = serde_json::from_str::<Row>(r#"{"ts":[2021,13,1,0,0,0,0]}"#).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains(&crate::Error::InvalidMonth.to_string()));
+    }
+
+    #[test]
+    fn test_serde_week_day_month() {
+        for week_day in WeekDay::all() {
+            let json = serde_json::to_string(&week_day).unwrap();
+            assert_eq!(json, format!("\"{}\"", week_day.name(NameStyle::Capital)));
+            assert_eq!(serde_json::from_str::<WeekDay>(&json).unwrap(), week_day);
+            let number = (week_day as u8).to_string();
+            assert_eq!(serde_json::from_str::<WeekDay>(&number).unwrap(), week_day);
+
+            let bin = bincode::serialize(&week_day).unwrap();
+            assert_eq!(bin, vec![week_day as u8]);
+            assert_eq!(bincode::deserialize::<WeekDay>(&bin).unwrap(), week_day);
+        }
+        for month in Month::all() {
+            let json = serde_json::to_string(&month).unwrap();
+            assert_eq!(json, format!("\"{}\"", month.name(NameStyle::Capital)));
+            assert_eq!(serde_json::from_str::<Month>(&json).unwrap(), month);
+            let number = (month as u8).to_string();
+            assert_eq!(serde_json::from_str::<Month>(&number).unwrap(), month);
+
+            let bin = bincode::serialize(&month).unwrap();
+            assert_eq!(bin, vec![month as u8]);
+            assert_eq!(bincode::deserialize::<Month>(&bin).unwrap(), month);
+        }
+
+        // Config style
+        #[derive(serde_crate::Deserialize, Debug, PartialEq)]
+        #[serde(crate = "serde_crate")]
+        struct Config {
+            first_day: WeekDay,
+            fiscal_year_start: Month,
+        }
+        assert_eq!(
+            serde_json::from_str::<Config>(r#"{ "first_day": "Monday", "fiscal_year_start": 4 }"#)
+                .unwrap(),
+            Config {
+                first_day: WeekDay::Monday,
+                fiscal_year_start: Month::April
+            }
+        );
+        assert_eq!(
+            serde_json::from_str::<WeekDay>(r#""sunday""#).unwrap(),
+            WeekDay::Sunday
+        );
+
+        // Invalid
+        for json in [r#""Mon""#, r#""Funday""#, "0", "8", "-1", "1.0", "null"] {
+            assert!(serde_json::from_str::<WeekDay>(json).is_err(), "{}", json);
+        }
+        for json in [r#""Jan""#, r#""""#, "0", "13", "-1"] {
+            assert!(serde_json::from_str::<Month>(json).is_err(), "{}", json);
+        }
+        assert!(bincode::deserialize::<WeekDay>(&[0]).is_err());
+        assert!(bincode::deserialize::<Month>(&[13]).is_err());
+    }
+
+    #[test]
+    fn test_serde_interval_float() {
+        use serde_crate::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        #[serde(crate = "serde_crate")]
+        struct Durations {
+            #[serde(with = "crate::serde::interval_dt_days")]
+            dt: IntervalDT,
+            #[serde(with = "crate::serde::interval_ym_months")]
+            ym: IntervalYM,
+        }
+
+        let durations = Durations {
+            dt: IntervalDT::try_from_dhms(1, 12, 0, 0, 0).unwrap(),
+            ym: IntervalYM::try_from_ym(1, 6).unwrap(),
+        };
+        let json = serde_json::to_string(&durations).unwrap();
+        assert_eq!(json, r#"{"dt":1.5,"ym":18.0}"#);
+        assert_eq!(serde_json::from_str::<Durations>(&json).unwrap(), durations);
+
+        let durations = Durations {
+            dt: -IntervalDT::try_from_dhms(0, 6, 0, 0, 0).unwrap(),
+            ym: -IntervalYM::try_from_ym(0, 3).unwrap(),
+        };
+        let json = serde_json::to_string(&durations).unwrap();
+        assert_eq!(json, r#"{"dt":-0.25,"ym":-3.0}"#);
+        assert_eq!(serde_json::from_str::<Durations>(&json).unwrap(), durations);
+
+        // Round trip within float precision tolerance
+        for interval in [
+            IntervalDT::try_from_dhms(0, 0, 0, 0, 1).unwrap(),
+            IntervalDT::try_from_dhms(1, 2, 3, 4, 5).unwrap(),
+            IntervalDT::try_from_dhms(12345, 23, 59, 59, 999999).unwrap(),
+            IntervalDT::MAX,
+            IntervalDT::MIN,
+        ] {
+            let durations = Durations {
+                dt: interval,
+                ym: IntervalYM::ZERO,
+            };
+            let json = serde_json::to_string(&durations).unwrap();
+            let decoded: Durations = serde_json::from_str(&json).unwrap();
+            assert!((decoded.dt.usecs() - interval.usecs()).abs() <= 16);
+            assert_eq!(decoded.ym, IntervalYM::ZERO);
+        }
+
+        let durations = Durations {
+            dt: IntervalDT::ZERO,
+            ym: IntervalYM::MAX,
+        };
+        let json = serde_json::to_string(&durations).unwrap();
+        assert_eq!(serde_json::from_str::<Durations>(&json).unwrap(), durations);
+
+        // Rounds to the nearest month
+        let decoded: Durations = serde_json::from_str(r#"{"dt":0.0,"ym":1.6}"#).unwrap();
+        assert_eq!(decoded.ym, IntervalYM::try_from_months(2).unwrap());
+
+        // Out of range
+        assert!(serde_json::from_str::<Durations>(r#"{"dt":100000001.0,"ym":0.0}"#).is_err());
+        assert!(serde_json::from_str::<Durations>(r#"{"dt":0.0,"ym":1e100}"#).is_err());
+    }
 }
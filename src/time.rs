@@ -6,11 +6,15 @@ use crate::common::{
 };
 use crate::error::{Error, Result};
 use crate::format::{Formatter, LazyFormat, NaiveDateTime};
-use crate::{Date, DateTime, IntervalDT, Timestamp};
+use crate::util::StrExt;
+use crate::{Date, DateTime, IntervalDT, IntervalYM, Timestamp};
 use std::cmp::Ordering;
 use std::convert::TryFrom;
 use std::fmt::Display;
 
+/// Microseconds of the unit of each fractional seconds precision.
+const PRECISION_FACTOR: [i64; 7] = [1_000_000, 100_000, 10_000, 1_000, 100, 10, 1];
+
 /// Time represents a valid time of day.
 #[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
 #[repr(transparent)]
@@ -152,6 +156,12 @@ impl Time {
         Ok(LazyFormat::new(fmt, self))
     }
 
+    /// Formats `Time` into a new `String` by given formatter, which can be reused across calls.
+    #[inline]
+    pub fn to_string_with(self, fmt: &Formatter) -> Result<String> {
+        fmt.format_to_string(self)
+    }
+
     /// Parses `Time` from given string and format.
     #[inline]
     pub fn parse<S1: AsRef<str>, S2: AsRef<str>>(input: S1, fmt: S2) -> Result<Self> {
@@ -159,6 +169,36 @@ impl Time {
         fmt.parse(input)
     }
 
+    /// Rounds `Time` to the given fractional seconds precision, which must be in `0..=6`.
+    ///
+    /// Since `Time` can not carry a day, the result saturates at `Time::MAX` if rounding up
+    /// would overflow into the next day, e.g. `23:59:59.999999` with precision 0.
+    #[inline]
+    pub const fn round_to_precision(self, precision: u8) -> Result<Time> {
+        if precision > 6 {
+            return Err(Error::InvalidNumber);
+        }
+
+        let factor = PRECISION_FACTOR[precision as usize];
+        let usecs = (self.0 + factor / 2) / factor * factor;
+        if usecs >= USECONDS_PER_DAY {
+            Ok(Time::MAX)
+        } else {
+            Ok(Time(usecs))
+        }
+    }
+
+    /// Truncates `Time` to the given fractional seconds precision, which must be in `0..=6`.
+    #[inline]
+    pub const fn truncate_to_precision(self, precision: u8) -> Result<Time> {
+        if precision > 6 {
+            return Err(Error::InvalidNumber);
+        }
+
+        let factor = PRECISION_FACTOR[precision as usize];
+        Ok(Time(self.0 / factor * factor))
+    }
+
     /// `Time` subtracts `Time`
     #[inline]
     pub const fn sub_time(self, time: Time) -> IntervalDT {
@@ -176,6 +216,17 @@ impl Time {
         }
     }
 
+    /// `Time` adds `IntervalYM`, which is meaningless for a time of day, so this always returns
+    /// `Error::InvalidInterval`.
+    ///
+    /// It lets generic code dispatching on interval types get a clear error.
+    #[inline]
+    pub fn add_interval_ym(self, _interval: IntervalYM) -> Result<Time> {
+        Err(Error::InvalidInterval(
+            "cannot add a year-month interval to a time".try_to_string()?,
+        ))
+    }
+
     /// `Time` subtracts `IntervalDT`
     #[inline]
     pub const fn sub_interval_dt(self, interval: IntervalDT) -> Time {
@@ -296,6 +347,16 @@ impl DateTime for Time {
         Some(remain_time as f64 / USECONDS_PER_SECOND as f64)
     }
 
+    #[inline(always)]
+    fn microsecond(&self) -> Option<u32> {
+        Some((self.usecs() % USECONDS_PER_SECOND) as u32)
+    }
+
+    #[inline]
+    fn epoch_seconds(&self) -> Option<f64> {
+        Some(self.usecs() as f64 / USECONDS_PER_SECOND as f64)
+    }
+
     #[inline(always)]
     fn date(&self) -> Option<Date> {
         None
@@ -764,6 +825,12 @@ mod tests {
             (sec as f64) + (usec as f64) / 1_000_000f64,
             time.second().unwrap()
         );
+        assert_eq!(usec / 1000, time.millisecond().unwrap());
+        assert_eq!(usec, time.microsecond().unwrap());
+        assert_eq!(
+            (hour * 3600 + min * 60 + sec) as f64 + (usec as f64) / 1_000_000f64,
+            time.epoch_seconds().unwrap()
+        );
 
         assert!(time.year().is_none());
         assert!(time.month().is_none());
@@ -777,6 +844,124 @@ mod tests {
         test_extract(12, 0, 0, 0);
         test_extract(16, 34, 59, 356);
         test_extract(23, 59, 59, 999999);
+        test_extract(8, 30, 15, 123456);
+        test_extract(8, 30, 15, 999);
+        test_extract(8, 30, 15, 1000);
+    }
+
+    #[test]
+    fn test_time_to_string_with() {
+        let time = Time::try_from_hms(12, 30, 5, 123456).unwrap();
+        for fmt in ["HH24:MI:SS.FF", "HH12:MI AM", "THH24:MI:SS.FF3"] {
+            let formatter = Formatter::try_new(fmt).unwrap();
+            assert_eq!(
+                time.to_string_with(&formatter).unwrap(),
+                time.format(fmt).unwrap().to_string()
+            );
+        }
+        assert!(time
+            .to_string_with(&Formatter::try_new("YYYY").unwrap())
+            .is_err());
+    }
+
+    #[test]
+    fn test_time_round_truncate_to_precision() {
+        let time = Time::try_from_hms(12, 34, 56, 456789).unwrap();
+        let expected_round = [
+            (12, 34, 56, 0),
+            (12, 34, 56, 500000),
+            (12, 34, 56, 460000),
+            (12, 34, 56, 457000),
+            (12, 34, 56, 456800),
+            (12, 34, 56, 456790),
+            (12, 34, 56, 456789),
+        ];
+        let expected_trunc = [
+            (12, 34, 56, 0),
+            (12, 34, 56, 400000),
+            (12, 34, 56, 450000),
+            (12, 34, 56, 456000),
+            (12, 34, 56, 456700),
+            (12, 34, 56, 456780),
+            (12, 34, 56, 456789),
+        ];
+        for p in 0..=6u8 {
+            assert_eq!(
+                time.round_to_precision(p).unwrap().extract(),
+                expected_round[p as usize]
+            );
+            assert_eq!(
+                time.truncate_to_precision(p).unwrap().extract(),
+                expected_trunc[p as usize]
+            );
+        }
+
+        // Carry into seconds, minutes and hours
+        let time = Time::try_from_hms(12, 59, 59, 500000).unwrap();
+        assert_eq!(
+            time.round_to_precision(0).unwrap(),
+            Time::try_from_hms(13, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            time.truncate_to_precision(0).unwrap(),
+            Time::try_from_hms(12, 59, 59, 0).unwrap()
+        );
+        let time = Time::try_from_hms(12, 59, 59, 499999).unwrap();
+        assert_eq!(
+            time.round_to_precision(0).unwrap(),
+            Time::try_from_hms(12, 59, 59, 0).unwrap()
+        );
+
+        // Saturates at Time::MAX
+        for p in 0..=6u8 {
+            assert_eq!(Time::MAX.round_to_precision(p).unwrap(), Time::MAX);
+        }
+        assert_eq!(
+            Time::try_from_hms(23, 59, 59, 500000)
+                .unwrap()
+                .round_to_precision(0)
+                .unwrap(),
+            Time::MAX
+        );
+        assert_eq!(
+            Time::try_from_hms(23, 59, 59, 499999)
+                .unwrap()
+                .round_to_precision(0)
+                .unwrap(),
+            Time::try_from_hms(23, 59, 59, 0).unwrap()
+        );
+        assert_eq!(
+            Time::MAX.truncate_to_precision(0).unwrap(),
+            Time::try_from_hms(23, 59, 59, 0).unwrap()
+        );
+
+        assert_eq!(Time::ZERO.round_to_precision(0).unwrap(), Time::ZERO);
+        assert_eq!(Time::ZERO.truncate_to_precision(0).unwrap(), Time::ZERO);
+
+        // Invalid precision
+        assert!(time.round_to_precision(7).is_err());
+        assert!(time.truncate_to_precision(7).is_err());
+    }
+
+    #[test]
+    fn test_time_add_interval_ym() {
+        let time = Time::try_from_hms(12, 30, 0, 0).unwrap();
+        for interval in [
+            IntervalYM::ZERO,
+            IntervalYM::try_from_ym(1, 6).unwrap(),
+            -IntervalYM::try_from_ym(0, 1).unwrap(),
+            IntervalYM::MAX,
+        ] {
+            let err = time.add_interval_ym(interval).unwrap_err();
+            assert_eq!(
+                err,
+                Error::InvalidInterval("cannot add a year-month interval to a time".to_string())
+            );
+            assert_eq!(
+                err.to_string(),
+                "sqldatetime: invalid_interval: cannot add a year-month interval to a time"
+            );
+        }
     }
 
     #[test]
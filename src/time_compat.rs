@@ -0,0 +1,167 @@
+//! Conversions between the SQL date time types and the `time` crate types.
+
+use crate::error::Error;
+use crate::{Date, Time, Timestamp};
+use std::convert::TryFrom;
+use time_crate::{Date as TimeDate, PrimitiveDateTime, Time as TimeTime};
+
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl TryFrom<TimeDate> for Date {
+    type Error = Error;
+
+    /// Converts a `time::Date`, failing with `DateOutOfRange` if the year is not between 1 and
+    /// 9999, since the `time` crate supports a wider year range.
+    #[inline]
+    fn try_from(date: TimeDate) -> Result<Self, Self::Error> {
+        Date::try_from_julian_day_number(date.to_julian_day())
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl From<Date> for TimeDate {
+    #[inline]
+    fn from(date: Date) -> Self {
+        // Years 1 to 9999 are always valid in the `time` crate
+        TimeDate::from_julian_day(date.to_julian_day_number()).unwrap()
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl From<TimeTime> for Time {
+    /// Converts a `time::Time`, truncating the nanoseconds to microseconds.
+    #[inline]
+    fn from(time: TimeTime) -> Self {
+        let (hour, minute, sec, usec) = time.as_hms_micro();
+        // The components of `time::Time` are always in range
+        Time::try_from_hms(hour as u32, minute as u32, sec as u32, usec).unwrap()
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl From<Time> for TimeTime {
+    #[inline]
+    fn from(time: Time) -> Self {
+        let (hour, minute, sec, usec) = time.extract();
+        TimeTime::from_hms_micro(hour as u8, minute as u8, sec as u8, usec).unwrap()
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl TryFrom<PrimitiveDateTime> for Timestamp {
+    type Error = Error;
+
+    /// Converts a `time::PrimitiveDateTime`, truncating the nanoseconds to microseconds.
+    /// Fails with `DateOutOfRange` if the year is not between 1 and 9999.
+    #[inline]
+    fn try_from(datetime: PrimitiveDateTime) -> Result<Self, Self::Error> {
+        let date = Date::try_from(datetime.date())?;
+        Ok(Timestamp::new(date, Time::from(datetime.time())))
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl From<Timestamp> for PrimitiveDateTime {
+    #[inline]
+    fn from(timestamp: Timestamp) -> Self {
+        let (date, time) = timestamp.extract();
+        PrimitiveDateTime::new(date.into(), time.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time_crate::Month;
+
+    fn time_date(year: i32, month: Month, day: u8) -> TimeDate {
+        TimeDate::from_calendar_date(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_time_date() {
+        for (year, month, day) in [(1, 1, 1), (1970, 1, 1), (2000, 2, 29), (9999, 12, 31)] {
+            let date = Date::try_from_ymd(year, month, day).unwrap();
+            let converted = TimeDate::from(date);
+            assert_eq!(
+                (
+                    converted.year(),
+                    converted.month() as u32,
+                    converted.day() as u32
+                ),
+                (year, month, day)
+            );
+            assert_eq!(Date::try_from(converted).unwrap(), date);
+        }
+
+        // Out of range
+        assert_eq!(
+            Date::try_from(time_date(0, Month::December, 31)).unwrap_err(),
+            Error::DateOutOfRange
+        );
+        assert_eq!(
+            Date::try_from(time_date(-9999, Month::January, 1)).unwrap_err(),
+            Error::DateOutOfRange
+        );
+        assert_eq!(
+            Date::try_from(TimeDate::MIN).unwrap_err(),
+            Error::DateOutOfRange
+        );
+        assert_eq!(
+            Date::try_from(time_date(9999, Month::December, 31)).unwrap(),
+            Date::MAX
+        );
+    }
+
+    #[test]
+    fn test_time_time() {
+        let time = Time::try_from_hms(23, 59, 59, 999999).unwrap();
+        assert_eq!(
+            TimeTime::from(time),
+            TimeTime::from_hms_micro(23, 59, 59, 999999).unwrap()
+        );
+        assert_eq!(Time::from(TimeTime::from(time)), time);
+        assert_eq!(Time::from(TimeTime::MIDNIGHT), Time::ZERO);
+
+        // Nanoseconds are truncated
+        assert_eq!(
+            Time::from(TimeTime::from_hms_nano(12, 30, 5, 123456999).unwrap()),
+            Time::try_from_hms(12, 30, 5, 123456).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_time_primitive_date_time() {
+        let epoch = Timestamp::new(Date::try_from_ymd(1970, 1, 1).unwrap(), Time::ZERO);
+        for timestamp in [Timestamp::MIN, Timestamp::MAX, epoch] {
+            let datetime = PrimitiveDateTime::from(timestamp);
+            assert_eq!(Timestamp::try_from(datetime).unwrap(), timestamp);
+        }
+
+        let datetime = PrimitiveDateTime::new(
+            time_date(2021, Month::October, 24),
+            TimeTime::from_hms_nano(1, 2, 3, 999999999).unwrap(),
+        );
+        assert_eq!(
+            Timestamp::try_from(datetime).unwrap(),
+            Timestamp::new(
+                Date::try_from_ymd(2021, 10, 24).unwrap(),
+                Time::try_from_hms(1, 2, 3, 999999).unwrap()
+            )
+        );
+
+        // Out of range
+        let datetime = PrimitiveDateTime::new(time_date(-1, Month::January, 1), TimeTime::MIDNIGHT);
+        assert_eq!(
+            Timestamp::try_from(datetime).unwrap_err(),
+            Error::DateOutOfRange
+        );
+        let datetime = PrimitiveDateTime::new(
+            time_date(0, Month::December, 31),
+            TimeTime::from_hms(23, 59, 59).unwrap(),
+        );
+        assert_eq!(
+            Timestamp::try_from(datetime).unwrap_err(),
+            Error::DateOutOfRange
+        );
+    }
+}
@@ -4,10 +4,42 @@ use crate::common::*;
 use crate::error::{Error, Result};
 use crate::format::{Formatter, LazyFormat, NaiveDateTime};
 use crate::local::Local;
-use crate::{Date, DateTime, IntervalDT, IntervalYM, Round, Time, Trunc};
+use crate::util::StrExt;
+use crate::{
+    Date, DateTime, DateTimeUnit, IntervalDT, IntervalYM, MonthOverflow, Round, Sign, Time, Trunc,
+};
 use std::cmp::Ordering;
 use std::convert::TryFrom;
 use std::fmt::Display;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// A coarse bucket of the time between two timestamps for display, e.g. "3 days ago", see
+/// [`Timestamp::humanize_since`]. The rendering is left to the caller for localization.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RelativeTime {
+    /// Less than a minute in either direction
+    JustNow,
+    /// 1..=59 minutes ago
+    MinutesAgo(u32),
+    /// 1..=23 hours ago
+    HoursAgo(u32),
+    /// 1 day or more ago, but less than a calendar month
+    DaysAgo(u32),
+    /// 1..=11 calendar months ago
+    MonthsAgo(u32),
+    /// 1 calendar year or more ago
+    YearsAgo(u32),
+    /// In 1..=59 minutes
+    InMinutes(u32),
+    /// In 1..=23 hours
+    InHours(u32),
+    /// In 1 day or more, but less than a calendar month
+    InDays(u32),
+    /// In 1..=11 calendar months
+    InMonths(u32),
+    /// In 1 calendar year or more
+    InYears(u32),
+}
 
 /// Timestamp represents a valid time at a valid Gregorian date.
 ///
@@ -72,6 +104,65 @@ impl Timestamp {
         }
     }
 
+    /// Converts `Timestamp` to `Date` by truncating the time part, i.e. to the midnight of the day.
+    #[inline]
+    pub fn to_date_trunc(self) -> Date {
+        self.date()
+    }
+
+    /// Converts `Timestamp` to `Date` by rounding to the nearest day, i.e. the next day
+    /// if the time is at or after noon.
+    #[inline]
+    pub fn to_date_round(self) -> Result<Date> {
+        let (date, time) = self.extract();
+        if time.extract().0 >= 12 {
+            date.add_days(1)
+        } else {
+            Ok(date)
+        }
+    }
+
+    /// Rounds `Timestamp` to the nearest day like [`Round::round_day`], except that exactly noon
+    /// rounds to the even day, which avoids the upward bias of rounding half up in aggregates.
+    ///
+    /// Days are numbered from `0001-01-01` as day 1, so e.g. `2021-01-02` is an even day.
+    #[inline]
+    pub fn round_day_half_even(self) -> Result<Timestamp> {
+        let (date, time) = self.extract();
+        let round_up = match time.usecs().cmp(&(USECONDS_PER_DAY / 2)) {
+            Ordering::Less => false,
+            Ordering::Greater => true,
+            // The day number is odd if the days since `0001-01-01` is even
+            Ordering::Equal => (date.days() - Date::MIN.days()) % 2 == 0,
+        };
+        let date = if round_up { date.add_days(1)? } else { date };
+        Ok(date.and_zero_time())
+    }
+
+    /// Rounds `Timestamp` to the nearest boundary of buckets of size `bucket` starting at `origin`,
+    /// e.g. to the nearest 15 minutes. Exactly halfway rounds up to the later boundary.
+    ///
+    /// Returns `InvalidInterval` error if `bucket` is not positive.
+    #[inline]
+    pub fn round_to(self, bucket: IntervalDT, origin: Timestamp) -> Result<Timestamp> {
+        let size = bucket.usecs();
+        if size <= 0 {
+            return Err(Error::InvalidInterval(
+                "bucket size must be positive".try_to_string()?,
+            ));
+        }
+        let rem = (self.0 - origin.0).rem_euclid(size);
+        let usecs = if rem >= size - rem {
+            (self.0 - rem).checked_add(size)
+        } else {
+            Some(self.0 - rem)
+        };
+        match usecs {
+            Some(usecs) => Timestamp::try_from_usecs(usecs),
+            None => Err(Error::DateOutOfRange),
+        }
+    }
+
     /// Gets the microseconds from Unix Epoch of `Timestamp`.
     #[inline(always)]
     pub const fn usecs(self) -> i64 {
@@ -95,6 +186,12 @@ impl Timestamp {
         Ok(LazyFormat::new(fmt, self))
     }
 
+    /// Formats `Timestamp` into a new `String` by given formatter, which can be reused across calls.
+    #[inline]
+    pub fn to_string_with(self, fmt: &Formatter) -> Result<String> {
+        fmt.format_to_string(self)
+    }
+
     /// Parses `Timestamp` from given string and format.
     #[inline]
     pub fn parse<S1: AsRef<str>, S2: AsRef<str>>(input: S1, fmt: S2) -> Result<Self> {
@@ -102,6 +199,311 @@ impl Timestamp {
         fmt.parse(input)
     }
 
+    /// Parses `Timestamp` from given string and format, returns `default` if the input cannot be
+    /// parsed.
+    #[inline]
+    pub fn parse_or<S1: AsRef<str>, S2: AsRef<str>>(input: S1, fmt: S2, default: Self) -> Self {
+        Timestamp::parse(input, fmt).unwrap_or(default)
+    }
+
+    /// Parses `Timestamp` from given string and format, returns the result of `f` if the input
+    /// cannot be parsed.
+    #[inline]
+    pub fn parse_or_else<S1: AsRef<str>, S2: AsRef<str>, F: FnOnce() -> Self>(
+        input: S1,
+        fmt: S2,
+        f: F,
+    ) -> Self {
+        Timestamp::parse(input, fmt).unwrap_or_else(|_| f())
+    }
+
+    /// Parses `Timestamp` from given string and format, returns the local system timestamp if the
+    /// input cannot be parsed.
+    #[inline]
+    pub fn parse_or_now<S1: AsRef<str>, S2: AsRef<str>>(input: S1, fmt: S2) -> Result<Self> {
+        match Timestamp::parse(input, fmt) {
+            Ok(timestamp) => Ok(timestamp),
+            Err(_) => Timestamp::now(),
+        }
+    }
+
+    /// Parses `Timestamp` from given string by trying each of the given formats in order,
+    /// returns the first successfully parsed result.
+    #[inline]
+    pub fn parse_any<S: AsRef<str>>(input: S, formats: &[&str]) -> Result<Self> {
+        for fmt in formats {
+            let fmt = Formatter::try_new(fmt)?;
+            if let Ok(timestamp) = fmt.parse(input.as_ref()) {
+                return Ok(timestamp);
+            }
+        }
+
+        Err(Error::ParseError(try_format!(
+            "the input {} does not match any of the formats",
+            input.as_ref()
+        )?))
+    }
+
+    /// Parses `Timestamp` from given ISO 8601 string, the date part can be in any of the forms:
+    /// - calendar date: `YYYY-MM-DD`, e.g. `2021-06-01`
+    /// - ordinal date: `YYYY-DDD`, e.g. `2021-152`
+    /// - week date: `YYYY-Www-D` or `YYYY-Www`, e.g. `2021-W22-2`
+    ///
+    /// The date can be followed by an optional time part `HH:MI:SS[.FF][Z]`,
+    /// separated by `T` or a space. A trailing `UTC` or `GMT` is accepted like `Z`. The time can
+    /// instead end with an offset `±HH:MM`/`±HHMM`/`±HH`, and is then converted to UTC, e.g.
+    /// `2021-06-01T12:30:05+08:00` is `2021-06-01 04:30:05`.
+    #[inline]
+    pub fn parse_iso8601<S: AsRef<str>>(input: S) -> Result<Self> {
+        #[inline]
+        fn parse_digits(s: &[u8]) -> Option<u32> {
+            if s.is_empty() || !s.iter().all(|ch| ch.is_ascii_digit()) {
+                return None;
+            }
+            Some(s.iter().fold(0, |n, &ch| n * 10 + (ch - b'0') as u32))
+        }
+
+        let input = input.as_ref();
+        let s = input.as_bytes();
+        let date_len = s
+            .iter()
+            .position(|&ch| ch == b'T' || ch == b' ')
+            .unwrap_or(s.len());
+        let date_str = &s[..date_len];
+
+        let invalid = || -> Result<Self> {
+            Err(Error::ParseError(try_format!(
+                "the input {} is not a valid ISO 8601 date",
+                input
+            )?))
+        };
+
+        let year = match (date_str.get(4), date_str.get(..4).and_then(parse_digits)) {
+            (Some(b'-'), Some(year)) => year as i32,
+            _ => return invalid(),
+        };
+
+        let date = match (date_len, date_str.get(5)) {
+            (8, Some(b'W')) => match parse_digits(&date_str[6..8]) {
+                Some(week) => Date::try_from_iso_week_date(year, week, 1)?,
+                None => return invalid(),
+            },
+            (10, Some(b'W')) => match (
+                parse_digits(&date_str[6..8]),
+                date_str[8],
+                parse_digits(&date_str[9..]),
+            ) {
+                (Some(week), b'-', Some(week_day)) => {
+                    Date::try_from_iso_week_date(year, week, week_day)?
+                }
+                _ => return invalid(),
+            },
+            (8, _) => match parse_digits(&date_str[5..]) {
+                Some(ordinal) => Date::try_from_ordinal(year, ordinal)?,
+                None => return invalid(),
+            },
+            (10, _) => match (
+                parse_digits(&date_str[5..7]),
+                date_str[7],
+                parse_digits(&date_str[8..]),
+            ) {
+                (Some(month), b'-', Some(day)) => Date::try_from_ymd(year, month, day)?,
+                _ => return invalid(),
+            },
+            _ => return invalid(),
+        };
+
+        if date_len == s.len() {
+            return Ok(date.and_zero_time());
+        }
+
+        let time_str = &input[date_len + 1..];
+        // An offset can't follow a UTC designator
+        let (time_str, offset_minutes) = match time_str.rfind(['+', '-']) {
+            Some(pos) if !time_str[..pos].ends_with(|ch: char| ch.is_ascii_alphabetic()) => {
+                match parse_utc_offset(&time_str.as_bytes()[pos..]) {
+                    Some(minutes) => (&time_str[..pos], minutes),
+                    None => return invalid(),
+                }
+            }
+            Some(_) => return invalid(),
+            None => (time_str, 0),
+        };
+        let time: Time = Formatter::try_new("THH24:MI:SS.FF")?.parse(time_str)?;
+        Timestamp::try_from_usecs(
+            date.and_time(time).usecs() - offset_minutes * USECONDS_PER_MINUTE,
+        )
+    }
+
+    /// Formats `Timestamp` as a fixed-width `YYYYMMDDHHMMSSffffff` string, e.g.
+    /// `20210601123005000001`, which sorts as a string in the same order as the timestamps for
+    /// the full range of `Timestamp`.
+    #[inline]
+    pub fn to_sortable_string(self) -> Result<String> {
+        let (date, time) = self.extract();
+        let (year, month, day) = date.extract();
+        let (hour, minute, sec, usec) = time.extract();
+        try_format!(
+            "{:04}{:02}{:02}{:02}{:02}{:02}{:06}",
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            sec,
+            usec
+        )
+    }
+
+    /// Parses `Timestamp` from a `YYYYMMDDHHMMSSffffff` string produced by
+    /// [`to_sortable_string`].
+    ///
+    /// [`to_sortable_string`]: Timestamp::to_sortable_string
+    #[inline]
+    pub fn from_sortable_string<S: AsRef<str>>(input: S) -> Result<Self> {
+        let s = input.as_ref().as_bytes();
+        if s.len() != 20 || !s.iter().all(|ch| ch.is_ascii_digit()) {
+            return Err(Error::ParseError(try_format!(
+                "the input {} is not a valid sortable timestamp",
+                input.as_ref()
+            )?));
+        }
+        let number = |s: &[u8]| s.iter().fold(0, |n, &ch| n * 10 + (ch - b'0') as u32);
+        let date = Date::try_from_ymd(number(&s[..4]) as i32, number(&s[4..6]), number(&s[6..8]))?;
+        date.and_hms(
+            number(&s[8..10]),
+            number(&s[10..12]),
+            number(&s[12..14]),
+            number(&s[14..]),
+        )
+    }
+
+    /// Parses `Timestamp` from given ISO 8601 basic format string without separators, i.e.
+    /// `YYYYMMDD` optionally followed by the time `THHMMSS[.FF]` and a UTC designator `Z` or an
+    /// offset `±HH:MM`/`±HHMM`/`±HH`, e.g. `20210102T030405.123456Z` or `20210102T030405+0800`.
+    ///
+    /// The fraction is truncated to microseconds, and `,` is accepted in place of `.`. A time
+    /// with an offset is converted to UTC like [`parse_iso8601`], e.g. `20210102T030405+0800` is
+    /// `2021-01-01 19:04:05`.
+    ///
+    /// [`parse_iso8601`]: Timestamp::parse_iso8601
+    #[inline]
+    pub fn parse_iso8601_basic<S: AsRef<str>>(input: S) -> Result<Self> {
+        #[inline]
+        fn parse_digits(s: Option<&[u8]>) -> Option<u32> {
+            match s {
+                Some(s) if !s.is_empty() && s.iter().all(|ch| ch.is_ascii_digit()) => {
+                    Some(s.iter().fold(0, |n, &ch| n * 10 + (ch - b'0') as u32))
+                }
+                _ => None,
+            }
+        }
+
+        let input = input.as_ref();
+        let s = input.as_bytes();
+        let invalid = || -> Result<Self> {
+            Err(Error::ParseError(try_format!(
+                "the input {} is not a valid ISO 8601 basic date",
+                input
+            )?))
+        };
+
+        let date = match (
+            parse_digits(s.get(..4)),
+            parse_digits(s.get(4..6)),
+            parse_digits(s.get(6..8)),
+        ) {
+            (Some(year), Some(month), Some(day)) => Date::try_from_ymd(year as i32, month, day)?,
+            _ => return invalid(),
+        };
+        if s.len() == 8 {
+            return Ok(date.and_zero_time());
+        }
+
+        let (hour, minute, sec) = match (
+            s.get(8),
+            parse_digits(s.get(9..11)),
+            parse_digits(s.get(11..13)),
+            parse_digits(s.get(13..15)),
+        ) {
+            (Some(b'T'), Some(hour), Some(minute), Some(sec)) => (hour, minute, sec),
+            _ => return invalid(),
+        };
+
+        let mut s = &s[15..];
+        let mut usec = 0;
+        if let Some(b'.') | Some(b',') = s.first() {
+            let len = s[1..].iter().take_while(|ch| ch.is_ascii_digit()).count();
+            if len == 0 {
+                return invalid();
+            }
+            let digits = &s[1..1 + len.min(6)];
+            usec = parse_digits(Some(digits)).unwrap_or(0) * 10u32.pow(6 - digits.len() as u32);
+            s = &s[1 + len..];
+        }
+        let ts = date.and_hms(hour, minute, sec, usec)?;
+
+        let offset_minutes = match s {
+            b"" | b"Z" => 0,
+            _ => match parse_utc_offset(s) {
+                Some(minutes) => minutes,
+                None => return invalid(),
+            },
+        };
+        Timestamp::try_from_usecs(ts.usecs() - offset_minutes * USECONDS_PER_MINUTE)
+    }
+
+    /// Formats `Timestamp` as ISO 8601 basic format in UTC, e.g. `20210102T030405.123456Z`.
+    /// The fraction is omitted if it is zero, e.g. `20210102T030405Z`.
+    #[inline]
+    pub fn format_iso8601_basic(self) -> Result<String> {
+        let (date, time) = self.extract();
+        let (year, month, day) = date.extract();
+        let (hour, minute, sec, usec) = time.extract();
+        if usec == 0 {
+            try_format!(
+                "{:04}{:02}{:02}T{:02}{:02}{:02}Z",
+                year,
+                month,
+                day,
+                hour,
+                minute,
+                sec
+            )
+        } else {
+            try_format!(
+                "{:04}{:02}{:02}T{:02}{:02}{:02}.{:06}Z",
+                year,
+                month,
+                day,
+                hour,
+                minute,
+                sec,
+                usec
+            )
+        }
+    }
+
+    /// Formats `Timestamp` as ISO 8601 ordinal date with time, e.g. `2021-152T12:30:00`.
+    #[inline]
+    pub fn format_iso8601_ordinal(self) -> Result<String> {
+        try_format!("{}", self.format("YYYY-DDDTHH24:MI:SS.FF")?)
+    }
+
+    /// Formats `Timestamp` as ISO 8601 week date with time, e.g. `2021-W22-2T12:30:00`.
+    #[inline]
+    pub fn format_iso8601_week(self) -> Result<String> {
+        let (date, time) = self.extract();
+        let (iso_year, week) = date.iso_year_week();
+        try_format!(
+            "{:04}-W{:02}-{}T{}",
+            iso_year,
+            week,
+            date.iso_day_of_week(),
+            time.format("THH24:MI:SS.FF")?
+        )
+    }
+
     /// Creates a `Timestamp` from the given microseconds from Unix Epoch
     #[inline]
     pub const fn try_from_usecs(usecs: i64) -> Result<Self> {
@@ -112,13 +514,142 @@ impl Timestamp {
         }
     }
 
+    /// Parses a `Timestamp` from a string of seconds since Unix Epoch, e.g. `"1655521445"` or
+    /// `"@1655521445"` as emitted by some tools.
+    ///
+    /// A leading sign is allowed for times before Unix Epoch, and a fractional part for
+    /// sub-seconds, which is truncated to microseconds, e.g. `"-1.5"` is
+    /// `1969-12-31 23:59:58.500000`.
+    #[inline]
+    pub fn try_from_epoch_str<S: AsRef<str>>(input: S) -> Result<Self> {
+        let s = input.as_ref().trim().as_bytes();
+        let s = s.strip_prefix(b"@").unwrap_or(s);
+        let (negative, s) = match s.split_first() {
+            Some((b'-', rem)) => (true, rem),
+            Some((b'+', rem)) => (false, rem),
+            _ => (false, s),
+        };
+        let (secs, fraction) = match s.iter().position(|&ch| ch == b'.') {
+            Some(dot) => (&s[..dot], &s[dot + 1..]),
+            None => (s, &s[s.len()..]),
+        };
+        if secs.is_empty()
+            || !secs.iter().all(u8::is_ascii_digit)
+            || !fraction.iter().all(u8::is_ascii_digit)
+        {
+            return Err(Error::ParseError(
+                "invalid seconds since Unix Epoch".try_to_string()?,
+            ));
+        }
+
+        let mut usecs = 0i64;
+        for &ch in secs {
+            usecs = usecs
+                .checked_mul(10)
+                .and_then(|u| u.checked_add((ch - b'0') as i64))
+                .ok_or(Error::DateOutOfRange)?;
+        }
+        usecs = usecs
+            .checked_mul(USECONDS_PER_SECOND)
+            .ok_or(Error::DateOutOfRange)?;
+        let mut factor = USECONDS_PER_SECOND;
+        for &ch in fraction.iter().take(6) {
+            factor /= 10;
+            usecs = usecs
+                .checked_add((ch - b'0') as i64 * factor)
+                .ok_or(Error::DateOutOfRange)?;
+        }
+
+        Timestamp::try_from_usecs(if negative { -usecs } else { usecs })
+    }
+
+    /// Creates a `Timestamp` from a packed `YYYYMMDDHHMMSS` integer, e.g. `20211024123045`
+    /// for `2021-10-24 12:30:45`.
+    #[inline]
+    pub fn try_from_packed_i64(packed: i64) -> Result<Self> {
+        if packed < 0 {
+            return Err(Error::DateOutOfRange);
+        }
+        let date = i32::try_from(packed / 1_000_000).map_err(|_| Error::DateOutOfRange)?;
+        let time = packed % 1_000_000;
+        Date::try_from_packed_i32(date)?.and_hms(
+            (time / 10000) as u32,
+            (time / 100 % 100) as u32,
+            (time % 100) as u32,
+            0,
+        )
+    }
+
+    /// Gets the packed `YYYYMMDDHHMMSS` integer of this `Timestamp`, e.g. `20211024123045`
+    /// for `2021-10-24 12:30:45`. The fractional seconds are truncated.
+    #[inline]
+    pub fn to_packed_i64(self) -> i64 {
+        let (date, time) = self.extract();
+        let (hour, minute, sec, _) = time.extract();
+        date.to_packed_i32() as i64 * 1_000_000 + (hour * 10000 + minute * 100 + sec) as i64
+    }
+
+    /// Creates a `Timestamp` from the given `SystemTime`.
+    ///
+    /// The nanoseconds are truncated to microseconds, i.e. rounded towards the earlier time.
+    #[inline]
+    pub fn try_from_system_time(t: SystemTime) -> Result<Self> {
+        let usecs = match t.duration_since(UNIX_EPOCH) {
+            Ok(duration) => {
+                i64::try_from(duration.as_micros()).map_err(|_| Error::DateOutOfRange)?
+            }
+            Err(e) => {
+                let duration = e.duration();
+                let mut usecs =
+                    i64::try_from(duration.as_micros()).map_err(|_| Error::DateOutOfRange)?;
+                if duration.subsec_nanos() % 1000 != 0 {
+                    usecs += 1;
+                }
+                -usecs
+            }
+        };
+        Timestamp::try_from_usecs(usecs)
+    }
+
+    /// Converts `Timestamp` to `SystemTime`.
+    ///
+    /// # Panics
+    /// Panics if the timestamp cannot be represented by `SystemTime` on the current platform.
+    #[inline]
+    pub fn to_system_time(self) -> SystemTime {
+        let usecs = self.usecs();
+        if usecs.is_negative() {
+            UNIX_EPOCH - Duration::from_micros(usecs.unsigned_abs())
+        } else {
+            UNIX_EPOCH + Duration::from_micros(usecs as u64)
+        }
+    }
+
+    /// Truncates `Timestamp` to the start of the local day in the zone with the given fixed offset
+    /// from UTC in minutes, e.g. `330` for `+05:30`.
+    ///
+    /// The timestamp is treated as UTC, so the result is the UTC timestamp of the local midnight.
+    #[inline]
+    pub const fn trunc_day_in_offset(self, offset_minutes: i32) -> Result<Timestamp> {
+        let offset = offset_minutes as i64 * USECONDS_PER_MINUTE;
+        let local = self.usecs() + offset;
+        let midnight = local.div_euclid(USECONDS_PER_DAY) * USECONDS_PER_DAY;
+        Timestamp::try_from_usecs(midnight - offset)
+    }
+
     /// `Timestamp` adds `IntervalDT`
+    ///
+    /// Returns `DateUnderflow` error if the result is before the minimum value, or
+    /// `DateOverflow` error if it is after the maximum value.
     #[inline]
     pub const fn add_interval_dt(self, interval: IntervalDT) -> Result<Timestamp> {
         let result = self.usecs().checked_add(interval.usecs());
         match result {
-            Some(ts) => Timestamp::try_from_usecs(ts),
-            None => Err(Error::DateOutOfRange),
+            Some(ts) if ts < TIMESTAMP_MIN => Err(Error::DateUnderflow),
+            Some(ts) if ts > TIMESTAMP_MAX => Err(Error::DateOverflow),
+            Some(ts) => Ok(unsafe { Timestamp::from_usecs_unchecked(ts) }),
+            None if interval.usecs() < 0 => Err(Error::DateUnderflow),
+            None => Err(Error::DateOverflow),
         }
     }
 
@@ -133,6 +664,32 @@ impl Timestamp {
         ))
     }
 
+    /// `Timestamp` adds months, keeping the time, and clamping the day to the end of the
+    /// resulting month as [`Date::add_months`].
+    #[inline]
+    pub fn add_months(self, months: i32) -> Result<Timestamp> {
+        self.add_months_with(months, MonthOverflow::ClampToMonthEnd)
+    }
+
+    /// `Timestamp` adds months, keeping the time, and handling a day that does not exist in the
+    /// resulting month as specified by `overflow`.
+    #[inline]
+    pub fn add_months_with(self, months: i32, overflow: MonthOverflow) -> Result<Timestamp> {
+        let (date, time) = self.extract();
+        Ok(Timestamp::new(
+            date.add_months_with(months, overflow)?,
+            time,
+        ))
+    }
+
+    /// `Timestamp` adds months, keeping the time, and preserving the end of month as
+    /// [`Date::add_months2`].
+    #[inline]
+    pub fn add_months_preserve_eom(self, months: i32) -> Result<Timestamp> {
+        let (date, time) = self.extract();
+        Ok(Timestamp::new(date.add_months_preserve_eom(months)?, time))
+    }
+
     /// `Timestamp` add `Time`
     #[inline]
     pub const fn add_time(self, time: Time) -> Result<Timestamp> {
@@ -140,6 +697,11 @@ impl Timestamp {
     }
 
     /// `Timestamp` add days
+    ///
+    /// `Timestamp` has no time zone, so a day is always exactly 24 hours. The fractional days are
+    /// rounded to microseconds, use [`add_calendar_days`] to add whole days exactly.
+    ///
+    /// [`add_calendar_days`]: Timestamp::add_calendar_days
     #[inline]
     pub fn add_days(self, days: f64) -> Result<Timestamp> {
         let microseconds = (days * USECONDS_PER_DAY as f64).round();
@@ -147,6 +709,9 @@ impl Timestamp {
             Err(Error::NumericOverflow)
         } else if microseconds.is_nan() {
             Err(Error::InvalidNumber)
+        } else if microseconds >= i64::MAX as f64 || microseconds < i64::MIN as f64 {
+            // `as i64` saturates, so reject the values out of range of `i64` before casting
+            Err(Error::NumericOverflow)
         } else {
             let result = self.usecs().checked_add(microseconds as i64);
             match result {
@@ -156,6 +721,13 @@ impl Timestamp {
         }
     }
 
+    /// `Timestamp` adds whole calendar days, keeping the time of day unchanged.
+    #[inline]
+    pub fn add_calendar_days(self, days: i32) -> Result<Timestamp> {
+        let (date, time) = self.extract();
+        Ok(Timestamp::new(date.add_days(days)?, time))
+    }
+
     /// `Timestamp` subtracts `Date`
     #[inline]
     pub const fn sub_date(self, date: Date) -> IntervalDT {
@@ -176,7 +748,152 @@ impl Timestamp {
         unsafe { IntervalDT::from_usecs_unchecked(microseconds) }
     }
 
+    /// Checked `Timestamp` subtracts `Timestamp`. Returns `None` if the difference
+    /// is out of range of `IntervalDT`.
+    ///
+    /// The difference between any two valid timestamps is less than 3652059 days,
+    /// which is always within the range of `IntervalDT`, so this returns `None` only
+    /// if that invariant is ever broken.
+    #[inline]
+    pub const fn checked_sub_timestamp(self, timestamp: Timestamp) -> Option<IntervalDT> {
+        match self.usecs().checked_sub(timestamp.usecs()) {
+            Some(usecs) if IntervalDT::is_valid_usecs(usecs) => {
+                Some(unsafe { IntervalDT::from_usecs_unchecked(usecs) })
+            }
+            _ => None,
+        }
+    }
+
+    /// Checks if `self` and `other` differ by at most `tolerance`, e.g. to ignore clock skews.
+    ///
+    /// The sign of `tolerance` is ignored, i.e. its absolute value is used.
+    #[inline]
+    pub const fn approx_eq(self, other: Timestamp, tolerance: IntervalDT) -> bool {
+        (self.usecs() - other.usecs()).abs() <= tolerance.usecs().abs()
+    }
+
+    /// Gets the number of whole weeks from `timestamp` to `self`, i.e. the floor of the elapsed
+    /// time divided by 7 days.
+    #[inline]
+    pub const fn weeks_between(self, timestamp: Timestamp) -> i32 {
+        (self.usecs() - timestamp.usecs()).div_euclid(7 * USECONDS_PER_DAY) as i32
+    }
+
+    /// Gets the number of ISO week boundaries crossed from `timestamp` to `self`, ignoring the
+    /// time of day, see [`Date::iso_weeks_between`].
+    #[inline]
+    pub fn iso_weeks_between(self, timestamp: Timestamp) -> i32 {
+        self.date().iso_weeks_between(timestamp.date())
+    }
+
+    /// Gets the time elapsed from `other` to `self`, decomposed into
+    /// `(sign, day, hour, minute, second, microsecond)`.
+    ///
+    /// The sign is `Negative` if `other` is later than `self`, and all other parts are magnitudes.
+    #[inline]
+    pub const fn elapsed_parts(self, other: Timestamp) -> (Sign, u32, u32, u32, u32, u32) {
+        self.sub_timestamp(other).extract()
+    }
+
+    /// Gets the coarse bucket of the time from `self` to `now`, e.g. `DaysAgo(3)`, or
+    /// `InDays(3)` if `self` is later than `now`.
+    ///
+    /// Each bucket counts whole units, truncated towards zero. Minutes, hours and days are
+    /// computed by microseconds, while months and years are computed by calendar like
+    /// [`diff_in`], so the days bucket is used until a calendar month has passed.
+    ///
+    /// [`diff_in`]: Timestamp::diff_in
+    #[inline]
+    pub fn humanize_since(self, now: Timestamp) -> RelativeTime {
+        let usecs = now.usecs() - self.usecs();
+        let is_past = usecs > 0;
+        let usecs = usecs.unsigned_abs();
+        let months = now.diff_in_months(self).unsigned_abs();
+
+        let bucket = |ago: fn(u32) -> RelativeTime, later: fn(u32) -> RelativeTime, n: u64| {
+            if is_past {
+                ago(n as u32)
+            } else {
+                later(n as u32)
+            }
+        };
+
+        if usecs < USECONDS_PER_MINUTE as u64 {
+            RelativeTime::JustNow
+        } else if usecs < USECONDS_PER_HOUR as u64 {
+            bucket(
+                RelativeTime::MinutesAgo,
+                RelativeTime::InMinutes,
+                usecs / USECONDS_PER_MINUTE as u64,
+            )
+        } else if usecs < USECONDS_PER_DAY as u64 {
+            bucket(
+                RelativeTime::HoursAgo,
+                RelativeTime::InHours,
+                usecs / USECONDS_PER_HOUR as u64,
+            )
+        } else if months == 0 {
+            bucket(
+                RelativeTime::DaysAgo,
+                RelativeTime::InDays,
+                usecs / USECONDS_PER_DAY as u64,
+            )
+        } else if months < MONTHS_PER_YEAR as u64 {
+            bucket(RelativeTime::MonthsAgo, RelativeTime::InMonths, months)
+        } else {
+            bucket(
+                RelativeTime::YearsAgo,
+                RelativeTime::InYears,
+                months / MONTHS_PER_YEAR as u64,
+            )
+        }
+    }
+
+    /// Gets the difference from `other` to `self` in whole units, truncated towards zero.
+    ///
+    /// Years, quarters and months are computed by calendar, e.g. there is 1 month from
+    /// `2021-01-31` to `2021-03-01`, while the other units are computed by microseconds.
+    #[inline]
+    pub fn diff_in(self, other: Timestamp, unit: DateTimeUnit) -> i64 {
+        match unit {
+            DateTimeUnit::Year => self.diff_in_months(other) / MONTHS_PER_YEAR as i64,
+            DateTimeUnit::Quarter => self.diff_in_months(other) / 3,
+            DateTimeUnit::Month => self.diff_in_months(other),
+            DateTimeUnit::Week => self.sub_timestamp(other).usecs() / (USECONDS_PER_DAY * 7),
+            DateTimeUnit::Day => self.sub_timestamp(other).usecs() / USECONDS_PER_DAY,
+            DateTimeUnit::Hour => self.sub_timestamp(other).usecs() / USECONDS_PER_HOUR,
+            DateTimeUnit::Minute => self.sub_timestamp(other).usecs() / USECONDS_PER_MINUTE,
+            DateTimeUnit::Second => self.sub_timestamp(other).usecs() / USECONDS_PER_SECOND,
+            DateTimeUnit::Millisecond => self.sub_timestamp(other).usecs() / 1000,
+            DateTimeUnit::Microsecond => self.sub_timestamp(other).usecs(),
+        }
+    }
+
+    #[inline]
+    fn diff_in_months(self, other: Timestamp) -> i64 {
+        let (date, time) = self.extract();
+        let (other_date, other_time) = other.extract();
+        let (year, month, day) = date.extract();
+        let (other_year, other_month, other_day) = other_date.extract();
+
+        let mut months =
+            (year - other_year) as i64 * MONTHS_PER_YEAR as i64 + month as i64 - other_month as i64;
+
+        // Excludes the last incomplete month
+        let rest = (day, time.usecs());
+        let other_rest = (other_day, other_time.usecs());
+        if months > 0 && rest < other_rest {
+            months -= 1;
+        } else if months < 0 && rest > other_rest {
+            months += 1;
+        }
+        months
+    }
+
     /// `Timestamp` subtracts `IntervalDT`
+    ///
+    /// Returns `DateUnderflow` error if the result is before the minimum value, or
+    /// `DateOverflow` error if it is after the maximum value.
     #[inline]
     pub const fn sub_interval_dt(self, interval: IntervalDT) -> Result<Timestamp> {
         self.add_interval_dt(interval.negate())
@@ -194,6 +911,40 @@ impl Timestamp {
         self.add_days(-days)
     }
 
+    /// Parses the relative keywords `now`, `today`, `yesterday` and `tomorrow`
+    /// (case-insensitive) into a `Timestamp` relative to `reference`, or to the local system
+    /// timestamp if `reference` is `None`.
+    ///
+    /// As in PostgreSQL, `now` is the reference itself, while `today`, `yesterday` and
+    /// `tomorrow` are the midnight of the respective day. Other input returns `ParseError`
+    /// error, so that callers can fall back to parsing by format.
+    #[inline]
+    pub fn parse_relative<S: AsRef<str>>(input: S, reference: Option<Timestamp>) -> Result<Self> {
+        let s = input.as_ref().trim();
+        let days = if s.eq_ignore_ascii_case("now") {
+            None
+        } else if s.eq_ignore_ascii_case("today") {
+            Some(0)
+        } else if s.eq_ignore_ascii_case("yesterday") {
+            Some(-1)
+        } else if s.eq_ignore_ascii_case("tomorrow") {
+            Some(1)
+        } else {
+            return Err(Error::ParseError(try_format!(
+                "the input {} is not a relative date keyword",
+                s
+            )?));
+        };
+        let reference = match reference {
+            Some(ts) => ts,
+            None => Timestamp::now()?,
+        };
+        match days {
+            Some(days) => Ok(reference.extract().0.add_days(days)?.and_zero_time()),
+            None => Ok(reference),
+        }
+    }
+
     /// Get local system timestamp
     #[inline]
     pub fn now() -> Result<Timestamp> {
@@ -204,33 +955,118 @@ impl Timestamp {
         ))
     }
 
-    /// Gets the last day in month of `Timestamp`.
+    /// Gets the day of month (1..=31) of `Timestamp`.
     #[inline]
-    pub fn last_day_of_month(self) -> Timestamp {
-        let (sqldate, _) = self.extract();
-        let (year, month, day) = sqldate.extract();
-
-        let result_day = days_of_month(year, month);
-        let result = self.usecs() + (result_day - day) as i64 * USECONDS_PER_DAY;
+    pub fn day_of_month(self) -> u32 {
+        self.date().day_of_month()
+    }
 
-        unsafe { Timestamp::from_usecs_unchecked(result) }
+    /// Gets the number of days in the month of `Timestamp`.
+    #[inline]
+    pub fn days_in_current_month(self) -> u32 {
+        self.date().days_in_current_month()
     }
-}
 
-impl Trunc for Timestamp {
+    /// Gets the number of whole days after the day of `Timestamp` until the end of its month.
     #[inline]
-    fn trunc_century(self) -> Result<Self> {
-        Ok(self.date().trunc_century()?.and_zero_time())
+    pub fn days_remaining_in_month(self) -> u32 {
+        self.date().days_remaining_in_month()
     }
 
+    /// Checks if the day of `Timestamp` is the first day of its month.
     #[inline]
-    fn trunc_year(self) -> Result<Self> {
-        Ok(self.date().trunc_year()?.and_zero_time())
+    pub fn is_first_day_of_month(self) -> bool {
+        self.date().is_first_day_of_month()
     }
 
+    /// Checks if the day of `Timestamp` is the last day of its month.
     #[inline]
-    fn trunc_iso_year(self) -> Result<Self> {
-        Ok(self.date().trunc_iso_year()?.and_zero_time())
+    pub fn is_last_day_of_month(self) -> bool {
+        self.date().is_last_day_of_month()
+    }
+
+    /// Checks if the day of `Timestamp` is the first day of its year, i.e. January 1.
+    #[inline]
+    pub fn is_first_day_of_year(self) -> bool {
+        self.date().is_first_day_of_year()
+    }
+
+    /// Checks if the day of `Timestamp` is the last day of its year, i.e. December 31.
+    #[inline]
+    pub fn is_last_day_of_year(self) -> bool {
+        self.date().is_last_day_of_year()
+    }
+
+    /// Gets which business day of its month the day of `Timestamp` is, see
+    /// [`Date::business_day_of_month`].
+    #[inline]
+    pub fn business_day_of_month(self) -> u32 {
+        self.date().business_day_of_month()
+    }
+
+    /// Gets the last day in month of `Timestamp`.
+    #[inline]
+    pub fn last_day_of_month(self) -> Timestamp {
+        let (sqldate, _) = self.extract();
+        let (year, month, day) = sqldate.extract();
+
+        let result_day = days_of_month(year, month);
+        let result = self.usecs() + (result_day - day) as i64 * USECONDS_PER_DAY;
+
+        unsafe { Timestamp::from_usecs_unchecked(result) }
+    }
+
+    /// Makes a new `Timestamp` with the year replaced, returns error if the result is invalid.
+    #[inline]
+    pub fn with_year(self, year: i32) -> Result<Timestamp> {
+        let (date, time) = self.extract();
+        Ok(date.with_year(year)?.and_time(time))
+    }
+
+    /// Makes a new `Timestamp` with the month replaced, returns error if the result is invalid.
+    #[inline]
+    pub fn with_month(self, month: u32) -> Result<Timestamp> {
+        let (date, time) = self.extract();
+        Ok(date.with_month(month)?.and_time(time))
+    }
+
+    /// Makes a new `Timestamp` with the month replaced, and the day clamped to the last day
+    /// of that month.
+    #[inline]
+    pub fn with_month_clamped(self, month: u32) -> Result<Timestamp> {
+        let (date, time) = self.extract();
+        Ok(date.with_month_clamped(month)?.and_time(time))
+    }
+
+    /// Makes a new `Timestamp` with the day of month replaced, returns error if the result is invalid.
+    #[inline]
+    pub fn with_day(self, day: u32) -> Result<Timestamp> {
+        let (date, time) = self.extract();
+        Ok(date.with_day(day)?.and_time(time))
+    }
+
+    /// Makes a new `Timestamp` with the day of year replaced, returns error if the result is invalid.
+    #[inline]
+    pub fn with_ordinal(self, ordinal: u32) -> Result<Timestamp> {
+        let (date, time) = self.extract();
+        Ok(date.with_ordinal(ordinal)?.and_time(time))
+    }
+}
+
+impl Trunc for Timestamp {
+    #[inline]
+    fn trunc_century(self) -> Result<Self> {
+        Ok(self.date().trunc_century()?.and_zero_time())
+    }
+
+    #[inline]
+    fn trunc_year(self) -> Result<Self> {
+        Ok(self.date().trunc_year()?.and_zero_time())
+    }
+
+    #[inline]
+    fn trunc_iso_year(self) -> Result<Self> {
+        Ok(self.date().trunc_iso_year()?.and_zero_time())
     }
 
     #[inline]
@@ -272,7 +1108,7 @@ impl Trunc for Timestamp {
     fn trunc_hour(self) -> Result<Self> {
         Ok(self
             .date()
-            .and_time(unsafe { Time::from_hms_unchecked(self.hour().unwrap() as u32, 0, 0, 0) }))
+            .and_time(unsafe { Time::from_hms_unchecked(self.time().extract().0, 0, 0, 0) }))
     }
 
     #[inline]
@@ -313,7 +1149,7 @@ impl Round for Timestamp {
     #[inline]
     fn round_week(self) -> Result<Self> {
         let (mut date, time) = self.extract();
-        if time.hour().unwrap() >= 12 {
+        if time.extract().0 >= 12 {
             date = date.add_days(1)?;
         }
         let year = date.extract().0;
@@ -324,7 +1160,7 @@ impl Round for Timestamp {
     #[inline]
     fn round_iso_week(self) -> Result<Self> {
         let (mut date, time) = self.extract();
-        if time.hour().unwrap() >= 12 {
+        if time.extract().0 >= 12 {
             date = date.add_days(1)?;
         }
         Ok(date.round_iso_week()?.and_zero_time())
@@ -333,7 +1169,7 @@ impl Round for Timestamp {
     #[inline]
     fn round_month_start_week(self) -> Result<Self> {
         let (mut date, time) = self.extract();
-        if time.hour().unwrap() >= 12 {
+        if time.extract().0 >= 12 {
             date = date.add_days(1)?;
         }
         let day = date.extract().2;
@@ -345,17 +1181,13 @@ impl Round for Timestamp {
 
     #[inline]
     fn round_day(self) -> Result<Self> {
-        let mut date = self.date();
-        if self.hour().unwrap() >= 12 {
-            date = date.add_days(1)?;
-        }
-        Ok(date.and_zero_time())
+        Ok(self.to_date_round()?.and_zero_time())
     }
 
     #[inline]
     fn round_sunday_start_week(self) -> Result<Self> {
         let (mut date, time) = self.extract();
-        if time.hour().unwrap() >= 12 {
+        if time.extract().0 >= 12 {
             date = date.add_days(1)?;
         }
         Ok(date.round_sunday_start_week()?.and_zero_time())
@@ -398,6 +1230,35 @@ impl Round for Timestamp {
     }
 }
 
+/// Parses a UTC offset `±HH:MM`, `±HHMM` or `±HH` of the ISO 8601 parsers into minutes.
+#[inline]
+fn parse_utc_offset(s: &[u8]) -> Option<i64> {
+    let digits = |s: &[u8]| -> Option<u32> {
+        if s.len() == 2 && s.iter().all(|ch| ch.is_ascii_digit()) {
+            Some(((s[0] - b'0') * 10 + (s[1] - b'0')) as u32)
+        } else {
+            None
+        }
+    };
+
+    let (sign, offset) = match s.split_first()? {
+        (b'+', offset) => (1, offset),
+        (b'-', offset) => (-1, offset),
+        _ => return None,
+    };
+    let (hours, minutes) = match offset.len() {
+        2 => (digits(offset)?, 0),
+        4 => (digits(&offset[..2])?, digits(&offset[2..])?),
+        5 if offset[2] == b':' => (digits(&offset[..2])?, digits(&offset[3..])?),
+        _ => return None,
+    };
+    if hours < 24 && minutes < 60 {
+        Some(sign * (hours * 60 + minutes) as i64)
+    } else {
+        None
+    }
+}
+
 impl From<Timestamp> for NaiveDateTime {
     #[inline]
     fn from(ts: Timestamp) -> Self {
@@ -503,6 +1364,16 @@ impl DateTime for Timestamp {
         self.time().second()
     }
 
+    #[inline]
+    fn microsecond(&self) -> Option<u32> {
+        self.time().microsecond()
+    }
+
+    #[inline]
+    fn epoch_seconds(&self) -> Option<f64> {
+        Some(self.usecs() as f64 / USECONDS_PER_SECOND as f64)
+    }
+
     #[inline]
     fn date(&self) -> Option<Date> {
         Some(Timestamp::date(*self))
@@ -513,6 +1384,7 @@ impl DateTime for Timestamp {
 mod tests {
     use super::*;
     use crate::common::DATE_MAX_YEAR;
+    use crate::util::testing::TestRng;
 
     fn generate_ts(
         year: i32,
@@ -756,11 +1628,12 @@ mod tests {
                     Timestamp::parse("PM 11-9999-59.999999 12-59-31", "PM HH-YYYY-MI.FF MM-SS-DD")
                         .unwrap();
                 assert_eq!(ts2, ts);
-                assert!(Timestamp::parse(
+                let ts2 = Timestamp::parse(
                     "P.M. 11-9999-59.999999 12-59-31",
-                    "PM HH-YYYY-MI.FF MM-SS-DD"
+                    "PM HH-YYYY-MI.FF MM-SS-DD",
                 )
-                .is_err());
+                .unwrap();
+                assert_eq!(ts2, ts);
 
                 let ts2 =
                     Timestamp::parse("23-9999-59.999999 12 59 31", "HH24-YYYY-MI.FF MM SS DD")
@@ -1120,7 +1993,7 @@ mod tests {
                 assert_eq!(format!("{}", timestamp.format("ff6").unwrap()), "123456");
                 assert_eq!(format!("{}", timestamp.format("ff").unwrap()), "123456");
                 assert_eq!(format!("{}", timestamp.format("ff9").unwrap()), "123456000");
-                assert_eq!(format!("{}", timestamp.format("ff5").unwrap()), "12345");
+                assert_eq!(format!("{}", timestamp.format("ff5").unwrap()), "12346");
             }
 
             // Month parse check
@@ -1420,10 +2293,16 @@ mod tests {
         assert_eq!(ts.sub_interval_dt(interval).unwrap(), expect);
 
         let interval = IntervalDT::try_from_dhms(0, 0, 0, 0, 1).unwrap();
-        assert!(ts.add_interval_dt(interval).is_err());
+        assert_eq!(
+            ts.add_interval_dt(interval).unwrap_err(),
+            Error::DateOverflow
+        );
 
         let interval = IntervalDT::try_from_dhms(12345, 12, 3, 5, 6).unwrap();
-        assert!(ts.add_interval_dt(interval).is_err());
+        assert_eq!(
+            ts.add_interval_dt(interval).unwrap_err(),
+            Error::DateOverflow
+        );
 
         let ts = generate_ts(1, 1, 1, 0, 0, 0, 0);
         let interval = IntervalDT::try_from_dhms(5, 4, 3, 2, 1).unwrap();
@@ -1431,10 +2310,171 @@ mod tests {
         assert_eq!(ts.add_interval_dt(interval).unwrap(), expect);
 
         let interval = IntervalDT::try_from_dhms(0, 0, 0, 0, 1).unwrap();
-        assert!(ts.sub_interval_dt(interval).is_err());
+        assert_eq!(
+            ts.sub_interval_dt(interval).unwrap_err(),
+            Error::DateUnderflow
+        );
 
         let interval = IntervalDT::try_from_dhms(12345, 12, 3, 5, 6).unwrap();
-        assert!(ts.sub_interval_dt(interval).is_err());
+        assert_eq!(
+            ts.sub_interval_dt(interval).unwrap_err(),
+            Error::DateUnderflow
+        );
+
+        // The direction of out of range results
+        assert_eq!(
+            ts.add_interval_dt(-interval).unwrap_err(),
+            Error::DateUnderflow
+        );
+        assert_eq!(
+            ts.add_interval_dt(IntervalDT::MIN).unwrap_err(),
+            Error::DateUnderflow
+        );
+        assert_eq!(
+            ts.sub_interval_dt(IntervalDT::MAX).unwrap_err(),
+            Error::DateUnderflow
+        );
+        assert_eq!(
+            ts.add_interval_dt(IntervalDT::MAX).unwrap_err(),
+            Error::DateOverflow
+        );
+    }
+
+    #[test]
+    fn test_timestamp_add_months_with() {
+        let ts = generate_ts(2021, 1, 31, 12, 30, 5, 123456);
+        assert_eq!(
+            ts.add_months_with(1, MonthOverflow::Error).unwrap_err(),
+            Error::InvalidDate
+        );
+        assert_eq!(
+            ts.add_months_with(1, MonthOverflow::ClampToMonthEnd)
+                .unwrap(),
+            generate_ts(2021, 2, 28, 12, 30, 5, 123456)
+        );
+        assert_eq!(
+            ts.add_months_with(1, MonthOverflow::Overflow).unwrap(),
+            generate_ts(2021, 3, 3, 12, 30, 5, 123456)
+        );
+        assert_eq!(
+            Timestamp::MAX
+                .add_months_with(1, MonthOverflow::ClampToMonthEnd)
+                .unwrap_err(),
+            Error::DateOutOfRange
+        );
+    }
+
+    #[test]
+    fn test_timestamp_add_months_preserve_eom() {
+        assert_eq!(
+            generate_ts(2021, 2, 28, 12, 30, 5, 123456)
+                .add_months_preserve_eom(1)
+                .unwrap(),
+            generate_ts(2021, 3, 31, 12, 30, 5, 123456)
+        );
+        assert_eq!(
+            generate_ts(2021, 1, 31, 23, 59, 59, 999999)
+                .add_months_preserve_eom(1)
+                .unwrap(),
+            generate_ts(2021, 2, 28, 23, 59, 59, 999999)
+        );
+        assert_eq!(
+            generate_ts(2021, 2, 27, 0, 0, 0, 0)
+                .add_months_preserve_eom(1)
+                .unwrap(),
+            generate_ts(2021, 3, 27, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            Timestamp::MIN.add_months_preserve_eom(-1).unwrap_err(),
+            Error::DateOutOfRange
+        );
+    }
+
+    #[test]
+    fn test_timestamp_fluent_chain() {
+        let ts = generate_ts(2021, 10, 15, 18, 30, 5, 123456);
+
+        let chain = || -> Result<Timestamp> {
+            ts.trunc(DateTimeUnit::Month)?
+                .add_months(1)?
+                .add_interval_dt(IntervalDT::try_from_dhms(0, 9, 0, 0, 0)?)?
+                .round(DateTimeUnit::Hour)
+        };
+        assert_eq!(chain().unwrap(), generate_ts(2021, 11, 1, 9, 0, 0, 0));
+
+        // Each unit is the same as the specific method
+        use DateTimeUnit::*;
+        for unit in [Year, Quarter, Month, Week, Day, Hour, Minute] {
+            let (trunc, round) = match unit {
+                Year => (ts.trunc_year(), ts.round_year()),
+                Quarter => (ts.trunc_quarter(), ts.round_quarter()),
+                Month => (ts.trunc_month(), ts.round_month()),
+                Week => (ts.trunc_iso_week(), ts.round_iso_week()),
+                Day => (ts.trunc_day(), ts.round_day()),
+                Hour => (ts.trunc_hour(), ts.round_hour()),
+                Minute => (ts.trunc_minute(), ts.round_minute()),
+                _ => unreachable!(),
+            };
+            assert_eq!(ts.trunc(unit), trunc);
+            assert_eq!(ts.round(unit), round);
+        }
+        for unit in [Second, Millisecond, Microsecond] {
+            assert!(matches!(ts.trunc(unit), Err(Error::UnsupportedUnit(_))));
+            assert!(matches!(ts.round(unit), Err(Error::UnsupportedUnit(_))));
+        }
+
+        // add_months clamps to the end of month
+        assert_eq!(
+            generate_ts(2021, 1, 31, 1, 2, 3, 4).add_months(1).unwrap(),
+            generate_ts(2021, 2, 28, 1, 2, 3, 4)
+        );
+        assert_eq!(
+            Timestamp::MAX.add_months(1).unwrap_err(),
+            Error::DateOutOfRange
+        );
+    }
+
+    #[test]
+    fn test_timestamp_parse_relative() {
+        let reference = generate_ts(2021, 3, 1, 12, 30, 5, 123456);
+        for (input, expected) in [
+            ("now", reference),
+            ("NOW", reference),
+            ("today", generate_ts(2021, 3, 1, 0, 0, 0, 0)),
+            ("Yesterday", generate_ts(2021, 2, 28, 0, 0, 0, 0)),
+            (" tomorrow ", generate_ts(2021, 3, 2, 0, 0, 0, 0)),
+        ] {
+            assert_eq!(
+                Timestamp::parse_relative(input, Some(reference)).unwrap(),
+                expected
+            );
+        }
+
+        // Defaults to now
+        let before = Timestamp::now().unwrap();
+        let parsed = Timestamp::parse_relative("now", None).unwrap();
+        assert!(parsed >= before);
+        // The clock is read again, which may be on the next day
+        let today = Timestamp::parse_relative("today", None).unwrap();
+        assert_eq!(today.extract().1, Time::ZERO);
+        let date = parsed.date();
+        assert!(today.date() == date || today.date() == date.add_days(1).unwrap());
+
+        // Out of range
+        assert_eq!(
+            Timestamp::parse_relative("tomorrow", Some(Timestamp::MAX)).unwrap_err(),
+            Error::DateOutOfRange
+        );
+        assert_eq!(
+            Timestamp::parse_relative("today", Some(Timestamp::MAX)).unwrap(),
+            generate_ts(9999, 12, 31, 0, 0, 0, 0)
+        );
+
+        // Unrecognized
+        assert_eq!(
+            Timestamp::parse_relative("noon", Some(reference)).unwrap_err(),
+            Error::ParseError("the input noon is not a relative date keyword".to_string())
+        );
     }
 
     #[test]
@@ -1576,6 +2616,186 @@ mod tests {
         assert!(ts.sub_time(time).is_err());
     }
 
+    #[test]
+    fn test_timestamp_parse_or() {
+        let fmt = "YYYY-MM-DD HH24:MI:SS";
+        let default = generate_ts(1970, 1, 1, 0, 0, 0, 0);
+        let expected = generate_ts(2021, 6, 1, 12, 30, 5, 0);
+
+        // Valid input
+        assert_eq!(
+            Timestamp::parse_or("2021-06-01 12:30:05", fmt, default),
+            expected
+        );
+        assert_eq!(
+            Timestamp::parse_or_else("2021-06-01 12:30:05", fmt, || unreachable!()),
+            expected
+        );
+        assert_eq!(
+            Timestamp::parse_or_now("2021-06-01 12:30:05", fmt).unwrap(),
+            expected
+        );
+
+        // Malformed input
+        for input in [
+            "2021-13-01 12:30:05",
+            "2021-06-01 25:00:00",
+            "not a timestamp",
+            "",
+        ] {
+            assert_eq!(Timestamp::parse_or(input, fmt, default), default);
+            assert_eq!(Timestamp::parse_or_else(input, fmt, || default), default);
+            let before = Timestamp::now().unwrap();
+            let now = Timestamp::parse_or_now(input, fmt).unwrap();
+            assert!(now >= before && now <= Timestamp::now().unwrap());
+        }
+
+        // Invalid format
+        assert_eq!(
+            Timestamp::parse_or("2021-06-01", "YYYY-MM-DD ZZ", default),
+            default
+        );
+    }
+
+    #[test]
+    fn test_timestamp_parse_any() {
+        let formats = ["yyyy-mm-dd hh24:mi:ss", "dd/mm/yyyy hh24:mi:ss"];
+        assert_eq!(
+            Timestamp::parse_any("2021-10-24 12:30:45", &formats).unwrap(),
+            generate_ts(2021, 10, 24, 12, 30, 45, 0)
+        );
+        assert_eq!(
+            Timestamp::parse_any("24/10/2021 12:30:45", &formats).unwrap(),
+            generate_ts(2021, 10, 24, 12, 30, 45, 0)
+        );
+
+        assert_eq!(
+            Timestamp::parse_any("2021", &formats).unwrap_err(),
+            Error::ParseError("the input 2021 does not match any of the formats".to_string())
+        );
+        assert!(Timestamp::parse_any("2021-10-24", &[]).is_err());
+        assert!(matches!(
+            Timestamp::parse_any("2021-10-24", &["testtest"]),
+            Err(Error::InvalidFormat(_))
+        ));
+    }
+
+    #[test]
+    fn test_timestamp_system_time() {
+        assert_eq!(
+            Timestamp::try_from_system_time(UNIX_EPOCH).unwrap(),
+            generate_ts(1970, 1, 1, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            Timestamp::try_from_system_time(UNIX_EPOCH + Duration::new(1622505600, 123456789))
+                .unwrap(),
+            generate_ts(2021, 6, 1, 0, 0, 0, 123456)
+        );
+
+        // Before Unix Epoch
+        assert_eq!(
+            Timestamp::try_from_system_time(UNIX_EPOCH - Duration::from_micros(1)).unwrap(),
+            generate_ts(1969, 12, 31, 23, 59, 59, 999999)
+        );
+        assert_eq!(
+            Timestamp::try_from_system_time(UNIX_EPOCH - Duration::new(86400, 500)).unwrap(),
+            generate_ts(1969, 12, 30, 23, 59, 59, 999999)
+        );
+        assert_eq!(
+            Timestamp::try_from_system_time(UNIX_EPOCH - Duration::new(86400, 1000)).unwrap(),
+            generate_ts(1969, 12, 30, 23, 59, 59, 999999)
+        );
+
+        // Round trip
+        for ts in [
+            Timestamp::MIN,
+            Timestamp::MAX,
+            generate_ts(1969, 12, 31, 23, 59, 59, 999999),
+            generate_ts(1970, 1, 1, 0, 0, 0, 1),
+            generate_ts(2021, 6, 1, 12, 34, 56, 789012),
+        ] {
+            assert_eq!(
+                Timestamp::try_from_system_time(ts.to_system_time()).unwrap(),
+                ts
+            );
+        }
+
+        let now = SystemTime::now();
+        let ts = Timestamp::try_from_system_time(now).unwrap();
+        let diff = now.duration_since(ts.to_system_time()).unwrap();
+        assert!(diff < Duration::from_micros(1));
+
+        // Out of range
+        assert!(Timestamp::try_from_system_time(
+            Timestamp::MAX.to_system_time() + Duration::from_micros(1)
+        )
+        .is_err());
+        assert!(Timestamp::try_from_system_time(
+            Timestamp::MIN.to_system_time() - Duration::from_micros(1)
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_timestamp_approx_eq() {
+        let ts = generate_ts(2021, 6, 1, 12, 0, 0, 0);
+        let ms = IntervalDT::try_from_dhms(0, 0, 0, 0, 1000).unwrap();
+
+        assert!(ts.approx_eq(ts, IntervalDT::ZERO));
+        assert!(ts.approx_eq(ts, ms));
+
+        // At the tolerance boundary
+        let later = generate_ts(2021, 6, 1, 12, 0, 0, 1000);
+        assert!(ts.approx_eq(later, ms));
+        assert!(later.approx_eq(ts, ms));
+        let later = generate_ts(2021, 6, 1, 12, 0, 0, 1001);
+        assert!(!ts.approx_eq(later, ms));
+        assert!(!later.approx_eq(ts, ms));
+        let earlier = generate_ts(2021, 6, 1, 11, 59, 59, 999000);
+        assert!(ts.approx_eq(earlier, ms));
+        let earlier = generate_ts(2021, 6, 1, 11, 59, 59, 998999);
+        assert!(!ts.approx_eq(earlier, ms));
+        assert!(!ts.approx_eq(generate_ts(2021, 6, 1, 12, 0, 0, 1), IntervalDT::ZERO));
+
+        // Negative tolerance is taken as its absolute value
+        assert!(ts.approx_eq(generate_ts(2021, 6, 1, 12, 0, 0, 1000), -ms));
+        assert!(!ts.approx_eq(generate_ts(2021, 6, 1, 12, 0, 0, 1001), -ms));
+
+        // Range bounds
+        assert!(Timestamp::MIN.approx_eq(Timestamp::MAX, IntervalDT::MAX));
+        assert!(Timestamp::MAX.approx_eq(Timestamp::MIN, IntervalDT::MIN));
+        assert!(!Timestamp::MIN.approx_eq(
+            Timestamp::MAX,
+            IntervalDT::try_from_dhms(3652058, 0, 0, 0, 0).unwrap()
+        ));
+    }
+
+    #[test]
+    fn test_timestamp_weeks_between() {
+        // Thursday noon to the following Monday across the year boundary
+        let thu = generate_ts(2020, 12, 31, 12, 0, 0, 0);
+        let mon = generate_ts(2021, 1, 4, 0, 0, 0, 0);
+        assert_eq!(mon.weeks_between(thu), 0);
+        assert_eq!(mon.iso_weeks_between(thu), 1);
+        assert_eq!(thu.weeks_between(mon), -1);
+        assert_eq!(thu.iso_weeks_between(mon), -1);
+
+        // The time of day counts for simple weeks only
+        let ts = generate_ts(2021, 6, 15, 12, 0, 0, 0);
+        let week_later = generate_ts(2021, 6, 22, 11, 59, 59, 999999);
+        assert_eq!(week_later.weeks_between(ts), 0);
+        assert_eq!(week_later.iso_weeks_between(ts), 1);
+        let week_later = generate_ts(2021, 6, 22, 12, 0, 0, 0);
+        assert_eq!(week_later.weeks_between(ts), 1);
+        assert_eq!(ts.weeks_between(week_later), -1);
+
+        assert_eq!(Timestamp::MAX.weeks_between(Timestamp::MIN), 3652059 / 7);
+        assert_eq!(
+            Timestamp::MAX.iso_weeks_between(Timestamp::MIN),
+            3652058 / 7
+        );
+    }
+
     #[test]
     fn test_timestamp_sub_timestamp() {
         let upper_ts = generate_ts(9999, 12, 31, 23, 59, 59, 999999);
@@ -1583,42 +2803,683 @@ mod tests {
         let ts = generate_ts(5000, 6, 15, 12, 30, 30, 500000);
 
         assert_eq!(
-            upper_ts.sub_timestamp(lower_ts),
-            IntervalDT::try_from_dhms(3652058, 23, 59, 59, 999999).unwrap()
+            upper_ts.sub_timestamp(lower_ts),
+            IntervalDT::try_from_dhms(3652058, 23, 59, 59, 999999).unwrap()
+        );
+
+        assert_eq!(
+            upper_ts.sub_timestamp(ts),
+            IntervalDT::try_from_dhms(1826046, 11, 29, 29, 499999).unwrap()
+        );
+
+        assert_eq!(
+            lower_ts.sub_timestamp(upper_ts),
+            -IntervalDT::try_from_dhms(3652058, 23, 59, 59, 999999).unwrap()
+        );
+
+        assert_eq!(
+            Timestamp::MAX.checked_sub_timestamp(Timestamp::MIN),
+            Some(IntervalDT::try_from_dhms(3652058, 23, 59, 59, 999999).unwrap())
+        );
+        assert_eq!(
+            Timestamp::MIN.checked_sub_timestamp(Timestamp::MAX),
+            Some(-IntervalDT::try_from_dhms(3652058, 23, 59, 59, 999999).unwrap())
+        );
+        assert_eq!(
+            Timestamp::MAX.checked_sub_timestamp(Timestamp::MAX),
+            Some(IntervalDT::try_from_usecs(0).unwrap())
+        );
+        assert_eq!(
+            Timestamp::MIN.checked_sub_timestamp(Timestamp::MIN),
+            Some(IntervalDT::try_from_usecs(0).unwrap())
+        );
+        assert_eq!(
+            upper_ts.checked_sub_timestamp(ts),
+            Some(upper_ts.sub_timestamp(ts))
+        );
+    }
+
+    #[test]
+    fn test_timestamp_trunc_day_in_offset() {
+        const IST: i32 = 5 * 60 + 30;
+
+        // 2021-06-01 20:00 UTC is 2021-06-02 01:30 in +05:30, so the local day starts at 2021-06-01 18:30 UTC
+        let ts = generate_ts(2021, 6, 1, 20, 0, 0, 0);
+        assert_eq!(
+            ts.trunc_day_in_offset(IST).unwrap(),
+            generate_ts(2021, 6, 1, 18, 30, 0, 0)
+        );
+        assert_eq!(ts.trunc_day().unwrap(), generate_ts(2021, 6, 1, 0, 0, 0, 0));
+
+        // 2021-06-01 10:00 UTC is 2021-06-01 15:30 in +05:30
+        let ts = generate_ts(2021, 6, 1, 10, 0, 0, 0);
+        assert_eq!(
+            ts.trunc_day_in_offset(IST).unwrap(),
+            generate_ts(2021, 5, 31, 18, 30, 0, 0)
+        );
+
+        // Exactly the local midnight
+        let ts = generate_ts(2021, 5, 31, 18, 30, 0, 0);
+        assert_eq!(ts.trunc_day_in_offset(IST).unwrap(), ts);
+        let ts = generate_ts(2021, 5, 31, 18, 29, 59, 999999);
+        assert_eq!(
+            ts.trunc_day_in_offset(IST).unwrap(),
+            generate_ts(2021, 5, 30, 18, 30, 0, 0)
+        );
+
+        // Negative offset
+        let ts = generate_ts(2021, 6, 1, 3, 0, 0, 0);
+        assert_eq!(
+            ts.trunc_day_in_offset(-5 * 60).unwrap(),
+            generate_ts(2021, 5, 31, 5, 0, 0, 0)
+        );
+
+        // Zero offset is the same as trunc_day
+        let ts = generate_ts(1969, 12, 31, 23, 59, 59, 999999);
+        assert_eq!(ts.trunc_day_in_offset(0).unwrap(), ts.trunc_day().unwrap());
+
+        // Out of range
+        assert!(Timestamp::MIN.trunc_day_in_offset(0).is_ok());
+        assert!(Timestamp::MIN.trunc_day_in_offset(IST).is_err());
+        assert!(Timestamp::MIN.trunc_day_in_offset(-IST).is_err());
+        assert!(Timestamp::MAX.trunc_day_in_offset(IST).is_ok());
+        assert!(Timestamp::MAX.trunc_day_in_offset(-IST).is_ok());
+        assert!(generate_ts(1, 1, 1, 12, 0, 0, 0)
+            .trunc_day_in_offset(IST)
+            .is_err());
+        assert!(generate_ts(1, 1, 1, 12, 0, 0, 0)
+            .trunc_day_in_offset(-IST)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_timestamp_to_date() {
+        let date = Date::try_from_ymd(2021, 6, 1).unwrap();
+        let next_date = Date::try_from_ymd(2021, 6, 2).unwrap();
+
+        let ts = generate_ts(2021, 6, 1, 0, 0, 0, 0);
+        assert_eq!(ts.to_date_trunc(), date);
+        assert_eq!(ts.to_date_round().unwrap(), date);
+
+        let ts = generate_ts(2021, 6, 1, 11, 59, 59, 999999);
+        assert_eq!(ts.to_date_trunc(), date);
+        assert_eq!(ts.to_date_round().unwrap(), date);
+
+        let ts = generate_ts(2021, 6, 1, 12, 0, 0, 0);
+        assert_eq!(ts.to_date_trunc(), date);
+        assert_eq!(ts.to_date_round().unwrap(), next_date);
+
+        let ts = generate_ts(1969, 12, 31, 23, 0, 0, 0);
+        assert_eq!(
+            ts.to_date_trunc(),
+            Date::try_from_ymd(1969, 12, 31).unwrap()
+        );
+        assert_eq!(
+            ts.to_date_round().unwrap(),
+            Date::try_from_ymd(1970, 1, 1).unwrap()
+        );
+
+        // Consistent with Trunc and Round
+        let ts = generate_ts(2021, 6, 1, 18, 30, 0, 0);
+        assert_eq!(ts.to_date_trunc().and_zero_time(), ts.trunc_day().unwrap());
+        assert_eq!(
+            ts.to_date_round().unwrap().and_zero_time(),
+            ts.round_day().unwrap()
+        );
+
+        assert_eq!(Timestamp::MIN.to_date_trunc(), Date::MIN);
+        assert_eq!(Timestamp::MIN.to_date_round().unwrap(), Date::MIN);
+        assert_eq!(Timestamp::MAX.to_date_trunc(), Date::MAX);
+        assert!(Timestamp::MAX.to_date_round().is_err());
+        assert_eq!(
+            generate_ts(9999, 12, 31, 11, 59, 59, 999999)
+                .to_date_round()
+                .unwrap(),
+            Date::MAX
+        );
+    }
+
+    #[test]
+    fn test_timestamp_diff_in() {
+        use DateTimeUnit::*;
+
+        let jan31 = generate_ts(2021, 1, 31, 0, 0, 0, 0);
+        let mar1 = generate_ts(2021, 3, 1, 0, 0, 0, 0);
+        assert_eq!(mar1.diff_in(jan31, Month), 1);
+        assert_eq!(jan31.diff_in(mar1, Month), -1);
+        assert_eq!(mar1.diff_in(jan31, Day), 29);
+        assert_eq!(mar1.diff_in(jan31, Week), 4);
+        assert_eq!(jan31.diff_in(mar1, Week), -4);
+
+        let feb28 = generate_ts(2021, 2, 28, 23, 59, 59, 999999);
+        assert_eq!(feb28.diff_in(jan31, Month), 0);
+        assert_eq!(jan31.diff_in(feb28, Month), 0);
+        let mar31 = generate_ts(2021, 3, 31, 0, 0, 0, 0);
+        assert_eq!(mar31.diff_in(jan31, Month), 2);
+        assert_eq!(jan31.diff_in(mar31, Month), -2);
+
+        // Time of day counts for calendar units
+        let ts1 = generate_ts(2021, 1, 15, 12, 0, 0, 0);
+        let ts2 = generate_ts(2021, 2, 15, 11, 59, 59, 999999);
+        assert_eq!(ts2.diff_in(ts1, Month), 0);
+        assert_eq!(ts1.diff_in(ts2, Month), 0);
+        let ts2 = generate_ts(2021, 2, 15, 12, 0, 0, 0);
+        assert_eq!(ts2.diff_in(ts1, Month), 1);
+        assert_eq!(ts1.diff_in(ts2, Month), -1);
+
+        let ts1 = generate_ts(2020, 2, 29, 0, 0, 0, 0);
+        let ts2 = generate_ts(2021, 2, 28, 0, 0, 0, 0);
+        assert_eq!(ts2.diff_in(ts1, Year), 0);
+        assert_eq!(ts2.diff_in(ts1, Quarter), 3);
+        assert_eq!(ts2.diff_in(ts1, Month), 11);
+        let ts2 = generate_ts(2024, 3, 1, 0, 0, 0, 0);
+        assert_eq!(ts2.diff_in(ts1, Year), 4);
+        assert_eq!(ts1.diff_in(ts2, Year), -4);
+        assert_eq!(ts2.diff_in(ts1, Quarter), 16);
+
+        // Hours across a day boundary
+        let ts1 = generate_ts(2021, 6, 1, 22, 30, 0, 0);
+        let ts2 = generate_ts(2021, 6, 2, 1, 29, 59, 999999);
+        assert_eq!(ts2.diff_in(ts1, Hour), 2);
+        assert_eq!(ts1.diff_in(ts2, Hour), -2);
+        assert_eq!(ts2.diff_in(ts1, Day), 0);
+        assert_eq!(ts2.diff_in(ts1, Minute), 179);
+        assert_eq!(ts2.diff_in(ts1, Second), 10799);
+        assert_eq!(ts2.diff_in(ts1, Millisecond), 10799999);
+        assert_eq!(ts2.diff_in(ts1, Microsecond), 10799999999);
+        let ts2 = generate_ts(2021, 6, 2, 1, 30, 0, 0);
+        assert_eq!(ts2.diff_in(ts1, Hour), 3);
+
+        assert_eq!(ts1.diff_in(ts1, Year), 0);
+        assert_eq!(ts1.diff_in(ts1, Microsecond), 0);
+
+        assert_eq!(Timestamp::MAX.diff_in(Timestamp::MIN, Year), 9998);
+        assert_eq!(Timestamp::MAX.diff_in(Timestamp::MIN, Month), 119987);
+        assert_eq!(Timestamp::MIN.diff_in(Timestamp::MAX, Month), -119987);
+        assert_eq!(Timestamp::MAX.diff_in(Timestamp::MIN, Day), 3652058);
+    }
+
+    #[test]
+    fn test_timestamp_elapsed_parts() {
+        let ts = generate_ts(2021, 3, 1, 12, 30, 15, 250000);
+
+        assert_eq!(ts.elapsed_parts(ts), (Sign::Positive, 0, 0, 0, 0, 0));
+
+        let earlier = generate_ts(2021, 2, 27, 10, 0, 0, 0);
+        assert_eq!(
+            ts.elapsed_parts(earlier),
+            (Sign::Positive, 2, 2, 30, 15, 250000)
+        );
+        assert_eq!(
+            earlier.elapsed_parts(ts),
+            (Sign::Negative, 2, 2, 30, 15, 250000)
+        );
+
+        // Less than one day
+        let earlier = generate_ts(2021, 3, 1, 12, 0, 0, 0);
+        assert_eq!(
+            ts.elapsed_parts(earlier),
+            (Sign::Positive, 0, 0, 30, 15, 250000)
+        );
+        assert_eq!(
+            earlier.elapsed_parts(ts),
+            (Sign::Negative, 0, 0, 30, 15, 250000)
+        );
+
+        // One microsecond around midnight
+        let before = generate_ts(2020, 12, 31, 23, 59, 59, 999999);
+        let after = generate_ts(2021, 1, 1, 0, 0, 0, 0);
+        assert_eq!(after.elapsed_parts(before), (Sign::Positive, 0, 0, 0, 0, 1));
+        assert_eq!(before.elapsed_parts(after), (Sign::Negative, 0, 0, 0, 0, 1));
+
+        assert_eq!(
+            Timestamp::MAX.elapsed_parts(Timestamp::MIN),
+            (Sign::Positive, 3652058, 23, 59, 59, 999999)
+        );
+        assert_eq!(
+            Timestamp::MIN.elapsed_parts(Timestamp::MAX),
+            (Sign::Negative, 3652058, 23, 59, 59, 999999)
+        );
+    }
+
+    #[test]
+    fn test_timestamp_sub_date() {
+        let upper_ts = generate_ts(9999, 12, 31, 23, 59, 59, 999999);
+        let lower_ts = generate_ts(1, 1, 1, 0, 0, 0, 0);
+        let lower_date = Date::try_from_ymd(1, 1, 1).unwrap();
+        let upper_date = Date::try_from_ymd(9999, 12, 31).unwrap();
+        let date = Date::try_from_ymd(5000, 6, 15).unwrap();
+
+        assert_eq!(
+            upper_ts.sub_date(lower_date),
+            IntervalDT::try_from_dhms(3652058, 23, 59, 59, 999999).unwrap()
+        );
+
+        assert_eq!(
+            upper_ts.sub_date(date),
+            IntervalDT::try_from_dhms(1826046, 23, 59, 59, 999999).unwrap()
+        );
+
+        assert_eq!(
+            lower_ts.sub_date(upper_date),
+            -IntervalDT::try_from_dhms(3652058, 0, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_timestamp_try_from_epoch_str() {
+        let ts = generate_ts(2022, 6, 18, 3, 4, 5, 0);
+        assert_eq!(Timestamp::try_from_epoch_str("1655521445").unwrap(), ts);
+        assert_eq!(Timestamp::try_from_epoch_str("@1655521445").unwrap(), ts);
+        assert_eq!(Timestamp::try_from_epoch_str(" +1655521445 ").unwrap(), ts);
+        assert_eq!(
+            Timestamp::try_from_epoch_str("1655521445.123456").unwrap(),
+            generate_ts(2022, 6, 18, 3, 4, 5, 123456)
+        );
+        assert_eq!(
+            Timestamp::try_from_epoch_str("1655521445.5").unwrap(),
+            generate_ts(2022, 6, 18, 3, 4, 5, 500000)
+        );
+        assert_eq!(
+            Timestamp::try_from_epoch_str("1655521445.123456789").unwrap(),
+            generate_ts(2022, 6, 18, 3, 4, 5, 123456)
+        );
+        assert_eq!(Timestamp::try_from_epoch_str("1655521445.").unwrap(), ts);
+        assert_eq!(
+            Timestamp::try_from_epoch_str("0").unwrap(),
+            generate_ts(1970, 1, 1, 0, 0, 0, 0)
+        );
+
+        // Before Unix Epoch
+        assert_eq!(
+            Timestamp::try_from_epoch_str("-1").unwrap(),
+            generate_ts(1969, 12, 31, 23, 59, 59, 0)
+        );
+        assert_eq!(
+            Timestamp::try_from_epoch_str("@-1.5").unwrap(),
+            generate_ts(1969, 12, 31, 23, 59, 58, 500000)
+        );
+        assert_eq!(
+            Timestamp::try_from_epoch_str("-1000000000").unwrap(),
+            generate_ts(1938, 4, 24, 22, 13, 20, 0)
+        );
+
+        // Boundaries
+        assert_eq!(
+            Timestamp::try_from_epoch_str("253402300799.999999").unwrap(),
+            Timestamp::MAX
+        );
+        assert_eq!(
+            Timestamp::try_from_epoch_str("-62135596800").unwrap(),
+            Timestamp::MIN
+        );
+        assert_eq!(
+            Timestamp::try_from_epoch_str("253402300800").unwrap_err(),
+            Error::DateOutOfRange
+        );
+        assert_eq!(
+            Timestamp::try_from_epoch_str("-62135596800.000001").unwrap_err(),
+            Error::DateOutOfRange
+        );
+        assert_eq!(
+            Timestamp::try_from_epoch_str("99999999999999999999").unwrap_err(),
+            Error::DateOutOfRange
+        );
+        assert_eq!(
+            Timestamp::try_from_epoch_str("9223372036854.999999").unwrap_err(),
+            Error::DateOutOfRange
+        );
+
+        // Invalid
+        let invalid = Error::ParseError("invalid seconds since Unix Epoch".to_string());
+        for input in [
+            "", "@", "-", ".5", "12a", "1.2.3", "1.-2", "--1", "@@1", "1e9",
+        ] {
+            assert_eq!(
+                Timestamp::try_from_epoch_str(input).unwrap_err(),
+                invalid,
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_timestamp_sortable_string() {
+        let ts = generate_ts(2021, 6, 1, 12, 30, 5, 1);
+        assert_eq!(ts.to_sortable_string().unwrap(), "20210601123005000001");
+        assert_eq!(
+            Timestamp::MIN.to_sortable_string().unwrap(),
+            "00010101000000000000"
+        );
+        assert_eq!(
+            Timestamp::MAX.to_sortable_string().unwrap(),
+            "99991231235959999999"
+        );
+
+        let mut timestamps = vec![
+            Timestamp::MAX,
+            generate_ts(1970, 1, 1, 0, 0, 0, 0),
+            generate_ts(1970, 1, 1, 0, 0, 0, 1),
+            generate_ts(1969, 12, 31, 23, 59, 59, 999999),
+            generate_ts(1969, 12, 31, 0, 0, 0, 0),
+            ts,
+            generate_ts(2021, 6, 1, 12, 30, 5, 0),
+            generate_ts(2021, 6, 1, 9, 30, 5, 999999),
+            generate_ts(999, 12, 31, 23, 59, 59, 0),
+            Timestamp::MIN,
+        ];
+        let mut strings: Vec<String> = timestamps
+            .iter()
+            .map(|ts| ts.to_sortable_string().unwrap())
+            .collect();
+        timestamps.sort();
+        strings.sort();
+        for (ts, s) in timestamps.iter().zip(strings.iter()) {
+            assert_eq!(ts.to_sortable_string().unwrap(), *s);
+            assert_eq!(Timestamp::from_sortable_string(s).unwrap(), *ts);
+        }
+
+        assert_eq!(
+            Timestamp::from_sortable_string("20210601243005000001").unwrap_err(),
+            Error::TimeOutOfRange
+        );
+        assert_eq!(
+            Timestamp::from_sortable_string("20210631123005000001").unwrap_err(),
+            Error::InvalidDate
+        );
+        for input in [
+            "",
+            "20210601",
+            "2021060112300500000",
+            "20210601 123005000001",
+        ] {
+            assert_eq!(
+                Timestamp::from_sortable_string(input).unwrap_err(),
+                Error::ParseError(format!(
+                    "the input {} is not a valid sortable timestamp",
+                    input
+                ))
+            );
+        }
+    }
+
+    #[test]
+    fn test_timestamp_humanize_since() {
+        let now = generate_ts(2021, 6, 15, 12, 0, 0, 0);
+        let ago = |y, m, d, h, mi, s, us| generate_ts(y, m, d, h, mi, s, us).humanize_since(now);
+
+        assert_eq!(now.humanize_since(now), RelativeTime::JustNow);
+        assert_eq!(ago(2021, 6, 15, 11, 59, 0, 1), RelativeTime::JustNow);
+        assert_eq!(ago(2021, 6, 15, 12, 0, 59, 999999), RelativeTime::JustNow);
+
+        // Minutes
+        assert_eq!(ago(2021, 6, 15, 11, 59, 0, 0), RelativeTime::MinutesAgo(1));
+        assert_eq!(ago(2021, 6, 15, 11, 0, 0, 1), RelativeTime::MinutesAgo(59));
+        assert_eq!(ago(2021, 6, 15, 12, 1, 0, 0), RelativeTime::InMinutes(1));
+
+        // Hours
+        assert_eq!(ago(2021, 6, 15, 11, 0, 0, 0), RelativeTime::HoursAgo(1));
+        assert_eq!(ago(2021, 6, 14, 12, 0, 0, 1), RelativeTime::HoursAgo(23));
+        assert_eq!(ago(2021, 6, 16, 11, 59, 59, 0), RelativeTime::InHours(23));
+
+        // Days
+        assert_eq!(ago(2021, 6, 14, 12, 0, 0, 0), RelativeTime::DaysAgo(1));
+        assert_eq!(ago(2021, 5, 15, 12, 0, 0, 1), RelativeTime::DaysAgo(30));
+        assert_eq!(ago(2021, 6, 18, 12, 0, 0, 0), RelativeTime::InDays(3));
+        assert_eq!(ago(2021, 7, 15, 11, 0, 0, 0), RelativeTime::InDays(29));
+
+        // Months
+        assert_eq!(ago(2021, 5, 15, 12, 0, 0, 0), RelativeTime::MonthsAgo(1));
+        assert_eq!(ago(2020, 6, 15, 12, 0, 0, 1), RelativeTime::MonthsAgo(11));
+        assert_eq!(ago(2021, 7, 15, 12, 0, 0, 0), RelativeTime::InMonths(1));
+
+        // Years
+        assert_eq!(ago(2020, 6, 15, 12, 0, 0, 0), RelativeTime::YearsAgo(1));
+        assert_eq!(ago(2000, 1, 1, 0, 0, 0, 0), RelativeTime::YearsAgo(21));
+        assert_eq!(ago(2023, 6, 15, 12, 0, 0, 0), RelativeTime::InYears(2));
+        assert_eq!(
+            Timestamp::MIN.humanize_since(Timestamp::MAX),
+            RelativeTime::YearsAgo(9998)
+        );
+        assert_eq!(
+            Timestamp::MAX.humanize_since(Timestamp::MIN),
+            RelativeTime::InYears(9998)
+        );
+
+        // Date is treated as its midnight
+        assert_eq!(
+            generate_date(2021, 6, 12).humanize_since(now),
+            RelativeTime::DaysAgo(3)
+        );
+        assert_eq!(
+            generate_date(2021, 6, 15).humanize_since(now),
+            RelativeTime::HoursAgo(12)
+        );
+    }
+
+    #[test]
+    fn test_timestamp_iso8601_basic() {
+        // Date
+        assert_eq!(
+            Timestamp::parse_iso8601_basic("20210102").unwrap(),
+            generate_ts(2021, 1, 2, 0, 0, 0, 0)
+        );
+
+        // Date and time
+        let ts = generate_ts(2021, 1, 2, 3, 4, 5, 0);
+        assert_eq!(
+            Timestamp::parse_iso8601_basic("20210102T030405").unwrap(),
+            ts
+        );
+        assert_eq!(
+            Timestamp::parse_iso8601_basic("20210102T030405Z").unwrap(),
+            ts
+        );
+        assert_eq!(ts.format_iso8601_basic().unwrap(), "20210102T030405Z");
+        assert_eq!(
+            Timestamp::parse_iso8601_basic("20210102T030405.5").unwrap(),
+            generate_ts(2021, 1, 2, 3, 4, 5, 500000)
+        );
+        assert_eq!(
+            Timestamp::parse_iso8601_basic("20210102T030405,123456789Z").unwrap(),
+            generate_ts(2021, 1, 2, 3, 4, 5, 123456)
+        );
+
+        // Round trip
+        for s in [
+            "20210102T030405.123456Z",
+            "20210102T030405.000001Z",
+            "00010101T000000Z",
+            "99991231T235959.999999Z",
+        ] {
+            let ts = Timestamp::parse_iso8601_basic(s).unwrap();
+            assert_eq!(ts.format_iso8601_basic().unwrap(), s);
+        }
+        assert_eq!(
+            Timestamp::parse_iso8601_basic("20210102T030405.123456Z").unwrap(),
+            generate_ts(2021, 1, 2, 3, 4, 5, 123456)
+        );
+
+        // Offset, converted to UTC
+        assert_eq!(
+            Timestamp::parse_iso8601_basic("20210102T030405+0800").unwrap(),
+            generate_ts(2021, 1, 1, 19, 4, 5, 0)
+        );
+        assert_eq!(
+            Timestamp::parse_iso8601_basic("20210102T030405.123456-0530").unwrap(),
+            generate_ts(2021, 1, 2, 8, 34, 5, 123456)
+        );
+        assert_eq!(
+            Timestamp::parse_iso8601_basic("20210102T030405+08").unwrap(),
+            generate_ts(2021, 1, 1, 19, 4, 5, 0)
+        );
+        assert_eq!(
+            Timestamp::parse_iso8601_basic("20210102T030405+08:00").unwrap(),
+            generate_ts(2021, 1, 1, 19, 4, 5, 0)
+        );
+        assert_eq!(
+            Timestamp::parse_iso8601_basic("20210102T030405+0000").unwrap(),
+            ts
+        );
+
+        // The same instant through both ISO 8601 parsers
+        for (basic, extended) in [
+            ("20210102T030405Z", "2021-01-02T03:04:05Z"),
+            ("20210102T030405+0800", "2021-01-02T03:04:05+08:00"),
+            ("20210102T030405+08:00", "2021-01-02T03:04:05+0800"),
+            ("20210102T030405.5-0530", "2021-01-02T03:04:05.5-05:30"),
+            ("20210102T030405-08", "2021-01-02T03:04:05-08"),
+        ] {
+            assert_eq!(
+                Timestamp::parse_iso8601_basic(basic).unwrap(),
+                Timestamp::parse_iso8601(extended).unwrap(),
+                "{}",
+                basic
+            );
+        }
+        assert_eq!(
+            Timestamp::parse_iso8601_basic("00010101T000000+0100").unwrap_err(),
+            Error::DateOutOfRange
+        );
+
+        // Invalid values
+        assert_eq!(
+            Timestamp::parse_iso8601_basic("20210230").unwrap_err(),
+            Error::InvalidDate
+        );
+        assert_eq!(
+            Timestamp::parse_iso8601_basic("20210102T240000").unwrap_err(),
+            Error::TimeOutOfRange
+        );
+        for input in [
+            "",
+            "2021010",
+            "2021-01-02",
+            "202101021",
+            "20210102T",
+            "20210102T0304",
+            "20210102 030405",
+            "20210102T03:04:05",
+            "20210102T030405.",
+            "20210102T030405.Z",
+            "20210102T030405+8",
+            "20210102T030405+08:0",
+            "20210102T030405+08-00",
+            "20210102T030405+2400",
+            "20210102T030405+0860",
+            "20210102T030405z",
+            "20210102T030405ZZ",
+        ] {
+            assert_eq!(
+                Timestamp::parse_iso8601_basic(input).unwrap_err(),
+                Error::ParseError(format!(
+                    "the input {} is not a valid ISO 8601 basic date",
+                    input
+                )),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_timestamp_packed_i64() {
+        assert_eq!(
+            Timestamp::try_from_packed_i64(20211024123045).unwrap(),
+            generate_ts(2021, 10, 24, 12, 30, 45, 0)
+        );
+        assert_eq!(
+            generate_ts(2021, 10, 24, 12, 30, 45, 999999).to_packed_i64(),
+            20211024123045
+        );
+        assert_eq!(Timestamp::MIN.to_packed_i64(), 10101000000);
+        assert_eq!(Timestamp::MAX.to_packed_i64(), 99991231235959);
+        assert_eq!(
+            Timestamp::try_from_packed_i64(10101000000).unwrap(),
+            Timestamp::MIN
+        );
+        assert_eq!(
+            Timestamp::try_from_packed_i64(99991231235959).unwrap(),
+            generate_ts(9999, 12, 31, 23, 59, 59, 0)
+        );
+
+        // Invalid fields
+        assert_eq!(
+            Timestamp::try_from_packed_i64(20211032123045).unwrap_err(),
+            Error::InvalidDay
         );
-
         assert_eq!(
-            upper_ts.sub_timestamp(ts),
-            IntervalDT::try_from_dhms(1826046, 11, 29, 29, 499999).unwrap()
+            Timestamp::try_from_packed_i64(20211024243045).unwrap_err(),
+            Error::TimeOutOfRange
         );
-
         assert_eq!(
-            lower_ts.sub_timestamp(upper_ts),
-            -IntervalDT::try_from_dhms(3652058, 23, 59, 59, 999999).unwrap()
+            Timestamp::try_from_packed_i64(20211024126045).unwrap_err(),
+            Error::InvalidMinute
         );
+        assert_eq!(
+            Timestamp::try_from_packed_i64(20211024123060).unwrap_err(),
+            Error::InvalidSecond
+        );
+        for packed in [0, 20211024, -20211024123045, i64::MIN, i64::MAX] {
+            assert_eq!(
+                Timestamp::try_from_packed_i64(packed).unwrap_err(),
+                Error::DateOutOfRange
+            );
+        }
     }
 
     #[test]
-    fn test_timestamp_sub_date() {
+    fn test_timestamp_add_calendar_days() {
+        let ts = generate_ts(2021, 2, 28, 12, 30, 30, 555555);
+        assert_eq!(
+            ts.add_calendar_days(1).unwrap(),
+            generate_ts(2021, 3, 1, 12, 30, 30, 555555)
+        );
+        assert_eq!(
+            ts.add_calendar_days(-365).unwrap(),
+            generate_ts(2020, 2, 29, 12, 30, 30, 555555)
+        );
+        assert_eq!(ts.add_calendar_days(0).unwrap(), ts);
+
+        // The time component is kept exactly
+        let mut rng = TestRng::new();
+        for _ in 0..1000 {
+            let random = rng.next_u64();
+            let days = ((random >> 33) % 200_000) as i32 - 100_000;
+            let usecs = (random % USECONDS_PER_DAY as u64) as i64;
+            let ts = Timestamp::new(
+                generate_date(5000, 1, 1),
+                Time::try_from_usecs(usecs).unwrap(),
+            );
+            let result = ts.add_calendar_days(days).unwrap();
+            assert_eq!(result.extract().1, ts.extract().1);
+            assert_eq!(result.extract().0, ts.extract().0.add_days(days).unwrap());
+            assert_eq!(result.add_calendar_days(-days).unwrap(), ts);
+        }
+
+        // Out of range
         let upper_ts = generate_ts(9999, 12, 31, 23, 59, 59, 999999);
         let lower_ts = generate_ts(1, 1, 1, 0, 0, 0, 0);
-        let lower_date = Date::try_from_ymd(1, 1, 1).unwrap();
-        let upper_date = Date::try_from_ymd(9999, 12, 31).unwrap();
-        let date = Date::try_from_ymd(5000, 6, 15).unwrap();
-
         assert_eq!(
-            upper_ts.sub_date(lower_date),
-            IntervalDT::try_from_dhms(3652058, 23, 59, 59, 999999).unwrap()
+            upper_ts.add_calendar_days(-1).unwrap(),
+            generate_ts(9999, 12, 30, 23, 59, 59, 999999)
         );
-
         assert_eq!(
-            upper_ts.sub_date(date),
-            IntervalDT::try_from_dhms(1826046, 23, 59, 59, 999999).unwrap()
+            upper_ts.add_calendar_days(1).unwrap_err(),
+            Error::DateOutOfRange
         );
-
         assert_eq!(
-            lower_ts.sub_date(upper_date),
-            -IntervalDT::try_from_dhms(3652058, 0, 0, 0, 0).unwrap()
+            lower_ts.add_calendar_days(-1).unwrap_err(),
+            Error::DateOutOfRange
+        );
+        assert_eq!(
+            lower_ts.add_calendar_days(i32::MAX).unwrap_err(),
+            Error::DateOutOfRange
+        );
+        assert_eq!(
+            upper_ts.add_calendar_days(i32::MIN).unwrap_err(),
+            Error::DateOutOfRange
         );
     }
 
@@ -1669,6 +3530,50 @@ mod tests {
         let ts = generate_ts(5000, 6, 15, 12, 30, 30, 555555);
         assert_eq!(ts.sub_days(1.12).unwrap(), ts.add_days(-1.12).unwrap());
         assert_eq!(ts.sub_days(-1.12).unwrap(), ts.add_days(1.12).unwrap());
+
+        // Zero is exact
+        assert_eq!(ts.add_days(0.0).unwrap(), ts);
+        assert_eq!(ts.add_days(-0.0).unwrap(), ts);
+        assert_eq!(ts.sub_days(-0.0).unwrap(), ts);
+        assert_eq!(lower_ts.add_days(f64::MIN_POSITIVE).unwrap(), lower_ts);
+
+        // NaN and infinity
+        assert_eq!(ts.add_days(f64::NAN).unwrap_err(), Error::InvalidNumber);
+        assert_eq!(
+            ts.add_days(f64::INFINITY).unwrap_err(),
+            Error::NumericOverflow
+        );
+        assert_eq!(
+            ts.add_days(f64::NEG_INFINITY).unwrap_err(),
+            Error::NumericOverflow
+        );
+
+        // Microseconds out of range of i64 are rejected before being saturated by the cast,
+        // 106751991.167... days is 2^63 microseconds
+        assert_eq!(ts.add_days(f64::MAX).unwrap_err(), Error::NumericOverflow);
+        assert_eq!(ts.add_days(f64::MIN).unwrap_err(), Error::NumericOverflow);
+        assert_eq!(ts.add_days(1e300).unwrap_err(), Error::NumericOverflow);
+        assert_eq!(
+            lower_ts.add_days(106751991.2).unwrap_err(),
+            Error::NumericOverflow
+        );
+        assert_eq!(
+            upper_ts.add_days(-106751991.2).unwrap_err(),
+            Error::NumericOverflow
+        );
+        assert_eq!(
+            lower_ts.add_days(213435445784784.13).unwrap_err(),
+            Error::NumericOverflow
+        );
+        // Within range of i64 but out of range of timestamp
+        assert_eq!(
+            lower_ts.add_days(106751991.0).unwrap_err(),
+            Error::DateOutOfRange
+        );
+        assert_eq!(
+            upper_ts.add_days(-106751991.0).unwrap_err(),
+            Error::DateOutOfRange
+        );
     }
 
     #[test]
@@ -1692,6 +3597,95 @@ mod tests {
             (sec as f64 + (usec as f64) / 1_000_000f64),
             ts.second().unwrap()
         );
+        assert_eq!(usec / 1000, ts.millisecond().unwrap());
+        assert_eq!(usec, ts.microsecond().unwrap());
+        assert_eq!(
+            ts.usecs() as f64 / 1_000_000f64,
+            ts.epoch_seconds().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_timestamp_to_string_with() {
+        let ts = generate_ts(2021, 6, 1, 12, 30, 5, 123456);
+        for fmt in [
+            "YYYY-MM-DD HH24:MI:SS.FF",
+            "YYYY-MM-DDTHH24:MI:SS.FF3",
+            "DD/MM/YY HH12:MI AM",
+            "Month DD, YYYY Day",
+            "FMDDD IW",
+        ] {
+            let formatter = Formatter::try_new(fmt).unwrap();
+            assert_eq!(
+                ts.to_string_with(&formatter).unwrap(),
+                ts.format(fmt).unwrap().to_string()
+            );
+        }
+
+        // The formatter is reused
+        let formatter = Formatter::try_new("YYYY-MM-DD HH24:MI:SS").unwrap();
+        assert_eq!(
+            Timestamp::MIN.to_string_with(&formatter).unwrap(),
+            "0001-01-01 00:00:00"
+        );
+        assert_eq!(
+            Timestamp::MAX.to_string_with(&formatter).unwrap(),
+            "9999-12-31 23:59:59"
+        );
+
+        // Long output beyond the reserved capacity
+        let formatter = Formatter::try_new("Month Month Month Month Month").unwrap();
+        assert_eq!(
+            ts.to_string_with(&formatter).unwrap(),
+            "June June June June June"
+        );
+        let formatter = Formatter::try_new("MONTH DAY MONTH DAY YYYY-MM-DD HH24:MI:SS.FF").unwrap();
+        assert_eq!(
+            ts.to_string_with(&formatter).unwrap(),
+            "JUNE TUESDAY JUNE TUESDAY 2021-06-01 12:30:05.123456"
+        );
+
+        let formatter = Formatter::try_new("YYYY-MM-DD HH24:MI:SS").unwrap();
+        assert!(IntervalDT::ZERO.to_string_with(&formatter).is_err());
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_timestamp_epoch_seconds() {
+        assert_eq!(
+            generate_ts(1970, 1, 1, 0, 0, 0, 0).epoch_seconds().unwrap(),
+            0.0
+        );
+        assert_eq!(
+            generate_ts(1970, 1, 1, 0, 0, 0, 500000)
+                .epoch_seconds()
+                .unwrap(),
+            0.5
+        );
+        assert_eq!(
+            generate_ts(1970, 1, 1, 0, 0, 1, 250000)
+                .epoch_seconds()
+                .unwrap(),
+            1.25
+        );
+        assert_eq!(
+            generate_ts(1969, 12, 31, 23, 59, 59, 500000)
+                .epoch_seconds()
+                .unwrap(),
+            -0.5
+        );
+        assert_eq!(
+            generate_ts(2021, 6, 1, 12, 30, 5, 0)
+                .epoch_seconds()
+                .unwrap(),
+            1622550605.0
+        );
+        assert_eq!(
+            generate_ts(2021, 6, 1, 12, 30, 5, 123456)
+                .epoch_seconds()
+                .unwrap(),
+            1622550605.123456
+        );
     }
 
     #[test]
@@ -1706,6 +3700,8 @@ mod tests {
         test_extract(1970, 1, 1, 12, 30, 30, 30);
         test_extract(1999, 10, 21, 12, 30, 30, 30);
         test_extract(9999, 12, 31, 23, 59, 59, 999999);
+        test_extract(2021, 6, 1, 12, 0, 1, 123456);
+        test_extract(1969, 12, 31, 23, 59, 59, 123456);
     }
 
     #[test]
@@ -1718,6 +3714,180 @@ mod tests {
         assert_eq!(now.hour() as i32, dt.hour().unwrap());
     }
 
+    #[test]
+    fn test_round_to() {
+        let origin = generate_ts(2021, 6, 1, 0, 0, 0, 0);
+        let quarter = IntervalDT::try_from_dhms(0, 0, 15, 0, 0).unwrap();
+
+        assert_eq!(
+            generate_ts(2021, 6, 1, 10, 7, 0, 0)
+                .round_to(quarter, origin)
+                .unwrap(),
+            generate_ts(2021, 6, 1, 10, 0, 0, 0)
+        );
+        assert_eq!(
+            generate_ts(2021, 6, 1, 10, 8, 0, 0)
+                .round_to(quarter, origin)
+                .unwrap(),
+            generate_ts(2021, 6, 1, 10, 15, 0, 0)
+        );
+        // Exactly halfway rounds up
+        assert_eq!(
+            generate_ts(2021, 6, 1, 10, 7, 30, 0)
+                .round_to(quarter, origin)
+                .unwrap(),
+            generate_ts(2021, 6, 1, 10, 15, 0, 0)
+        );
+        assert_eq!(
+            generate_ts(2021, 6, 1, 10, 7, 29, 999999)
+                .round_to(quarter, origin)
+                .unwrap(),
+            generate_ts(2021, 6, 1, 10, 0, 0, 0)
+        );
+        // On a boundary
+        assert_eq!(
+            generate_ts(2021, 6, 1, 10, 15, 0, 0)
+                .round_to(quarter, origin)
+                .unwrap(),
+            generate_ts(2021, 6, 1, 10, 15, 0, 0)
+        );
+        // Into the next day
+        assert_eq!(
+            generate_ts(2021, 6, 1, 23, 55, 0, 0)
+                .round_to(quarter, origin)
+                .unwrap(),
+            generate_ts(2021, 6, 2, 0, 0, 0, 0)
+        );
+
+        // Before the origin
+        assert_eq!(
+            generate_ts(2021, 5, 31, 23, 52, 0, 0)
+                .round_to(quarter, origin)
+                .unwrap(),
+            generate_ts(2021, 5, 31, 23, 45, 0, 0)
+        );
+        assert_eq!(
+            generate_ts(2021, 5, 31, 23, 53, 0, 0)
+                .round_to(quarter, origin)
+                .unwrap(),
+            generate_ts(2021, 6, 1, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            generate_ts(1969, 12, 31, 23, 59, 0, 0)
+                .round_to(quarter, origin)
+                .unwrap(),
+            generate_ts(1970, 1, 1, 0, 0, 0, 0)
+        );
+
+        // Buckets aligned to an offset origin
+        let origin = generate_ts(2021, 6, 1, 0, 5, 0, 0);
+        let hour = IntervalDT::try_from_dhms(0, 1, 0, 0, 0).unwrap();
+        assert_eq!(
+            generate_ts(2021, 6, 1, 10, 34, 0, 0)
+                .round_to(hour, origin)
+                .unwrap(),
+            generate_ts(2021, 6, 1, 10, 5, 0, 0)
+        );
+        assert_eq!(
+            generate_ts(2021, 6, 1, 10, 36, 0, 0)
+                .round_to(hour, origin)
+                .unwrap(),
+            generate_ts(2021, 6, 1, 11, 5, 0, 0)
+        );
+
+        // Out of range
+        assert_eq!(
+            Timestamp::MAX.round_to(quarter, origin).unwrap_err(),
+            Error::DateOutOfRange
+        );
+        assert_eq!(
+            Timestamp::MIN
+                .round_to(hour, generate_ts(2021, 6, 1, 0, 45, 0, 0))
+                .unwrap_err(),
+            Error::DateOutOfRange
+        );
+
+        // A bucket larger than the whole range
+        assert_eq!(
+            Timestamp::MAX
+                .round_to(IntervalDT::MAX, Timestamp::MIN)
+                .unwrap(),
+            Timestamp::MIN
+        );
+
+        // Invalid bucket
+        for bucket in [IntervalDT::ZERO, -quarter] {
+            assert_eq!(
+                generate_ts(2021, 6, 1, 10, 7, 0, 0)
+                    .round_to(bucket, origin)
+                    .unwrap_err(),
+                Error::InvalidInterval("bucket size must be positive".to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn test_round_day_half_even() {
+        // Exactly noon rounds to the even day
+        assert_eq!(
+            generate_ts(2021, 1, 2, 12, 0, 0, 0)
+                .round_day_half_even()
+                .unwrap(),
+            generate_ts(2021, 1, 2, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            generate_ts(2021, 1, 3, 12, 0, 0, 0)
+                .round_day_half_even()
+                .unwrap(),
+            generate_ts(2021, 1, 4, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            generate_ts(1, 1, 1, 12, 0, 0, 0)
+                .round_day_half_even()
+                .unwrap(),
+            generate_ts(1, 1, 2, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            generate_ts(1970, 1, 1, 12, 0, 0, 0)
+                .round_day_half_even()
+                .unwrap(),
+            generate_ts(1970, 1, 2, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            generate_ts(1969, 12, 31, 12, 0, 0, 0)
+                .round_day_half_even()
+                .unwrap(),
+            generate_ts(1969, 12, 31, 0, 0, 0, 0)
+        );
+
+        // Same as round_day when not exactly noon
+        for ts in [
+            generate_ts(2021, 1, 2, 11, 59, 59, 999999),
+            generate_ts(2021, 1, 2, 12, 0, 0, 1),
+            generate_ts(2021, 1, 3, 12, 0, 0, 1),
+            generate_ts(2021, 1, 3, 0, 0, 0, 0),
+            generate_ts(2021, 1, 3, 23, 59, 59, 999999),
+            generate_ts(1, 1, 1, 0, 0, 0, 0),
+        ] {
+            assert_eq!(ts.round_day_half_even().unwrap(), ts.round_day().unwrap());
+        }
+
+        // Half of exactly noon timestamps round up
+        let mut ts = generate_ts(2021, 1, 1, 12, 0, 0, 0);
+        let mut rounded_up = 0;
+        for _ in 0..100 {
+            if ts.round_day_half_even().unwrap() > ts {
+                rounded_up += 1;
+            }
+            ts = ts.add_calendar_days(1).unwrap();
+        }
+        assert_eq!(rounded_up, 50);
+
+        assert!(generate_ts(9999, 12, 31, 12, 0, 0, 1)
+            .round_day_half_even()
+            .is_err());
+    }
+
     #[test]
     fn test_round_error() {
         let ts = generate_ts(DATE_MAX_YEAR, 12, 31, 23, 59, 30, 0);
@@ -2092,6 +4262,103 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_timestamp_with_ordinal() {
+        let ts = generate_ts(2020, 6, 1, 12, 30, 5, 123456);
+        assert_eq!(
+            ts.with_ordinal(366).unwrap(),
+            generate_ts(2020, 12, 31, 12, 30, 5, 123456)
+        );
+        assert_eq!(
+            ts.with_ordinal(1).unwrap(),
+            generate_ts(2020, 1, 1, 12, 30, 5, 123456)
+        );
+        let ts = generate_ts(2021, 6, 1, 12, 30, 5, 123456);
+        assert_eq!(ts.with_ordinal(366).unwrap_err(), Error::InvalidDay);
+        assert_eq!(ts.with_ordinal(0).unwrap_err(), Error::InvalidDay);
+    }
+
+    #[test]
+    fn test_timestamp_with_fields() {
+        let ts = generate_ts(2021, 1, 31, 12, 30, 5, 123456);
+
+        assert_eq!(
+            ts.with_year(2020).unwrap(),
+            generate_ts(2020, 1, 31, 12, 30, 5, 123456)
+        );
+        assert_eq!(ts.with_year(0).unwrap_err(), Error::DateOutOfRange);
+        assert_eq!(
+            ts.with_month(3).unwrap(),
+            generate_ts(2021, 3, 31, 12, 30, 5, 123456)
+        );
+        assert_eq!(ts.with_month(2).unwrap_err(), Error::InvalidDate);
+        assert_eq!(ts.with_month(13).unwrap_err(), Error::InvalidMonth);
+        assert_eq!(
+            ts.with_month_clamped(2).unwrap(),
+            generate_ts(2021, 2, 28, 12, 30, 5, 123456)
+        );
+        assert_eq!(ts.with_month_clamped(0).unwrap_err(), Error::InvalidMonth);
+        assert_eq!(
+            ts.with_day(1).unwrap(),
+            generate_ts(2021, 1, 1, 12, 30, 5, 123456)
+        );
+        assert_eq!(ts.with_day(32).unwrap_err(), Error::InvalidDay);
+        assert_eq!(
+            generate_ts(2021, 2, 1, 0, 0, 0, 0)
+                .with_day(31)
+                .unwrap_err(),
+            Error::InvalidDate
+        );
+        assert_eq!(
+            Timestamp::MAX.with_year(1).unwrap(),
+            generate_ts(1, 12, 31, 23, 59, 59, 999999)
+        );
+    }
+
+    #[test]
+    fn test_timestamp_business_day_of_month() {
+        assert_eq!(
+            generate_ts(2021, 5, 5, 23, 59, 59, 999999).business_day_of_month(),
+            3
+        );
+        assert_eq!(
+            generate_ts(2021, 5, 1, 12, 0, 0, 0).business_day_of_month(),
+            0
+        );
+        assert_eq!(Timestamp::MAX.business_day_of_month(), 23);
+    }
+
+    #[test]
+    fn test_day_of_month_progress() {
+        let ts = generate_ts(2020, 2, 29, 23, 59, 59, 999999);
+        assert_eq!(ts.day_of_month(), 29);
+        assert_eq!(ts.days_in_current_month(), 29);
+        assert_eq!(ts.days_remaining_in_month(), 0);
+
+        let ts = generate_ts(2021, 2, 28, 0, 0, 0, 0);
+        assert_eq!(ts.day_of_month(), 28);
+        assert_eq!(ts.days_in_current_month(), 28);
+        assert_eq!(ts.days_remaining_in_month(), 0);
+
+        let ts = generate_ts(1969, 12, 12, 12, 0, 0, 0);
+        assert_eq!(ts.day_of_month(), 12);
+        assert_eq!(ts.days_in_current_month(), 31);
+        assert_eq!(ts.days_remaining_in_month(), 19);
+    }
+
+    #[test]
+    fn test_month_year_boundary_predicates() {
+        assert!(generate_ts(2020, 2, 29, 23, 59, 59, 999999).is_last_day_of_month());
+        assert!(generate_ts(2021, 2, 28, 0, 0, 0, 0).is_last_day_of_month());
+        assert!(!generate_ts(2020, 2, 28, 12, 0, 0, 0).is_last_day_of_month());
+        assert!(generate_ts(2021, 3, 1, 12, 0, 0, 0).is_first_day_of_month());
+        assert!(!generate_ts(2021, 3, 1, 12, 0, 0, 0).is_first_day_of_year());
+        assert!(generate_ts(1969, 12, 31, 23, 59, 59, 999999).is_last_day_of_year());
+        assert!(generate_ts(1970, 1, 1, 0, 0, 0, 0).is_first_day_of_year());
+        assert!(Timestamp::MIN.is_first_day_of_year());
+        assert!(Timestamp::MAX.is_last_day_of_year());
+    }
+
     #[test]
     fn test_last_day_of_month() {
         assert_eq!(
@@ -2120,6 +4387,197 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_timestamp_parse_iso8601() {
+        let date = generate_ts(2021, 6, 1, 0, 0, 0, 0);
+        let ts = generate_ts(2021, 6, 1, 12, 30, 5, 0);
+        let ts_fraction = generate_ts(2021, 6, 1, 12, 30, 5, 123000);
+
+        // Calendar date
+        assert_eq!(Timestamp::parse_iso8601("2021-06-01").unwrap(), date);
+        assert_eq!(Timestamp::parse_iso8601("2021-06-01T12:30:05").unwrap(), ts);
+        assert_eq!(Timestamp::parse_iso8601("2021-06-01 12:30:05").unwrap(), ts);
+        assert_eq!(
+            Timestamp::parse_iso8601("2021-06-01T12:30:05.123Z").unwrap(),
+            ts_fraction
+        );
+
+        // Offset, converted to UTC
+        for input in [
+            "2021-06-01T20:30:05+08:00",
+            "2021-06-01T20:30:05+0800",
+            "2021-06-01T20:30:05+08",
+            "2021-06-01T07:00:05-05:30",
+            "2021-152T20:30:05+08:00",
+            "2021-W22-2T20:30:05+08:00",
+        ] {
+            assert_eq!(Timestamp::parse_iso8601(input).unwrap(), ts, "{}", input);
+        }
+        assert_eq!(
+            Timestamp::parse_iso8601("0001-01-01T00:00:00+01:00").unwrap_err(),
+            Error::DateOutOfRange
+        );
+
+        // Ordinal date
+        assert_eq!(Timestamp::parse_iso8601("2021-152").unwrap(), date);
+        assert_eq!(Timestamp::parse_iso8601("2021-152T12:30:05").unwrap(), ts);
+        assert_eq!(
+            Timestamp::parse_iso8601("2021-152T12:30:05.123").unwrap(),
+            ts_fraction
+        );
+        assert_eq!(
+            Timestamp::parse_iso8601("2020-366").unwrap(),
+            generate_ts(2020, 12, 31, 0, 0, 0, 0)
+        );
+
+        // Week date
+        assert_eq!(Timestamp::parse_iso8601("2021-W22-2").unwrap(), date);
+        assert_eq!(Timestamp::parse_iso8601("2021-W22-2T12:30:05").unwrap(), ts);
+        assert_eq!(
+            Timestamp::parse_iso8601("2021-W22-2 12:30:05.123Z").unwrap(),
+            ts_fraction
+        );
+        assert_eq!(
+            Timestamp::parse_iso8601("2021-W22").unwrap(),
+            generate_ts(2021, 5, 31, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            Timestamp::parse_iso8601("2020-W53-5").unwrap(),
+            generate_ts(2021, 1, 1, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            Timestamp::parse_iso8601("2020-W01-1").unwrap(),
+            generate_ts(2019, 12, 30, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            Timestamp::parse_iso8601("0001-W01-1").unwrap(),
+            Timestamp::MIN
+        );
+
+        // Invalid
+        for input in [
+            "",
+            "2021",
+            "2021-06",
+            "2021/06/01",
+            "2021-6-1",
+            "2021-06-01T",
+            "2021-06-01T12",
+            "2021-06-01X12:30:05",
+            "2021-06-01T12:30:05+8",
+            "2021-06-01T12:30:05+08:60",
+            "2021-06-01T12:30:05Z+08:00",
+            "2021-1522",
+            "2021-W2-2",
+            "2021-W22-2-1",
+            "2021-W22/2",
+            "21-06-01",
+            "abcd-06-01",
+        ] {
+            assert!(Timestamp::parse_iso8601(input).is_err(), "{}", input);
+        }
+        assert!(Timestamp::parse_iso8601("2021-02-29").is_err());
+        assert!(Timestamp::parse_iso8601("2021-000").is_err());
+        assert!(Timestamp::parse_iso8601("2021-366").is_err());
+        assert!(Timestamp::parse_iso8601("2021-W53-1").is_err());
+        assert!(Timestamp::parse_iso8601("2021-W00-1").is_err());
+        assert!(Timestamp::parse_iso8601("2021-W22-8").is_err());
+        assert!(Timestamp::parse_iso8601("9999-W52-6").is_err());
+        assert!(Timestamp::parse_iso8601("0000-01-01").is_err());
+    }
+
+    #[test]
+    fn test_timestamp_parse_iso8601_week_date_time() {
+        let ts = generate_ts(2021, 1, 4, 9, 30, 0, 0);
+        for input in [
+            "2021-W01-1T09:30:00Z",
+            "2021-W01-1T09:30:00",
+            "2021-W01-1 09:30:00",
+            "2021-W01-1T09:30:00.000000Z",
+            "2021-W01-1T09:30:00 UTC",
+        ] {
+            assert_eq!(Timestamp::parse_iso8601(input).unwrap(), ts, "{}", input);
+        }
+        assert_eq!(ts.format_iso8601_week().unwrap(), "2021-W01-1T09:30:00");
+
+        // Week belonging to the previous or next ISO year
+        assert_eq!(
+            Timestamp::parse_iso8601("2020-W53-7T23:59:59.999999Z").unwrap(),
+            generate_ts(2021, 1, 3, 23, 59, 59, 999999)
+        );
+        assert_eq!(
+            Timestamp::parse_iso8601("2020-W01-1T00:00:01Z").unwrap(),
+            generate_ts(2019, 12, 30, 0, 0, 1, 0)
+        );
+
+        // Round trip of the week date with time
+        let mut ts = generate_ts(2019, 12, 20, 9, 30, 0, 123456);
+        for _ in 0..40 {
+            let s = ts.format_iso8601_week().unwrap();
+            assert_eq!(Timestamp::parse_iso8601(&s).unwrap(), ts, "{}", s);
+            let s = format!("{}Z", s);
+            assert_eq!(Timestamp::parse_iso8601(&s).unwrap(), ts, "{}", s);
+            ts = ts.add_calendar_days(1).unwrap();
+        }
+
+        // Invalid
+        for input in [
+            "2021-W01-1T",
+            "2021-W01-1T09:30",
+            "2021-W01-1T24:00:00Z",
+            "2021-W53-1T09:30:00Z",
+            "2021-W01-8T09:30:00Z",
+            "2021-W01-1T09:30:00 PST",
+            "2021-W01-1T09:30:00+24:00",
+        ] {
+            assert!(Timestamp::parse_iso8601(input).is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_timestamp_format_iso8601() {
+        let ts = generate_ts(2021, 6, 1, 12, 30, 5, 0);
+        assert_eq!(ts.format_iso8601_ordinal().unwrap(), "2021-152T12:30:05");
+        assert_eq!(ts.format_iso8601_week().unwrap(), "2021-W22-2T12:30:05");
+
+        let ts = generate_ts(2021, 1, 1, 0, 0, 0, 123000);
+        assert_eq!(
+            ts.format_iso8601_ordinal().unwrap(),
+            "2021-001T00:00:00.123000"
+        );
+        assert_eq!(
+            ts.format_iso8601_week().unwrap(),
+            "2020-W53-5T00:00:00.123000"
+        );
+
+        assert_eq!(
+            Timestamp::MIN.format_iso8601_week().unwrap(),
+            "0001-W01-1T00:00:00"
+        );
+        assert_eq!(
+            Timestamp::MAX.format_iso8601_ordinal().unwrap(),
+            "9999-365T23:59:59.999999"
+        );
+
+        // Round trip
+        for ts in [
+            Timestamp::MIN,
+            Timestamp::MAX,
+            generate_ts(2019, 12, 30, 8, 0, 0, 1),
+            generate_ts(2020, 12, 31, 23, 59, 59, 0),
+            generate_ts(2021, 6, 1, 12, 30, 5, 500000),
+        ] {
+            assert_eq!(
+                Timestamp::parse_iso8601(ts.format_iso8601_ordinal().unwrap()).unwrap(),
+                ts
+            );
+            assert_eq!(
+                Timestamp::parse_iso8601(ts.format_iso8601_week().unwrap()).unwrap(),
+                ts
+            );
+        }
+    }
+
     #[test]
     fn test_iso_format() {
         const FMT: &str = "YYYY-MM-DDTHH24:MI:SS.FF";
@@ -2169,4 +4627,107 @@ mod tests {
         assert_invalid_iso_str("2023-05-26T00:00:Z");
         assert_invalid_iso_str("2023-05-26T00:00.123Z");
     }
+
+    #[test]
+    fn test_iso_format_trailing_zone() {
+        const FMT: &str = "YYYY-MM-DDTHH24:MI:SS.FF";
+        let ts = generate_ts(2022, 6, 18, 3, 4, 5, 0);
+        for input in [
+            "2022-06-18 03:04:05 UTC",
+            "2022-06-18 03:04:05UTC",
+            "2022-06-18T03:04:05 utc",
+            "2022-06-18T03:04:05 GMT",
+            "2022-06-18T03:04:05Gmt ",
+            "2022-06-18T03:04:05 Z",
+        ] {
+            assert_eq!(Timestamp::parse(input, FMT).unwrap(), ts, "{}", input);
+        }
+        assert_eq!(
+            Timestamp::parse("2022-06-18 03:04:05.123 UTC", FMT).unwrap(),
+            generate_ts(2022, 6, 18, 3, 4, 5, 123000)
+        );
+
+        // Other abbreviations imply offsets
+        for input in [
+            "2022-06-18 03:04:05 PST",
+            "2022-06-18 03:04:05.123 PST",
+            "2022-06-18 03:04:05 cest",
+        ] {
+            let err = Timestamp::parse(input, FMT).unwrap_err();
+            assert!(
+                err.to_string()
+                    .starts_with("sqldatetime: parse_error: unsupported time zone abbreviation"),
+                "{}",
+                input
+            );
+        }
+        assert_eq!(
+            Timestamp::parse("2022-06-18 03:04:05 PST", FMT).unwrap_err(),
+            Error::ParseError("unsupported time zone abbreviation PST".to_string())
+        );
+
+        assert!(Timestamp::parse("2022-06-18 03:04:05 UTC UTC", FMT).is_err());
+        assert!(Timestamp::parse("2022-06-18 03:04:05 UTC+8", FMT).is_err());
+        assert!(Timestamp::parse("2022-06-18 03:04 UTC", FMT).is_err());
+        // Only ISO 8601 format ignores the zone
+        assert!(Timestamp::parse("2022-06-18 03:04:05 UTC", "YYYY-MM-DD HH24:MI:SS").is_err());
+    }
+
+    #[test]
+    fn test_timestamp_round_trunc_never_panic() {
+        let mut inputs = vec![
+            Timestamp::MIN,
+            Timestamp::MAX,
+            generate_ts(1, 1, 1, 11, 59, 59, 999999),
+            generate_ts(1, 1, 1, 12, 0, 0, 0),
+            generate_ts(1, 1, 7, 23, 59, 59, 999999),
+            generate_ts(9999, 12, 31, 0, 0, 0, 0),
+            generate_ts(9999, 12, 31, 11, 59, 59, 999999),
+            generate_ts(9999, 12, 31, 12, 0, 0, 0),
+            generate_ts(9999, 12, 25, 12, 0, 0, 0),
+        ];
+
+        // Pseudo-random timestamps across the whole range
+        let span = (Timestamp::MAX.usecs() - Timestamp::MIN.usecs()) as u64 + 1;
+        let mut rng = TestRng::new();
+        for _ in 0..2000 {
+            let usecs = Timestamp::MIN.usecs() + (rng.next_u64() % span) as i64;
+            inputs.push(Timestamp::try_from_usecs(usecs).unwrap());
+        }
+
+        let ops: [fn(Timestamp) -> Result<Timestamp>; 24] = [
+            Timestamp::trunc_century,
+            Timestamp::trunc_year,
+            Timestamp::trunc_iso_year,
+            Timestamp::trunc_quarter,
+            Timestamp::trunc_month,
+            Timestamp::trunc_week,
+            Timestamp::trunc_iso_week,
+            Timestamp::trunc_month_start_week,
+            Timestamp::trunc_day,
+            Timestamp::trunc_sunday_start_week,
+            Timestamp::trunc_hour,
+            Timestamp::trunc_minute,
+            Timestamp::round_century,
+            Timestamp::round_year,
+            Timestamp::round_iso_year,
+            Timestamp::round_quarter,
+            Timestamp::round_month,
+            Timestamp::round_week,
+            Timestamp::round_iso_week,
+            Timestamp::round_month_start_week,
+            Timestamp::round_day,
+            Timestamp::round_sunday_start_week,
+            Timestamp::round_hour,
+            Timestamp::round_minute,
+        ];
+
+        for input in inputs {
+            for op in ops.iter() {
+                if let Ok(ts) = op(input) {
+                    assert!(ts >= Timestamp::MIN && ts <= Timestamp::MAX);
+                }
+            }
+        }
+    }
 }
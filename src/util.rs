@@ -44,3 +44,37 @@ macro_rules! try_format {
         res
     }}
 }
+
+/// Helpers shared by the unit tests of several modules.
+#[cfg(test)]
+pub mod testing {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    /// Hashes `value` with the default hasher, e.g. to check that `Hash` is consistent with `Eq`.
+    pub fn hash_of<T: Hash>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// A fixed seed pseudo random generator, so that randomized tests are reproducible.
+    pub struct TestRng(u64);
+
+    impl TestRng {
+        #[inline]
+        pub fn new() -> Self {
+            TestRng(0x2545_f491_4f6c_dd1d)
+        }
+
+        /// Returns the next value of the linear congruential generator.
+        #[inline]
+        pub fn next_u64(&mut self) -> u64 {
+            self.0 = self
+                .0
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            self.0
+        }
+    }
+}